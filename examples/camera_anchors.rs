@@ -1,15 +1,20 @@
-use bevy::{input::mouse::MouseMotion, prelude::*};
+use bevy::prelude::*;
 
 use bevy_control::prelude::*;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, CameraPlugin))
+        // mouse motion and scrolling are fed into the camera buffer by the input plugin
+        .add_plugins((
+            DefaultPlugins,
+            CameraPlugin::default(),
+            CameraInputPlugin::default(),
+        ))
         .add_systems(
             Startup,
             (setup_ui, setup_environment, setup_camera_controller),
         )
-        .add_systems(Update, (update_buffer, move_controller, switch_anchor))
+        .add_systems(Update, (move_controller, switch_anchor))
         .run();
 }
 
@@ -47,20 +52,6 @@ fn setup_environment(
     }
 }
 
-fn update_buffer(
-    mut query: Query<&mut CameraBuffer>,
-    mut mouse: EventReader<MouseMotion>,
-    time: Res<Time>,
-) {
-    for mut delta_buffer in query.iter_mut() {
-        // Calculate total mouse movement this frame
-        let delta = -mouse.read().map(|event| event.delta).sum::<Vec2>();
-
-        // Update input buffer based on mouse movement
-        delta_buffer.update(delta * time.delta_secs());
-    }
-}
-
 fn switch_anchor(
     input: Res<ButtonInput<KeyCode>>,
     mut controllers: Query<(Entity, &mut CameraController)>,
@@ -129,7 +120,7 @@ fn setup_ui(mut commands: Commands) {
                 1: Yaw (3D)\n\
                 2: Plane (2D Panning)\n\
                 3: Point (3D first person)\n\
-                4: Orbit (3D third person)",
+                4: Orbit (3D third person, scroll to zoom)",
         ));
     });
 }
@@ -152,7 +143,8 @@ fn setup_camera_controller(
         // add camera controller component
         CameraController::new(camera, CameraAnchor::default(), CameraView::Free)
             .with_pitch_range(f32::to_radians(90.0))
-            .with_sensitivity(0.25)
-            .with_smoothing(0.1),
+            .with_smoothing(0.1)
+            .with_zoom_smoothing(0.1)
+            .with_distance_limits(1.0, 20.0),
     ));
 }
@@ -4,7 +4,7 @@ use bevy_control::prelude::*;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, CameraPlugin))
+        .add_plugins((DefaultPlugins, CameraPlugin::default()))
         .add_systems(
             Startup,
             (setup_ui, setup_environment, setup_camera_controller),
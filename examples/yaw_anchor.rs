@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+
+use bevy_control::prelude::*;
+
+fn main() {
+    App::new()
+        // mouse motion is fed into the camera buffer by the input plugin
+        .add_plugins((
+            DefaultPlugins,
+            CameraPlugin::default(),
+            CameraInputPlugin::default(),
+        ))
+        .add_systems(
+            Startup,
+            (setup_ui, setup_environment, setup_camera_controller),
+        )
+        .run();
+}
+
+fn setup_environment(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Add a point light above the scene
+    commands.spawn((PointLight::default(), Transform::from_xyz(0.0, 5.0, 0.0)));
+
+    // Create a large white ground plane
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::new(Vec3::Y, Vec2::new(100.0, 100.0)))),
+        MeshMaterial3d(materials.add(Color::WHITE)),
+    ));
+}
+
+fn setup_ui(mut commands: Commands) {
+    commands.spawn(Node::DEFAULT).with_children(|parent| {
+        parent.spawn(Text::new(
+            "Yaw Anchor:\n\
+                Move the mouse up and down to raise and lower the camera\n\
+                Move the mouse sideways to slide the camera around the cube",
+        ));
+    });
+}
+
+fn setup_camera_controller(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // spawn camera and get entity
+    let camera = commands
+        .spawn((Camera3d::default(), Transform::from_xyz(0.0, 2.0, 6.0)))
+        .id();
+
+    // the cube the camera keeps looking at while it is panned
+    let cube = commands
+        .spawn((
+            Transform::from_xyz(0.0, 0.5, 0.0),
+            Mesh3d(meshes.add(Cuboid::new(1.0, 1.0, 1.0))),
+            MeshMaterial3d(materials.add(Color::linear_rgb(0.3, 10.0, 0.3))),
+        ))
+        .id();
+
+    commands.spawn((
+        Transform::default(),
+        // input pans the camera along the yaw axis and its local X axis
+        CameraController::new(camera, CameraAnchor::Yaw, CameraView::Target(cube))
+            .with_pan_sensitivity(Vec2::splat(2.0))
+            .with_pan_smoothing(Vec2::new(0.2, 0.1))
+            .with_rotation_smoothing(0.1),
+    ));
+}
@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+
+/// Confines a controller's camera to a region of the world, so it never leaves the level.
+///
+/// Bounds are given in world units, since they usually come from level geometry.
+/// Add it to the entity holding a [`CameraController`](super::CameraController) or
+/// [`CameraController2d`](super::CameraController2d).
+#[derive(Component, Clone, Copy, Debug)]
+pub enum CameraBounds {
+    /// Box the camera position stays inside, for 3D cameras
+    Box { min: Vec3, max: Vec3 },
+    /// Rectangle the visible area stays inside, for 2D cameras
+    Rect(Rect),
+}
+
+impl CameraBounds {
+    /// Creates bounds confining a 3D camera to a box
+    ///
+    /// # Arguments
+    /// * `min` - Minimum corner of the box
+    /// * `max` - Maximum corner of the box
+    pub fn from_box(min: Vec3, max: Vec3) -> Self {
+        Self::Box {
+            min: min.min(max),
+            max: min.max(max),
+        }
+    }
+
+    /// Creates bounds confining the visible area of a 2D camera to a rectangle
+    ///
+    /// # Arguments
+    /// * `rect` - Rectangle the visible area stays inside
+    pub fn from_rect(rect: Rect) -> Self {
+        Self::Rect(rect)
+    }
+
+    /// Clamps a 3D camera position to the bounds
+    ///
+    /// # Arguments
+    /// * `translation` - Camera position to clamp
+    pub fn clamp_translation(&self, translation: Vec3) -> Vec3 {
+        match self {
+            Self::Box { min, max } => translation.clamp(*min, *max),
+            Self::Rect(rect) => translation
+                .truncate()
+                .clamp(rect.min, rect.max)
+                .extend(translation.z),
+        }
+    }
+
+    /// Clamps a 2D camera center so its visible area stays inside the bounds.
+    /// Along axes where the visible area is larger than the bounds, the view is centered.
+    ///
+    /// # Arguments
+    /// * `center` - Camera center to clamp
+    /// * `view_half_size` - Half size of the visible area in world units
+    pub fn clamp_view(&self, center: Vec2, view_half_size: Vec2) -> Vec2 {
+        let (min, max) = match self {
+            Self::Box { min, max } => (min.truncate(), max.truncate()),
+            Self::Rect(rect) => (rect.min, rect.max),
+        };
+        confine_view(min, max, center, view_half_size)
+    }
+}
+
+/// Confines the visible area of a [`CameraController2d`](super::CameraController2d) to a level
+/// rectangle, accounting for the orthographic scale and viewport size of the camera.
+///
+/// Add it to the entity holding the controller. Along axes where the level is smaller
+/// than the visible area, the view is centered on the level instead.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct CameraConfiner2d {
+    /// Rectangle in world units the visible area stays inside
+    pub rect: Rect,
+}
+
+impl CameraConfiner2d {
+    /// Creates a new CameraConfiner2d
+    ///
+    /// # Arguments
+    /// * `rect` - Rectangle in world units the visible area stays inside
+    pub fn new(rect: Rect) -> Self {
+        Self { rect }
+    }
+
+    /// Clamps a camera center so its visible area stays inside the rectangle
+    ///
+    /// # Arguments
+    /// * `center` - Camera center to clamp
+    /// * `view_half_size` - Half size of the visible area in world units
+    pub fn clamp_view(&self, center: Vec2, view_half_size: Vec2) -> Vec2 {
+        confine_view(self.rect.min, self.rect.max, center, view_half_size)
+    }
+}
+
+/// Clamps a view center so the view stays between two corners, centering axes that do not fit
+///
+/// # Arguments
+/// * `min` - Minimum corner
+/// * `max` - Maximum corner
+/// * `center` - View center to clamp
+/// * `view_half_size` - Half size of the view
+fn confine_view(min: Vec2, max: Vec2, center: Vec2, view_half_size: Vec2) -> Vec2 {
+    let (inner_min, inner_max) = (min + view_half_size, max - view_half_size);
+    let middle = (min + max) * 0.5;
+    Vec2::select(
+        inner_min.cmple(inner_max),
+        center.clamp(inner_min.min(inner_max), inner_max.max(inner_min)),
+        middle,
+    )
+}
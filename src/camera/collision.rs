@@ -0,0 +1,558 @@
+#[cfg(feature = "avian3d")]
+use avian3d::prelude::*;
+#[cfg(feature = "rapier3d")]
+use bevy::ecs::entity::EntityHashSet;
+use bevy::prelude::*;
+#[cfg(feature = "rapier3d")]
+use bevy_rapier3d::prelude::{
+    Collider, CollisionGroups, Group, QueryFilter, ReadRapierContext, ShapeCastOptions,
+};
+
+/// Collision layers a camera collides with, avian's `LayerMask` with the `avian3d` backend
+#[cfg(feature = "avian3d")]
+pub type CollisionMask = LayerMask;
+/// Collision layers a camera collides with, rapier's `Group` with the `rapier3d` backend
+#[cfg(feature = "rapier3d")]
+pub type CollisionMask = Group;
+
+/// Filter for camera collision queries, avian's `SpatialQueryFilter` with the `avian3d` backend
+#[cfg(feature = "avian3d")]
+pub type CollisionFilter = SpatialQueryFilter;
+/// Filter for camera collision queries, a [`RapierQueryFilter`] with the `rapier3d` backend
+#[cfg(feature = "rapier3d")]
+pub type CollisionFilter = RapierQueryFilter;
+
+/// Scene queries used for camera collision with the active physics backend
+#[cfg(feature = "avian3d")]
+pub(crate) type CollisionQuery<'w, 's> = SpatialQuery<'w, 's>;
+/// Scene queries used for camera collision with the active physics backend
+#[cfg(feature = "rapier3d")]
+pub(crate) type CollisionQuery<'w, 's> = ReadRapierContext<'w, 's>;
+
+/// Owned filter for rapier scene queries, mirroring avian's `SpatialQueryFilter` since
+/// rapier's own `QueryFilter` borrows its exclusion predicate
+#[cfg(feature = "rapier3d")]
+#[derive(Clone, Debug, Default)]
+pub struct RapierQueryFilter {
+    /// Collision groups included in the query
+    pub mask: Group,
+    /// Entities whose colliders are excluded from the query
+    pub excluded_entities: EntityHashSet,
+}
+
+#[cfg(feature = "rapier3d")]
+impl RapierQueryFilter {
+    /// Creates a filter including the given collision groups
+    ///
+    /// # Arguments
+    /// * `mask` - Collision groups included in the query
+    pub fn from_mask(mask: impl Into<Group>) -> Self {
+        Self {
+            mask: mask.into(),
+            excluded_entities: EntityHashSet::default(),
+        }
+    }
+
+    /// Excludes the colliders of the given entities from the query
+    ///
+    /// # Arguments
+    /// * `entities` - Entities excluded from the query
+    pub fn with_excluded_entities(mut self, entities: impl IntoIterator<Item = Entity>) -> Self {
+        self.excluded_entities.extend(entities);
+        self
+    }
+
+    /// Runs a query with the equivalent rapier `QueryFilter`
+    ///
+    /// # Arguments
+    /// * `query` - Query run with the filter
+    fn with_query_filter<T>(&self, query: impl FnOnce(QueryFilter) -> T) -> T {
+        let predicate = |entity: Entity| !self.excluded_entities.contains(&entity);
+        query(
+            QueryFilter::new()
+                .groups(CollisionGroups::new(Group::ALL, self.mask))
+                .predicate(&predicate),
+        )
+    }
+}
+
+/// Spring arm settings that pull an orbiting camera in front of obstacles between it and
+/// the target, using a sphere cast so thin geometry does not clip the near plane
+#[derive(Clone, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpringArm {
+    /// Radius of the sphere cast along the arm, in meters
+    pub probe_radius: f32,
+    /// Rate at which the arm shortens toward obstacles with smooth interpolation
+    push_in_decay_rate: f32,
+    /// Rate at which the arm extends again once clear with smooth interpolation
+    recover_decay_rate: f32,
+    /// Optional fan of rays steering the camera away from upcoming obstacles
+    pub whiskers: Option<Whiskers>,
+}
+
+impl Default for SpringArm {
+    fn default() -> Self {
+        Self::new(0.2)
+    }
+}
+
+impl SpringArm {
+    /// Creates a new SpringArm that pushes in instantly and recovers with a smoothing of 0.3
+    ///
+    /// # Arguments
+    /// * `probe_radius` - Radius of the sphere cast along the arm, in meters
+    pub fn new(probe_radius: f32) -> Self {
+        Self {
+            probe_radius,
+            push_in_decay_rate: f32::INFINITY,
+            recover_decay_rate: 1.0 / 0.3,
+            whiskers: None,
+        }
+    }
+
+    /// Sets smoothing factors for shortening and extending the arm.
+    /// Larger values give smoother movement.
+    ///
+    /// # Arguments
+    /// * `push_in` - Smoothing factor when moving in front of an obstacle
+    /// * `recover` - Smoothing factor when moving back out once clear
+    #[inline]
+    pub fn with_smoothing(mut self, push_in: f32, recover: f32) -> Self {
+        self.push_in_decay_rate = 1.0 / push_in;
+        self.recover_decay_rate = 1.0 / recover;
+        self
+    }
+
+    /// Casts a fan of whisker rays around the arm, gently turning the camera away from
+    /// obstacles near the arm before they block it and pull the distance in
+    ///
+    /// # Arguments
+    /// * `whiskers` - Whisker configuration
+    #[inline]
+    pub fn with_whiskers(mut self, whiskers: Whiskers) -> Self {
+        self.whiskers = Some(whiskers);
+        self
+    }
+
+    /// Moves the arm length toward the allowed length, using the push in rate
+    /// when shortening and the recover rate when extending
+    ///
+    /// # Arguments
+    /// * `length` - Current arm length
+    /// * `allowed` - Arm length free of obstacles
+    /// * `dt` - Time elapsed since last update in seconds
+    pub(crate) fn smooth(&self, mut length: f32, allowed: f32, dt: f32) -> f32 {
+        let decay_rate = if allowed < length {
+            self.push_in_decay_rate
+        } else {
+            self.recover_decay_rate
+        };
+        if decay_rate.is_finite() {
+            length.smooth_nudge(&allowed, decay_rate, dt);
+            length
+        } else {
+            allowed
+        }
+    }
+}
+
+/// A fan of rays cast around a spring arm, see [`SpringArm::with_whiskers`]
+#[derive(Clone, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Whiskers {
+    /// Number of rays on each side of the arm, horizontally and vertically
+    pub count: u32,
+    /// Angle in radians between the arm and the outermost rays
+    pub spread: f32,
+    /// Maximum turn rate in radians per second when fully blocked on one side
+    pub steer_speed: f32,
+}
+
+impl Default for Whiskers {
+    fn default() -> Self {
+        Self::new(3, 30f32.to_radians(), 1.0)
+    }
+}
+
+impl Whiskers {
+    /// Creates a new Whiskers configuration
+    ///
+    /// # Arguments
+    /// * `count` - Number of rays on each side of the arm
+    /// * `spread` - Angle in radians between the arm and the outermost rays
+    /// * `steer_speed` - Maximum turn rate in radians per second
+    pub fn new(count: u32, spread: f32, steer_speed: f32) -> Self {
+        Self {
+            count,
+            spread,
+            steer_speed,
+        }
+    }
+}
+
+/// Builds a collision query filter excluding entities along with all of their descendants,
+/// so a camera never collides with the character it follows or anything attached to it
+///
+/// # Arguments
+/// * `entities` - Entities excluded along with their descendants
+/// * `children` - Query for the children of entities
+/// * `mask` - Collision layers included in the query
+pub fn exclude_hierarchy(
+    entities: impl IntoIterator<Item = Entity>,
+    children: &Query<&Children>,
+    mask: impl Into<CollisionMask>,
+) -> CollisionFilter {
+    let mut filter = CollisionFilter::from_mask(mask);
+    for entity in entities {
+        filter.excluded_entities.insert(entity);
+        filter
+            .excluded_entities
+            .extend(children.iter_descendants(entity));
+    }
+    filter
+}
+
+/// Collision layers included by default, used when deserializing controllers
+#[cfg(feature = "serde")]
+pub(crate) fn all_layers() -> CollisionMask {
+    CollisionMask::ALL
+}
+
+/// Casts a ray and returns the first entity hit and the distance to it
+///
+/// # Arguments
+/// * `query` - Scene queries used to find colliders
+/// * `filter` - Filter excluding colliders from the query
+/// * `origin` - Start of the ray
+/// * `direction` - Direction of the ray
+/// * `max_distance` - Maximum distance along the ray
+pub(crate) fn cast_ray(
+    query: &CollisionQuery,
+    filter: &CollisionFilter,
+    origin: Vec3,
+    direction: Dir3,
+    max_distance: f32,
+) -> Option<(Entity, f32)> {
+    #[cfg(feature = "avian3d")]
+    {
+        query
+            .cast_ray(origin, direction, max_distance, true, filter)
+            .map(|hit| (hit.entity, hit.distance))
+    }
+    #[cfg(feature = "rapier3d")]
+    {
+        let context = query.single().ok()?;
+        filter.with_query_filter(|filter| {
+            context.cast_ray(origin, direction.as_vec3(), max_distance, true, filter)
+        })
+    }
+}
+
+/// Casts a ray and returns up to `max_hits` entities hit along it
+///
+/// # Arguments
+/// * `query` - Scene queries used to find colliders
+/// * `filter` - Filter excluding colliders from the query
+/// * `origin` - Start of the ray
+/// * `direction` - Direction of the ray
+/// * `max_distance` - Maximum distance along the ray
+/// * `max_hits` - Maximum number of entities returned
+pub(crate) fn ray_hits(
+    query: &CollisionQuery,
+    filter: &CollisionFilter,
+    origin: Vec3,
+    direction: Dir3,
+    max_distance: f32,
+    max_hits: u32,
+) -> Vec<Entity> {
+    #[cfg(feature = "avian3d")]
+    {
+        query
+            .ray_hits(origin, direction, max_distance, max_hits, true, filter)
+            .into_iter()
+            .map(|hit| hit.entity)
+            .collect()
+    }
+    #[cfg(feature = "rapier3d")]
+    {
+        let mut hits = Vec::new();
+        let Ok(context) = query.single() else {
+            return hits;
+        };
+        filter.with_query_filter(|filter| {
+            context.intersections_with_ray(
+                origin,
+                direction.as_vec3(),
+                max_distance,
+                true,
+                filter,
+                |entity, _| {
+                    hits.push(entity);
+                    hits.len() < max_hits as usize
+                },
+            );
+        });
+        hits
+    }
+}
+
+/// Casts a sphere and returns the distance travelled before it hits anything, ignoring
+/// colliders the sphere already overlaps at its origin
+///
+/// # Arguments
+/// * `query` - Scene queries used to find colliders
+/// * `filter` - Filter excluding colliders from the query
+/// * `origin` - Start of the cast
+/// * `direction` - Direction of the cast
+/// * `max_distance` - Maximum distance travelled
+/// * `radius` - Radius of the sphere
+pub(crate) fn cast_sphere(
+    query: &CollisionQuery,
+    filter: &CollisionFilter,
+    origin: Vec3,
+    direction: Dir3,
+    max_distance: f32,
+    radius: f32,
+) -> Option<f32> {
+    #[cfg(feature = "avian3d")]
+    {
+        let config = ShapeCastConfig {
+            ignore_origin_penetration: true,
+            ..ShapeCastConfig::from_max_distance(max_distance)
+        };
+        query
+            .cast_shape(
+                &Collider::sphere(radius),
+                origin,
+                Quat::IDENTITY,
+                direction,
+                &config,
+                filter,
+            )
+            .map(|hit| hit.distance)
+    }
+    #[cfg(feature = "rapier3d")]
+    {
+        let context = query.single().ok()?;
+        // separating impacts at the origin are discarded, like avian's origin penetration
+        let options = ShapeCastOptions {
+            stop_at_penetration: false,
+            ..ShapeCastOptions::with_max_time_of_impact(max_distance)
+        };
+        filter.with_query_filter(|filter| {
+            context
+                .cast_shape(
+                    origin,
+                    Quat::IDENTITY,
+                    direction.as_vec3(),
+                    &Collider::ball(radius),
+                    options,
+                    filter,
+                )
+                .map(|(_, hit)| hit.time_of_impact)
+        })
+    }
+}
+
+/// Casts whisker rays around the arm and returns the yaw and pitch steering in radians
+/// for this update, turning away from the side with more and closer obstacles
+///
+/// # Arguments
+/// * `query` - Scene queries used to find obstacles
+/// * `filter` - Filter excluding the controller's own colliders
+/// * `pivot` - Point the camera orbits
+/// * `rotation` - Rotation of the arm, pointing its local Z axis toward the camera
+/// * `distance` - Desired arm length
+/// * `whiskers` - Whisker configuration
+/// * `dt` - Time elapsed since last update in seconds
+pub(crate) fn steer_whiskers(
+    query: &CollisionQuery,
+    filter: &CollisionFilter,
+    pivot: Vec3,
+    rotation: Quat,
+    distance: f32,
+    whiskers: &Whiskers,
+    dt: f32,
+) -> Vec2 {
+    if whiskers.count == 0 || distance <= 0.0 {
+        return Vec2::ZERO;
+    }
+    let back = rotation * Vec3::Z;
+    // turning around local up steers yaw, around local right steers pitch
+    let axes = [rotation * Vec3::Y, rotation * Vec3::X];
+
+    let mut steer = Vec2::ZERO;
+    for i in 1..=whiskers.count {
+        let angle = whiskers.spread * i as f32 / whiskers.count as f32;
+        for side in [-1.0, 1.0] {
+            for (index, axis) in axes.into_iter().enumerate() {
+                let Ok(direction) = Dir3::new(Quat::from_axis_angle(axis, side * angle) * back)
+                else {
+                    continue;
+                };
+                if let Some((_, hit_distance)) = cast_ray(query, filter, pivot, direction, distance)
+                {
+                    // closer obstacles push harder
+                    steer[index] -= side * (1.0 - hit_distance / distance);
+                }
+            }
+        }
+    }
+    steer / whiskers.count as f32 * whiskers.steer_speed * dt
+}
+
+/// Casts a sphere from the pivot toward the camera, returning the arm length free of obstacles
+///
+/// # Arguments
+/// * `query` - Scene queries used to find obstacles
+/// * `filter` - Filter excluding the controller's own colliders
+/// * `pivot` - Point the camera orbits
+/// * `direction` - Direction from the pivot toward the camera
+/// * `distance` - Desired arm length
+/// * `radius` - Radius of the sphere cast
+pub(crate) fn cast_spring_arm(
+    query: &CollisionQuery,
+    filter: &CollisionFilter,
+    pivot: Vec3,
+    direction: Dir3,
+    distance: f32,
+    radius: f32,
+) -> f32 {
+    cast_sphere(query, filter, pivot, direction, distance, radius).unwrap_or(distance)
+}
+
+/// Raises a solved camera position so it stays at least `min_height` above the ground,
+/// returning the distance the camera was raised by
+///
+/// # Arguments
+/// * `query` - Scene queries used to find the ground
+/// * `filter` - Filter excluding the controller's own colliders
+/// * `translation` - Solved camera position to clamp
+/// * `up` - Direction pointing away from the ground
+/// * `min_height` - Minimum height of the camera above the ground
+pub(crate) fn clamp_to_ground(
+    query: &CollisionQuery,
+    filter: &CollisionFilter,
+    translation: &mut Vec3,
+    up: Dir3,
+    min_height: f32,
+) -> Option<f32> {
+    // cast from above the camera so cameras already dipping under the ground are caught
+    let origin = *translation + up * min_height;
+    let (_, hit_distance) = cast_ray(query, filter, origin, -up, min_height * 2.0)?;
+
+    let ground = origin - up * hit_distance;
+    let push = min_height - (*translation - ground).dot(up.as_vec3());
+    if push > 0.0 {
+        *translation += up * push;
+        Some(push)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use bevy::{ecs::system::RunSystemOnce, time::TimeUpdateStrategy};
+
+    use super::*;
+
+    /// Builds an app with the active physics backend and a wall 5 units along negative Z,
+    /// stepped until the scene queries see it
+    fn wall_app() -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            TransformPlugin,
+            AssetPlugin::default(),
+            bevy::scene::ScenePlugin,
+        ))
+        .init_asset::<Mesh>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )));
+        #[cfg(feature = "avian3d")]
+        app.add_plugins(PhysicsPlugins::default());
+        #[cfg(feature = "rapier3d")]
+        app.add_plugins(bevy_rapier3d::prelude::RapierPhysicsPlugin::<()>::default());
+
+        #[cfg(feature = "avian3d")]
+        let collider = Collider::cuboid(10.0, 10.0, 1.0);
+        #[cfg(feature = "rapier3d")]
+        let collider = Collider::cuboid(5.0, 5.0, 0.5);
+        let wall = app
+            .world_mut()
+            .spawn((collider, Transform::from_xyz(0.0, 0.0, -5.0)))
+            .id();
+        app.finish();
+        for _ in 0..4 {
+            app.update();
+        }
+        (app, wall)
+    }
+
+    #[test]
+    fn spring_arm_stops_in_front_of_obstacles() {
+        let (mut app, _) = wall_app();
+        let allowed = app
+            .world_mut()
+            .run_system_once(|query: CollisionQuery| {
+                let filter = CollisionFilter::from_mask(CollisionMask::ALL);
+                cast_spring_arm(&query, &filter, Vec3::ZERO, Dir3::NEG_Z, 10.0, 0.5)
+            })
+            .unwrap();
+        // the wall's near face is 4.5 away, minus the probe radius
+        assert!((allowed - 4.0).abs() < 0.05, "{allowed}");
+
+        let free = app
+            .world_mut()
+            .run_system_once(|query: CollisionQuery| {
+                let filter = CollisionFilter::from_mask(CollisionMask::ALL);
+                cast_spring_arm(&query, &filter, Vec3::ZERO, Dir3::Z, 10.0, 0.5)
+            })
+            .unwrap();
+        assert_eq!(free, 10.0);
+    }
+
+    #[test]
+    fn excluded_entities_are_ignored() {
+        let (mut app, wall) = wall_app();
+        let (hit, allowed, hits) = app
+            .world_mut()
+            .run_system_once(move |query: CollisionQuery| {
+                let included = CollisionFilter::from_mask(CollisionMask::ALL);
+                let excluded = included.clone().with_excluded_entities([wall]);
+                (
+                    cast_ray(&query, &included, Vec3::ZERO, Dir3::NEG_Z, 10.0),
+                    cast_spring_arm(&query, &excluded, Vec3::ZERO, Dir3::NEG_Z, 10.0, 0.5),
+                    ray_hits(&query, &excluded, Vec3::ZERO, Dir3::NEG_Z, 10.0, 4),
+                )
+            })
+            .unwrap();
+        let (entity, distance) = hit.expect("the ray should hit the wall");
+        assert_eq!(entity, wall);
+        assert!((distance - 4.5).abs() < 0.05, "{distance}");
+        assert_eq!(allowed, 10.0);
+        assert!(hits.is_empty());
+    }
+
+    #[test]
+    fn cameras_are_raised_above_the_ground() {
+        let (mut app, _) = wall_app();
+        // the wall's near face stands in for the ground, with up along positive Z
+        let (raised, translation) = app
+            .world_mut()
+            .run_system_once(|query: CollisionQuery| {
+                let filter = CollisionFilter::from_mask(CollisionMask::ALL);
+                let mut translation = Vec3::new(0.0, 0.0, -4.3);
+                let raised = clamp_to_ground(&query, &filter, &mut translation, Dir3::Z, 0.5);
+                (raised, translation)
+            })
+            .unwrap();
+        assert!(raised.is_some_and(|raised| (raised - 0.3).abs() < 0.05));
+        assert!((translation.z + 4.0).abs() < 0.05, "{translation}");
+    }
+}
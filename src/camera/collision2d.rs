@@ -0,0 +1,62 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+/// Collision settings keeping a 2D camera center on the same side of level geometry as the
+/// point it follows, using a circle cast so the center stops short of walls
+#[derive(Clone, Copy, Debug)]
+pub struct CameraCollision2d {
+    /// Radius of the circle cast toward the camera center, in meters
+    pub probe_radius: f32,
+}
+
+impl Default for CameraCollision2d {
+    fn default() -> Self {
+        Self::new(0.2)
+    }
+}
+
+impl CameraCollision2d {
+    /// Creates a new CameraCollision2d
+    ///
+    /// # Arguments
+    /// * `probe_radius` - Radius of the circle cast toward the camera center, in meters
+    pub fn new(probe_radius: f32) -> Self {
+        Self { probe_radius }
+    }
+}
+
+/// Casts a circle from the followed point toward the desired camera center,
+/// returning the center moved in front of any obstacle in between
+///
+/// # Arguments
+/// * `spatial_query` - Spatial query used to find obstacles
+/// * `excluded` - Optional entity whose colliders are ignored, such as the followed target
+/// * `origin` - Point the camera follows, or its previous center while panning
+/// * `center` - Desired camera center
+/// * `radius` - Radius of the circle cast
+pub(crate) fn cast_view(
+    spatial_query: &SpatialQuery,
+    excluded: Option<Entity>,
+    origin: Vec2,
+    center: Vec2,
+    radius: f32,
+) -> Vec2 {
+    let Ok((direction, distance)) = Dir2::new_and_length(center - origin) else {
+        return center;
+    };
+    let config = ShapeCastConfig {
+        ignore_origin_penetration: true,
+        ..ShapeCastConfig::from_max_distance(distance)
+    };
+    let filter = SpatialQueryFilter::from_excluded_entities(excluded);
+    spatial_query
+        .cast_shape(
+            &Collider::circle(radius),
+            origin,
+            0.0,
+            direction,
+            &config,
+            &filter,
+        )
+        .map_or(center, |hit| origin + direction * hit.distance)
+}
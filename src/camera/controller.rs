@@ -1,27 +1,213 @@
-use bevy::prelude::*;
+#[cfg(feature = "avian3d")]
+use avian3d::prelude::{SpatialQuery, SpatialQueryFilter};
+use bevy::{
+    ecs::entity::{EntityMapper, MapEntities},
+    platform::collections::HashMap,
+    prelude::*,
+    render::camera::CameraProjection,
+};
+
+use core::time::Duration;
+
+use super::DeltaBuffer;
+use super::{
+    CameraSnapshot, CameraTimeSource, CameraTransition, FrameTarget, ProjectionMode, RegionScale,
+    Smoothing,
+    frame::FrameRequest,
+    projection::ProjectionBlend,
+    smoothing::{SmoothingVelocity, decay_to_half_life, half_life_to_decay},
+    snapshot::SnapshotRequest,
+};
+#[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+use super::{CollisionFilter, CollisionMask, SpringArm};
+
+/// Rate at which the measured spin speed follows rotation input, so a flick carries the
+/// speed of the last few frames rather than a single one
+const SPIN_VELOCITY_DECAY_RATE: f32 = 20.0;
+
+/// Spin speed in radians per second below which inertia stops
+const MIN_SPIN_SPEED: f32 = 1e-3;
 
 /// A camera controller component that provides smooth camera movement and rotation
-#[derive(Component)]
+///
+/// The controller and its targets may be children of other entities, their world space
+/// transforms are used. The camera may also be a child, such as of the controller entity
+/// itself so it is carried along between updates. The camera is solved in world space and
+/// its `Transform` is written relative to its parent.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[require(CameraBuffer)]
 pub struct CameraController {
     /// Entity ID of the camera being controlled
+    #[entities]
     pub camera: Entity,
+    /// Whether the controller consumes input and moves the camera. A disabled controller
+    /// keeps its camera, leaving it where it is and discarding input, such as during menus.
+    /// A [`CameraControllerPaused`](super::CameraControllerPaused) component also chooses
+    /// whether input is kept or faded while paused.
+    pub enabled: bool,
+    /// Priority among controllers sharing the camera, the highest active one drives it
+    pub priority: i32,
+    /// Duration in seconds of the blend when this controller takes over the camera
+    pub activation_blend: f32,
+    /// Whether the controller competes for its camera
+    active: bool,
+    /// Whether the controller was picked to drive its camera
+    pub(crate) live: bool,
     /// Constrain camera to either plane for 2D or orbit for 3D control
+    #[entities]
     pub anchor: CameraAnchor,
     /// View configuration for the camera
+    #[entities]
     pub view: CameraView,
-    /// Sensitivity of the camera controller
-    pub sensitivity: f32,
-    /// Offset position from the target in world space
+    /// Horizontal and vertical sensitivity of the camera controller
+    pub sensitivity: Vec2,
+    /// Offset position from the target in world space, in meters
     pub offset: Vec3,
-    /// Rate at which translation decays with smooth interpolation
-    translation_decay_rate: f32,
-    /// Rate at which rotation decays with smooth interpolation
-    rotation_decay_rate: f32,
+    /// Offset of the look point from the view target, in the target's local space
+    pub look_offset: Vec3,
+    /// Horizontal and vertical smoothing of translation
+    pub(crate) translation_smoothing: [Smoothing; 2],
+    /// Meters panned per unit of horizontal and vertical input by the
+    /// [`Yaw`](CameraAnchor::Yaw) and [`Plane`](CameraAnchor::Plane) anchors and per unit of
+    /// [pan input](CameraBuffer::update_pan), or `None` to use the sensitivity
+    pub pan_sensitivity: Option<Vec2>,
+    /// Horizontal and vertical smoothing of panning, or `None` to use the translation smoothing
+    pub(crate) pan_smoothing: Option<[Smoothing; 2]>,
+    /// Yaw and pitch smoothing of rotation
+    pub(crate) rotation_smoothing: [Smoothing; 2],
+    /// Sensitivity of the zoom channel
+    pub zoom_sensitivity: f32,
+    /// Smoothing of zoom
+    pub(crate) zoom_smoothing: Smoothing,
+    /// Optional minimum and maximum orbit distance reachable by zooming
+    pub distance_limits: Option<(f32, f32)>,
+    /// Optional base vertical field of view in radians written to the camera's projection
+    pub fov: Option<f32>,
+    /// Offset added to the base field of view in radians, such as sprint kicks or aiming
+    fov_offset: f32,
+    /// Rate at which field of view decays with smooth interpolation
+    fov_decay_rate: f32,
+    /// Rate at which the final camera rotation turns toward its goal with smooth interpolation
+    look_decay_rate: f32,
     /// World space axis around which yaw rotation occurs
     pub yaw_axis: Dir3,
     /// Optional limit on pitch angle, stored as cosine of half the range
     pitch_range: Option<f32>,
+    /// Optional minimum and maximum pitch angle in radians, negative values look down
+    pub(crate) pitch_limits: Option<(f32, f32)>,
+    /// Sensitivity of the roll channel
+    pub roll_sensitivity: f32,
+    /// Optional minimum and maximum roll angle in radians, positive values bank left
+    pub roll_limits: Option<(f32, f32)>,
+    /// Optional minimum and maximum yaw angle in radians relative to the yaw reference
+    pub yaw_range: Option<(f32, f32)>,
+    /// Fixed world direction yaw limits are relative to, or the controller entity's forward if unset
+    pub yaw_reference: Option<Dir3>,
+    /// Whether camera rotation is owned by an external source such as an XR headset
+    pub head_tracked: bool,
+    /// What the camera does once its view target no longer exists
+    pub target_lost: TargetLost,
+    /// Clock the controller advances its smoothing and animation with, also used by the
+    /// shake, noise, override, offset blend, lock on, up axis, motion state, killcam, sample
+    /// playback, drag pan, gamepad look, edge scroll and paused input systems acting on it.
+    /// Cameras without a controller, namely [`FlyCameraController`](super::FlyCameraController),
+    /// [`RtsCameraController`](super::RtsCameraController),
+    /// [`FlightController`](super::FlightController), [`ChaseCamera`](super::ChaseCamera) and
+    /// [`CameraBrain`](super::CameraBrain), as well as character bodies and occlusion fading,
+    /// always use the schedule's default clock. Controllers solved with
+    /// [`FixedCameraUpdate`](super::FixedCameraUpdate) always solve on the fixed timestep.
+    pub time_source: CameraTimeSource,
+    /// How rotation input turns the camera
+    pub rotation_mode: RotationMode,
+    /// Friction of the spin kept after rotation input stops, or no spin if unset
+    pub inertia: Option<f32>,
+    /// Optional fastest the camera moves in meters per second, after smoothing
+    pub max_speed: Option<f32>,
+    /// Optional fastest the camera turns in radians per second, after smoothing
+    pub max_angular_speed: Option<f32>,
+    /// Optional distance in meters the controller entity can jump in one update before
+    /// the camera snaps after it instead of smoothing across
+    pub teleport_distance: Option<f32>,
+    /// Whether [`CameraController::snap_to_target`] was called since the last update
+    snap_requested: bool,
+    /// Optional discrete yaw turning for comfort settings
+    pub snap_turn: Option<SnapTurn>,
+    /// Optional snapping of orbit yaw to fixed headings
+    pub orbit_snap: Option<OrbitSnap>,
+    /// Heading steps requested with [`CameraController::snap_orbit`]
+    orbit_snap_steps: i32,
+    /// Duration in seconds of the animated turn between isometric directions
+    pub isometric_turn_time: f32,
+    /// Weight from 0 to 1 pulling orbit yaw toward the controller entity's facing
+    pub heading_follow: f32,
+    /// Optional automatic orbiting once rotation input has been idle
+    pub idle_orbit: Option<IdleOrbit>,
+    /// Optional drift back behind the controller entity once rotation input has been idle
+    pub recenter: Option<Recenter>,
+    /// Optional lean applied on top of the solved camera
+    pub lean: Option<CameraLean>,
+    /// Lean the camera moves toward, from -1 for full left to 1 for full right
+    lean_target: f32,
+    /// Optional widening of the field of view and orbit distance as the controller
+    /// entity moves faster
+    pub speed_scaling: Option<SpeedScaling>,
+    /// Current kick in radians of yaw and pitch, layered on top of the solved camera
+    kick: Vec2,
+    /// Rate at which kick recovers toward rest with smooth interpolation
+    pub kick_recovery: f32,
+    /// Optional minimum height of the camera above the ground
+    #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+    pub min_ground_height: Option<f32>,
+    /// Optional spring arm keeping orbiting cameras in front of obstacles
+    #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+    pub spring_arm: Option<SpringArm>,
+    /// Whether rigid bodies followed by the controller are given transform interpolation
+    #[cfg(feature = "avian3d")]
+    pub interpolate_bodies: bool,
+    /// Collision layers the camera collides with
+    #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "super::collision::all_layers")
+    )]
+    pub collision_mask: CollisionMask,
+    /// Cameras slaved to the controlled camera's solved pose
+    #[entities]
+    pub secondary_cameras: SecondaryCameras,
+    /// Blend in progress after switching anchor or view
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) transition: Option<CameraTransition>,
+    /// Look blend in progress after switching view target
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) retarget: Option<Retarget>,
+    /// Lateral offset blend in progress after swapping shoulders
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) shoulder_swap: Option<ShoulderSwap>,
+    /// World space point orbited instead of the controller entity
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) orbit_pivot: Option<OrbitPivot>,
+    /// Target waiting to be fit in view
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) frame_request: Option<FrameRequest>,
+    /// Blend between perspective and orthographic projections
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) projection_blend: ProjectionBlend,
+    /// Saved views by slot
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) snapshots: HashMap<u32, CameraSnapshot>,
+    /// Snapshot saves and restores waiting for the next update
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) snapshot_requests: Vec<SnapshotRequest>,
 }
 
 impl CameraController {
@@ -38,28 +224,170 @@ impl CameraController {
     pub fn new(camera: Entity, anchor: CameraAnchor, view: CameraView) -> Self {
         Self {
             camera,
+            enabled: true,
+            priority: 0,
+            activation_blend: 0.5,
+            active: true,
+            live: true,
             anchor,
             view,
 
-            sensitivity: 1.0,
+            sensitivity: Vec2::ONE,
             offset: Vec3::ZERO,
+            look_offset: Vec3::ZERO,
 
-            translation_decay_rate: f32::INFINITY,
-            rotation_decay_rate: f32::INFINITY,
+            translation_smoothing: [Smoothing::INSTANT; 2],
+            pan_sensitivity: None,
+            pan_smoothing: None,
+            rotation_smoothing: [Smoothing::INSTANT; 2],
+
+            zoom_sensitivity: 1.0,
+            zoom_smoothing: Smoothing::INSTANT,
+            distance_limits: None,
+
+            fov: None,
+            fov_offset: 0.0,
+            fov_decay_rate: f32::INFINITY,
+            look_decay_rate: f32::INFINITY,
 
             yaw_axis: Dir3::Y,
             pitch_range: None,
+            pitch_limits: None,
+            roll_sensitivity: 1.0,
+            roll_limits: None,
+            yaw_range: None,
+            yaw_reference: None,
+
+            head_tracked: false,
+            target_lost: TargetLost::Free,
+            time_source: CameraTimeSource::Virtual,
+            rotation_mode: RotationMode::Turntable,
+            inertia: None,
+            max_speed: None,
+            max_angular_speed: None,
+            teleport_distance: None,
+            snap_requested: false,
+            snap_turn: None,
+            orbit_snap: None,
+            orbit_snap_steps: 0,
+            isometric_turn_time: 0.25,
+            heading_follow: 0.0,
+            idle_orbit: None,
+            recenter: None,
+            lean: None,
+            lean_target: 0.0,
+            speed_scaling: None,
+            kick: Vec2::ZERO,
+            kick_recovery: 1.0 / 0.08,
+            #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+            min_ground_height: None,
+            #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+            spring_arm: None,
+            #[cfg(feature = "avian3d")]
+            interpolate_bodies: false,
+            #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+            collision_mask: CollisionMask::ALL,
+            secondary_cameras: SecondaryCameras::default(),
+            transition: None,
+            retarget: None,
+            shoulder_swap: None,
+            orbit_pivot: None,
+            frame_request: None,
+            projection_blend: ProjectionBlend::default(),
+            snapshots: HashMap::default(),
+            snapshot_requests: Vec::new(),
         }
     }
 
+    /// Returns the translation decay rate used when following targets,
+    /// which is the horizontal rate
     #[inline]
     pub fn get_translation_decay_rate(&self) -> f32 {
-        self.translation_decay_rate
+        self.translation_smoothing[0].decay_rate()
+    }
+
+    #[inline]
+    pub fn get_translation_decay_rate_xy(&self) -> Vec2 {
+        Vec2::new(
+            self.translation_smoothing[0].decay_rate(),
+            self.translation_smoothing[1].decay_rate(),
+        )
+    }
+
+    #[inline]
+    pub fn get_rotation_decay_rate(&self) -> Vec2 {
+        Vec2::new(
+            self.rotation_smoothing[0].decay_rate(),
+            self.rotation_smoothing[1].decay_rate(),
+        )
+    }
+
+    #[inline]
+    pub fn get_zoom_decay_rate(&self) -> f32 {
+        self.zoom_smoothing.decay_rate()
+    }
+
+    /// Returns the horizontal and vertical smoothing of translation
+    #[inline]
+    pub fn translation_smoothing(&self) -> [Smoothing; 2] {
+        self.translation_smoothing
+    }
+
+    /// Returns the horizontal and vertical smoothing of panning
+    #[inline]
+    pub fn pan_smoothing(&self) -> [Smoothing; 2] {
+        self.pan_smoothing.unwrap_or(self.translation_smoothing)
+    }
+
+    /// Returns the yaw and pitch smoothing of rotation
+    #[inline]
+    pub fn rotation_smoothing(&self) -> [Smoothing; 2] {
+        self.rotation_smoothing
+    }
+
+    /// Returns the smoothing of zoom
+    #[inline]
+    pub fn zoom_smoothing(&self) -> Smoothing {
+        self.zoom_smoothing
+    }
+
+    /// Returns the horizontal and vertical time translation takes to cover half the
+    /// remaining distance to its goal
+    #[inline]
+    pub fn translation_half_life(&self) -> [Duration; 2] {
+        self.translation_smoothing
+            .map(|smoothing| smoothing.half_life())
+    }
+
+    /// Returns the yaw and pitch time rotation takes to cover half the remaining angle
+    /// to its goal
+    #[inline]
+    pub fn rotation_half_life(&self) -> [Duration; 2] {
+        self.rotation_smoothing
+            .map(|smoothing| smoothing.half_life())
+    }
+
+    /// Returns the time zoom takes to cover half the remaining distance to its goal
+    #[inline]
+    pub fn zoom_half_life(&self) -> Duration {
+        self.zoom_smoothing.half_life()
+    }
+
+    #[inline]
+    pub fn get_fov_decay_rate(&self) -> f32 {
+        self.fov_decay_rate
     }
 
     #[inline]
-    pub fn get_rotation_decay_rate(&self) -> f32 {
-        self.rotation_decay_rate
+    pub fn get_look_decay_rate(&self) -> f32 {
+        self.look_decay_rate
+    }
+
+    /// Returns the time the final camera rotation takes to turn half the remaining angle
+    /// to its goal
+    #[inline]
+    pub fn look_half_life(&self) -> Duration {
+        decay_to_half_life(self.look_decay_rate)
     }
 
     /// Sets the sensitivity multiplier for all movement
@@ -68,6 +396,17 @@ impl CameraController {
     /// * `sensitivity` - Multiplier for camera movement sensitivity
     #[inline]
     pub fn with_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.sensitivity = Vec2::splat(sensitivity);
+        self
+    }
+
+    /// Sets separate horizontal and vertical sensitivity multipliers,
+    /// such as slower pitch than yaw
+    ///
+    /// # Arguments
+    /// * `sensitivity` - Multipliers for horizontal and vertical movement
+    #[inline]
+    pub fn with_sensitivity_xy(mut self, sensitivity: Vec2) -> Self {
         self.sensitivity = sensitivity;
         self
     }
@@ -82,6 +421,16 @@ impl CameraController {
         self
     }
 
+    /// Sets the offset of the look point from the view target
+    ///
+    /// # Arguments
+    /// * `look_offset` - 3D vector offset in the target's local space
+    #[inline]
+    pub fn with_look_offset(mut self, look_offset: Vec3) -> Self {
+        self.look_offset = look_offset;
+        self
+    }
+
     /// Sets smoothing factor for both translation and rotation.
     /// Larger values give smoother movement.
     ///
@@ -89,9 +438,65 @@ impl CameraController {
     /// * `smoothing` - Smoothing factor for camera movement
     #[inline]
     pub fn with_smoothing(mut self, smoothing: f32) -> Self {
-        let decay_rate = 1.0 / smoothing;
-        self.translation_decay_rate = decay_rate;
-        self.rotation_decay_rate = decay_rate;
+        let smoothing = Smoothing::exponential(smoothing);
+        self.translation_smoothing = [smoothing; 2];
+        self.rotation_smoothing = [smoothing; 2];
+        self
+    }
+
+    /// Sets smoothing for both translation and rotation by the time taken to cover half
+    /// the remaining distance to the goal, where zero moves instantly
+    ///
+    /// # Arguments
+    /// * `half_life` - Time to cover half the remaining distance
+    #[inline]
+    pub fn with_smoothing_half_life(mut self, half_life: Duration) -> Self {
+        let smoothing = Smoothing::from_half_life(half_life);
+        self.translation_smoothing = [smoothing; 2];
+        self.rotation_smoothing = [smoothing; 2];
+        self
+    }
+
+    /// Sets the response model used for translation, rotation and zoom,
+    /// such as a damped spring for weightier movement
+    ///
+    /// # Arguments
+    /// * `smoothing` - Response model for camera movement
+    #[inline]
+    pub fn with_smoothing_model(mut self, smoothing: Smoothing) -> Self {
+        self.translation_smoothing = [smoothing; 2];
+        self.rotation_smoothing = [smoothing; 2];
+        self.zoom_smoothing = smoothing;
+        self
+    }
+
+    /// Sets the response model used for translation only
+    ///
+    /// # Arguments
+    /// * `smoothing` - Response model for translation movement
+    #[inline]
+    pub fn with_translation_model(mut self, smoothing: Smoothing) -> Self {
+        self.translation_smoothing = [smoothing; 2];
+        self
+    }
+
+    /// Sets the response model used for rotation only
+    ///
+    /// # Arguments
+    /// * `smoothing` - Response model for rotational movement
+    #[inline]
+    pub fn with_rotation_model(mut self, smoothing: Smoothing) -> Self {
+        self.rotation_smoothing = [smoothing; 2];
+        self
+    }
+
+    /// Sets the response model used for zoom only
+    ///
+    /// # Arguments
+    /// * `smoothing` - Response model for zoom movement
+    #[inline]
+    pub fn with_zoom_model(mut self, smoothing: Smoothing) -> Self {
+        self.zoom_smoothing = smoothing;
         self
     }
 
@@ -102,7 +507,60 @@ impl CameraController {
     /// * `smoothing` - Smoothing factor for translation movement
     #[inline]
     pub fn with_translation_smoothing(mut self, smoothing: f32) -> Self {
-        self.translation_decay_rate = 1.0 / smoothing;
+        self.translation_smoothing = [Smoothing::exponential(smoothing); 2];
+        self
+    }
+
+    /// Sets smoothing for translation only by the time taken to cover half the remaining
+    /// distance to the goal
+    ///
+    /// # Arguments
+    /// * `half_life` - Time to cover half the remaining distance
+    #[inline]
+    pub fn with_translation_half_life(mut self, half_life: Duration) -> Self {
+        self.translation_smoothing = [Smoothing::from_half_life(half_life); 2];
+        self
+    }
+
+    /// Sets separate horizontal and vertical smoothing factors for translation.
+    /// Following targets uses the horizontal factor.
+    /// Larger values give smoother movement.
+    ///
+    /// # Arguments
+    /// * `smoothing` - Horizontal and vertical smoothing factors
+    #[inline]
+    pub fn with_translation_smoothing_xy(mut self, smoothing: Vec2) -> Self {
+        self.translation_smoothing = [
+            Smoothing::exponential(smoothing.x),
+            Smoothing::exponential(smoothing.y),
+        ];
+        self
+    }
+
+    /// Sets the distance panned per unit of input by the [`Yaw`](CameraAnchor::Yaw)
+    /// and [`Plane`](CameraAnchor::Plane) anchors, separately from rotation sensitivity
+    ///
+    /// # Arguments
+    /// * `sensitivity` - Meters panned per unit of horizontal and vertical input
+    #[inline]
+    pub fn with_pan_sensitivity(mut self, sensitivity: Vec2) -> Self {
+        self.pan_sensitivity = Some(sensitivity);
+        self
+    }
+
+    /// Sets separate horizontal and vertical smoothing factors for panning by the
+    /// [`Yaw`](CameraAnchor::Yaw) and [`Plane`](CameraAnchor::Plane) anchors,
+    /// separately from the smoothing used when following.
+    /// Larger values give smoother movement.
+    ///
+    /// # Arguments
+    /// * `smoothing` - Horizontal and vertical smoothing factors
+    #[inline]
+    pub fn with_pan_smoothing(mut self, smoothing: Vec2) -> Self {
+        self.pan_smoothing = Some([
+            Smoothing::exponential(smoothing.x),
+            Smoothing::exponential(smoothing.y),
+        ]);
         self
     }
 
@@ -113,153 +571,2175 @@ impl CameraController {
     /// * `smoothing` - Smoothing factor for rotational movement
     #[inline]
     pub fn with_rotation_smoothing(mut self, smoothing: f32) -> Self {
-        self.rotation_decay_rate = 1.0 / smoothing;
+        self.rotation_smoothing = [Smoothing::exponential(smoothing); 2];
         self
     }
 
-    /// Sets the world space axis for yaw rotation
+    /// Sets smoothing for rotation only by the time taken to cover half the remaining
+    /// angle to the goal
     ///
     /// # Arguments
-    /// * `yaw_axis` - The axis around which yaw rotation occurs
+    /// * `half_life` - Time to cover half the remaining angle
     #[inline]
-    pub fn with_yaw_axis(mut self, yaw_axis: Dir3) -> Self {
-        self.yaw_axis = yaw_axis;
+    pub fn with_rotation_half_life(mut self, half_life: Duration) -> Self {
+        self.rotation_smoothing = [Smoothing::from_half_life(half_life); 2];
         self
     }
 
-    /// Sets the maximum pitch angle in radians from horizontal
+    /// Sets separate yaw and pitch smoothing factors for rotation.
+    /// Roll uses the yaw factor.
+    /// Larger values give smoother movement.
     ///
     /// # Arguments
-    /// * `pitch_range` - Maximum pitch angle in radians (+/- from horizontal)
+    /// * `smoothing` - Yaw and pitch smoothing factors
     #[inline]
-    pub fn with_pitch_range(mut self, pitch_range: f32) -> Self {
-        // stores the cosine of half the pitch range as the minimum y component
-        // of the controllers yaw axis
-        self.pitch_range = Some((pitch_range / 2.0).cos());
+    pub fn with_rotation_smoothing_xy(mut self, smoothing: Vec2) -> Self {
+        self.rotation_smoothing = [
+            Smoothing::exponential(smoothing.x),
+            Smoothing::exponential(smoothing.y),
+        ];
         self
     }
 
-    /// Gets rotation delta for this frame, with smooth decay
-    /// subtracting the delta from the accumulated delta
+    /// Sets the sensitivity multiplier for zoom input
     ///
     /// # Arguments
-    /// * `delta_buffer` - Delta buffer to decay
-    /// * `dt` - Time elapsed since last update in seconds
-    pub fn get_rotation_delta(&self, delta_buffer: &mut CameraBuffer, dt: f32) -> Vec2 {
-        if self.rotation_decay_rate.is_finite() {
-            delta_buffer.decay(self.rotation_decay_rate, dt) * self.sensitivity
-        } else {
-            delta_buffer.take() * self.sensitivity
-        }
+    /// * `sensitivity` - Multiplier for zoom sensitivity
+    #[inline]
+    pub fn with_zoom_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.zoom_sensitivity = sensitivity;
+        self
     }
 
-    /// Gets translation delta for this frame, with smooth decay
-    /// subtracting the delta from the accumulated delta
+    /// Sets smoothing factor for zoom only.
+    /// Larger values give smoother movement.
     ///
     /// # Arguments
-    /// * `delta_buffer` - Delta buffer to decay
-    /// * `dt` - Time elapsed since last update in seconds
-    pub fn get_translation_delta(&self, delta_buffer: &mut CameraBuffer, dt: f32) -> Vec2 {
-        if self.translation_decay_rate.is_finite() {
-            delta_buffer.decay(self.translation_decay_rate, dt) * self.sensitivity
-        } else {
-            delta_buffer.take() * self.sensitivity
-        }
+    /// * `smoothing` - Smoothing factor for zoom movement
+    #[inline]
+    pub fn with_zoom_smoothing(mut self, smoothing: f32) -> Self {
+        self.zoom_smoothing = Smoothing::exponential(smoothing);
+        self
     }
 
-    /// Checks if a pitch rotation would exceed configured angle limits
+    /// Sets smoothing for zoom only by the time taken to cover half the remaining
+    /// distance to the goal
     ///
     /// # Arguments
-    /// * `pitch` - Proposed pitch rotation in radians
-    /// * `rotation` - Current camera rotation
-    pub fn can_rotate_pitch(&self, pitch: f32, rotation: Quat) -> bool {
-        match self.pitch_range {
-            Some(pitch_range) => {
-                let up = rotation * Quat::from_rotation_x(pitch) * self.yaw_axis;
-                up.y >= pitch_range
-            }
-            _ => true,
-        }
+    /// * `half_life` - Time to cover half the remaining distance
+    #[inline]
+    pub fn with_zoom_half_life(mut self, half_life: Duration) -> Self {
+        self.zoom_smoothing = Smoothing::from_half_life(half_life);
+        self
     }
-}
 
-#[derive(Default, Clone)]
-pub enum CameraAnchor {
-    #[default]
-    /// Constrains camera to point with respect to controller for first person control
-    Point,
-    /// Constrains camera to radial orbit around controller to allow for 3D third person control
-    Orbit { distance: f32 },
-    /// projects y input onto yaw axis for translation
-    Yaw,
-    /// Constrains camera to plane to allow for 2D panning control across plane defined by normal
-    Plane { normal: Dir3 },
-}
+    /// Sets the base field of view, letting the controller drive the camera's
+    /// perspective projection
+    ///
+    /// # Arguments
+    /// * `fov` - Vertical field of view in radians
+    #[inline]
+    pub fn with_fov(mut self, fov: f32) -> Self {
+        self.fov = Some(fov);
+        self
+    }
 
-#[derive(Default, Clone)]
-pub enum CameraView {
-    #[default]
-    /// Allows for camera view to be dependent on input
-    Free,
-    /// Constrains camera to look at an Entity
-    Target(Entity),
-}
+    /// Sets smoothing factor for field of view only.
+    /// Larger values give smoother changes.
+    ///
+    /// # Arguments
+    /// * `smoothing` - Smoothing factor for field of view changes
+    #[inline]
+    pub fn with_fov_smoothing(mut self, smoothing: f32) -> Self {
+        self.fov_decay_rate = 1.0 / smoothing;
+        self
+    }
 
-/// A buffer component that stores and manages data for the controller to use
-/// contains fields that are expected to be frequently mutated
-#[derive(Component, Default)]
-pub struct CameraBuffer {
-    /// The current accumulated 2D input from mouse or joystick
-    input: Vec2,
-    /// The current rotation that would allow camera to point the desired direction.
-    /// To allow for targetting functionallity, this variable is used for rotation
-    /// control independent of the current camera orientation
-    pub(crate) rotation: Quat,
-}
+    /// Sets smoothing factor for the final camera rotation, so switching view targets or
+    /// between free and target views turns the camera rather than cutting. Unlike rotation
+    /// smoothing, which smooths input, this smooths every change of rotation.
+    /// Larger values give smoother turns.
+    ///
+    /// # Arguments
+    /// * `smoothing` - Smoothing factor for camera rotation
+    #[inline]
+    pub fn with_look_smoothing(mut self, smoothing: f32) -> Self {
+        self.look_decay_rate = 1.0 / smoothing;
+        self
+    }
 
-impl CameraBuffer {
-    /// Adds the given delta to the buffer's current value
+    /// Sets smoothing for the final camera rotation by the time taken to turn half the
+    /// remaining angle to the goal
+    ///
+    /// # Arguments
+    /// * `half_life` - Time to turn half the remaining angle
     #[inline]
-    pub fn update(&mut self, delta: Vec2) {
-        self.input += delta;
+    pub fn with_look_half_life(mut self, half_life: Duration) -> Self {
+        self.look_decay_rate = half_life_to_decay(half_life);
+        self
     }
 
-    /// Subtracts the given delta from the buffer's current value
+    /// Sets the offset added to the base field of view, such as a sprint kick
+    /// or a narrower field of view while aiming
+    ///
+    /// # Arguments
+    /// * `offset` - Offset in radians
     #[inline]
-    pub fn consume(&mut self, delta: Vec2) {
-        self.input -= delta;
+    pub fn set_fov_offset(&mut self, offset: f32) {
+        self.fov_offset = offset;
     }
 
-    /// Resets the buffer's delta value to zero
+    /// Returns the offset added to the base field of view
     #[inline]
-    pub fn reset(&mut self) {
-        self.input = Vec2::ZERO;
+    pub fn fov_offset(&self) -> f32 {
+        self.fov_offset
     }
 
-    /// Returns the current delta value without modifying it
+    /// Returns the field of view the camera moves toward, if driven by the controller
     #[inline]
-    pub fn read(&self) -> Vec2 {
-        self.input
+    pub fn target_fov(&self) -> Option<f32> {
+        self.fov
+            .map(|fov| (fov + self.fov_offset).clamp(0.01, std::f32::consts::PI - 0.01))
     }
 
-    /// Returns the current delta value and resets the buffer
+    /// Sets the minimum and maximum orbit distance reachable by zooming
+    ///
+    /// # Arguments
+    /// * `min` - Minimum orbit distance
+    /// * `max` - Maximum orbit distance
     #[inline]
-    pub fn take(&mut self) -> Vec2 {
-        let taken = self.input;
-        self.reset();
-        taken
+    pub fn with_distance_limits(mut self, min: f32, max: f32) -> Self {
+        self.distance_limits = Some((min.min(max), max.max(min)));
+        self
     }
 
-    /// Reduces the delta value using smooth interpolation
+    /// Sets the world space axis for yaw rotation
     ///
     /// # Arguments
-    /// * `rate` - The rate at which to decay the value
-    /// * `dt` - The time increment
+    /// * `yaw_axis` - The axis around which yaw rotation occurs
     #[inline]
-    pub fn decay(&mut self, rate: f32, dt: f32) -> Vec2 {
-        let mut consumed = Vec2::ZERO;
-        consumed.smooth_nudge(&self.input, rate, dt);
-        self.consume(consumed);
-        consumed
+    pub fn with_yaw_axis(mut self, yaw_axis: Dir3) -> Self {
+        self.yaw_axis = yaw_axis;
+        self
+    }
+
+    /// Sets the maximum pitch angle in radians from horizontal
+    ///
+    /// # Arguments
+    /// * `pitch_range` - Maximum pitch angle in radians (+/- from horizontal)
+    #[inline]
+    pub fn with_pitch_range(mut self, pitch_range: f32) -> Self {
+        // stores the cosine of half the pitch range as the minimum y component
+        // of the controllers yaw axis
+        self.pitch_range = Some((pitch_range / 2.0).cos());
+        self
+    }
+
+    /// Sets separate lower and upper pitch limits in radians from horizontal,
+    /// such as allowing a third person camera to look far down but only a little up
+    ///
+    /// # Arguments
+    /// * `min` - Lowest pitch angle, negative values look down
+    /// * `max` - Highest pitch angle, positive values look up
+    #[inline]
+    pub fn with_pitch_limits(mut self, min: f32, max: f32) -> Self {
+        let limit = std::f32::consts::FRAC_PI_2 - 0.001;
+        let (min, max) = (min.min(max), min.max(max));
+        self.pitch_limits = Some((min.clamp(-limit, limit), max.clamp(-limit, limit)));
+        self
+    }
+
+    /// Sets the sensitivity multiplier for roll input
+    ///
+    /// # Arguments
+    /// * `sensitivity` - Multiplier for roll sensitivity
+    #[inline]
+    pub fn with_roll_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.roll_sensitivity = sensitivity;
+        self
+    }
+
+    /// Sets roll limits in radians around the camera's forward axis,
+    /// such as a small range for lean peeking
+    ///
+    /// # Arguments
+    /// * `min` - Lowest roll angle, negative values bank right
+    /// * `max` - Highest roll angle, positive values bank left
+    #[inline]
+    pub fn with_roll_limits(mut self, min: f32, max: f32) -> Self {
+        self.roll_limits = Some((min.min(max), min.max(max)));
+        self
+    }
+
+    /// Sets yaw limits in radians relative to the yaw reference, stopping the camera
+    /// from turning fully around in vehicle interiors or cinematic shots.
+    /// Positive angles turn counterclockwise around the yaw axis.
+    ///
+    /// # Arguments
+    /// * `min` - Lowest yaw angle relative to the reference
+    /// * `max` - Highest yaw angle relative to the reference
+    #[inline]
+    pub fn with_yaw_range(mut self, min: f32, max: f32) -> Self {
+        self.yaw_range = Some((min.min(max), min.max(max)));
+        self
+    }
+
+    /// Sets a fixed world direction that yaw limits are relative to,
+    /// instead of the controller entity's forward direction
+    ///
+    /// # Arguments
+    /// * `reference` - Direction with a yaw of zero
+    #[inline]
+    pub fn with_yaw_reference(mut self, reference: Dir3) -> Self {
+        self.yaw_reference = Some(reference);
+        self
+    }
+
+    /// Clamps the yaw of a rotation to the yaw range around a reference direction
+    ///
+    /// # Arguments
+    /// * `rotation` - Camera rotation to clamp
+    /// * `reference` - Direction with a yaw of zero
+    pub fn clamp_yaw(&self, rotation: Quat, reference: Vec3) -> Quat {
+        let Some((min, max)) = self.yaw_range else {
+            return rotation;
+        };
+        // the yaw from the reference is the inverse of the yaw back to it
+        let Some(yaw) = self.yaw_to_heading(rotation, reference).map(|yaw| -yaw) else {
+            return rotation;
+        };
+        let clamped = yaw.clamp(min, max);
+        if clamped == yaw {
+            rotation
+        } else {
+            Quat::from_axis_angle(self.yaw_axis.as_vec3(), clamped - yaw) * rotation
+        }
+    }
+
+    /// Returns the lower and upper pitch limits in radians, if set
+    #[inline]
+    pub fn pitch_limits(&self) -> Option<(f32, f32)> {
+        self.pitch_limits
+    }
+
+    /// Gets the pitch of a rotation in radians above the plane normal to the yaw axis
+    ///
+    /// # Arguments
+    /// * `rotation` - Camera rotation
+    #[inline]
+    pub fn get_pitch(&self, rotation: Quat) -> f32 {
+        let forward = rotation * Vec3::NEG_Z;
+        forward.dot(self.yaw_axis.as_vec3()).clamp(-1.0, 1.0).asin()
+    }
+
+    /// Sets whether the camera rotation is owned by an external source such as an XR headset.
+    /// When enabled the headset is expected to write the camera's rotation every frame before
+    /// the controller updates. The controller positions the camera and turns the tracked
+    /// rotation around the yaw axis by the accumulated yaw input, such as from snap turns,
+    /// but ignores pitch and roll input and never applies view targets.
+    ///
+    /// # Arguments
+    /// * `head_tracked` - Whether the camera rotation is externally tracked
+    #[inline]
+    pub fn with_head_tracking(mut self, head_tracked: bool) -> Self {
+        self.head_tracked = head_tracked;
+        self
+    }
+
+    /// Sets what the camera does once its view target no longer exists, such as after being
+    /// despawned. Either way [`CameraTargetLost`](super::CameraTargetLost) is triggered once.
+    ///
+    /// # Arguments
+    /// * `target_lost` - Behavior once the view target is lost
+    #[inline]
+    pub fn with_target_lost(mut self, target_lost: TargetLost) -> Self {
+        self.target_lost = target_lost;
+        self
+    }
+
+    /// Sets the clock the controller advances its smoothing and animation with, such as real
+    /// time so the camera can still be orbited in a pause menu
+    ///
+    /// # Arguments
+    /// * `time_source` - Clock to advance with
+    #[inline]
+    pub fn with_time_source(mut self, time_source: CameraTimeSource) -> Self {
+        self.time_source = time_source;
+        self
+    }
+
+    /// Sets how rotation input turns the camera
+    ///
+    /// # Arguments
+    /// * `rotation_mode` - Turntable or arcball rotation
+    #[inline]
+    pub fn with_rotation_mode(mut self, rotation_mode: RotationMode) -> Self {
+        self.rotation_mode = rotation_mode;
+        self
+    }
+
+    /// Keeps the camera spinning after rotation input stops, at the speed it was turning
+    /// when released and slowing with friction, for flicking models and globes around.
+    /// The spin replaces what rotation smoothing would still have applied, and is ignored
+    /// with snap turning.
+    ///
+    /// # Arguments
+    /// * `friction` - Rate at which the spin slows, larger values stop sooner
+    #[inline]
+    pub fn with_inertia(mut self, friction: f32) -> Self {
+        self.inertia = Some(friction);
+        self
+    }
+
+    /// Limits how fast the camera moves, so a target teleporting across the level is
+    /// chased rather than followed in a single frame. Bounds and collision are still
+    /// resolved after the limit, and transitions are not limited.
+    ///
+    /// # Arguments
+    /// * `speed` - Speed in meters per second
+    #[inline]
+    pub fn with_max_speed(mut self, speed: f32) -> Self {
+        self.max_speed = Some(speed);
+        self
+    }
+
+    /// Limits how fast the camera turns, such as when a look target jumps behind it.
+    /// Transitions are not limited.
+    ///
+    /// # Arguments
+    /// * `speed` - Speed in radians per second
+    #[inline]
+    pub fn with_max_angular_speed(mut self, speed: f32) -> Self {
+        self.max_angular_speed = Some(speed);
+        self
+    }
+
+    /// Enables snap turning, replacing smooth yaw with discrete increments
+    ///
+    /// # Arguments
+    /// * `snap_turn` - Snap turn configuration
+    #[inline]
+    pub fn with_snap_turn(mut self, snap_turn: SnapTurn) -> Self {
+        self.snap_turn = Some(snap_turn);
+        self
+    }
+
+    /// Enables orbit yaw snapping. Rotation input turns an orbiting camera freely, and once
+    /// it is released the camera animates to the nearest of a number of fixed headings,
+    /// such as for grid based builders.
+    ///
+    /// # Arguments
+    /// * `orbit_snap` - Orbit snap configuration
+    #[inline]
+    pub fn with_orbit_snap(mut self, orbit_snap: OrbitSnap) -> Self {
+        self.orbit_snap = Some(orbit_snap);
+        self
+    }
+
+    /// Turns a snapping orbit camera by whole headings, such as from discrete key presses.
+    /// Has no effect unless orbit snapping is enabled.
+    ///
+    /// # Arguments
+    /// * `steps` - Number of headings to turn, positive values turn left
+    #[inline]
+    pub fn snap_orbit(&mut self, steps: i32) {
+        self.orbit_snap_steps += steps;
+    }
+
+    /// Takes the heading steps requested since the last update
+    #[inline]
+    pub(crate) fn take_orbit_snap_steps(&mut self) -> i32 {
+        std::mem::take(&mut self.orbit_snap_steps)
+    }
+
+    /// Snaps the camera after the controller entity when it jumps further than a distance
+    /// in one update, such as when respawning or moving between levels, triggering
+    /// [`CameraTargetTeleported`](super::CameraTargetTeleported)
+    ///
+    /// # Arguments
+    /// * `distance` - Distance in meters
+    #[inline]
+    pub fn with_teleport_distance(mut self, distance: f32) -> Self {
+        self.teleport_distance = Some(distance);
+        self
+    }
+
+    /// Moves the camera straight to its goal on the next update, skipping smoothing and
+    /// speed limits and clearing smoothing velocities, such as after teleporting the
+    /// controller entity
+    #[inline]
+    pub fn snap_to_target(&mut self) {
+        self.snap_requested = true;
+    }
+
+    /// Takes whether a snap was requested since the last update
+    #[inline]
+    pub(crate) fn take_snap_request(&mut self) -> bool {
+        std::mem::take(&mut self.snap_requested)
+    }
+
+    /// Sets the duration of the animated turn between isometric directions
+    ///
+    /// # Arguments
+    /// * `duration` - Turn duration in seconds
+    #[inline]
+    pub fn with_isometric_turn_time(mut self, duration: f32) -> Self {
+        self.isometric_turn_time = duration;
+        self
+    }
+
+    /// Sets the soft heading follow weight for the Orbit anchor.
+    /// A weight of 0 gives a free orbit, while 1 keeps the camera locked behind
+    /// the controller entity. Values in between pull the yaw toward the entity's
+    /// facing each frame while still allowing player input on top.
+    ///
+    /// # Arguments
+    /// * `weight` - Follow weight in the range 0 to 1
+    #[inline]
+    pub fn with_heading_follow(mut self, weight: f32) -> Self {
+        self.heading_follow = weight.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Enables leaning, a roll and lateral offset layered on top of the solved camera
+    /// for strafe tilt or peeking around corners, driven by [`CameraController::set_lean`]
+    ///
+    /// # Arguments
+    /// * `lean` - Lean configuration
+    #[inline]
+    pub fn with_lean(mut self, lean: CameraLean) -> Self {
+        self.lean = Some(lean);
+        self
+    }
+
+    /// Sets the lean the camera moves toward, such as the strafe input or a peek key
+    ///
+    /// # Arguments
+    /// * `amount` - Lean from -1 for full left to 1 for full right
+    #[inline]
+    pub fn set_lean(&mut self, amount: f32) {
+        self.lean_target = amount.clamp(-1.0, 1.0);
+    }
+
+    /// Returns the lean the camera moves toward
+    #[inline]
+    pub fn lean_target(&self) -> f32 {
+        self.lean_target
+    }
+
+    /// Enables speed scaling, adding field of view and orbit distance as the controller
+    /// entity moves faster so racing and sprinting read as fast. Extra field of view is
+    /// only added when the controller drives it, see [`CameraController::with_fov`].
+    ///
+    /// # Arguments
+    /// * `speed_scaling` - Speed scaling configuration
+    #[inline]
+    pub fn with_speed_scaling(mut self, speed_scaling: SpeedScaling) -> Self {
+        self.speed_scaling = Some(speed_scaling);
+        self
+    }
+
+    /// Punches the camera with an impulse such as weapon recoil or a hit reaction, which
+    /// recovers back to rest on its own. The kick is layered on top of the solved camera,
+    /// so it never reaches the input buffer, pitch limits or rotation smoothing.
+    ///
+    /// # Arguments
+    /// * `kick` - Yaw and pitch in radians, in the same directions as rotation input
+    #[inline]
+    pub fn add_kick(&mut self, kick: Vec2) {
+        self.kick += kick;
+    }
+
+    /// Returns the current kick in radians of yaw and pitch
+    #[inline]
+    pub fn kick(&self) -> Vec2 {
+        self.kick
+    }
+
+    /// Sets smoothing factor for kick recovery, independent of input smoothing.
+    /// Larger values recover more slowly.
+    ///
+    /// # Arguments
+    /// * `smoothing` - Smoothing factor for kick recovery
+    #[inline]
+    pub fn with_kick_recovery(mut self, smoothing: f32) -> Self {
+        self.kick_recovery = 1.0 / smoothing;
+        self
+    }
+
+    /// Moves the kick toward rest
+    ///
+    /// # Arguments
+    /// * `dt` - Time delta in seconds
+    pub(crate) fn recover_kick(&mut self, dt: f32) {
+        if self.kick_recovery.is_finite() {
+            self.kick.smooth_nudge(&Vec2::ZERO, self.kick_recovery, dt);
+            // settle once the kick is no longer visible
+            if self.kick.length_squared() < 1e-10 {
+                self.kick = Vec2::ZERO;
+            }
+        } else {
+            self.kick = Vec2::ZERO;
+        }
+    }
+
+    /// Sets the priority among controllers sharing the camera
+    ///
+    /// # Arguments
+    /// * `priority` - Priority, the highest active controller drives the camera
+    #[inline]
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the duration of the blend when this controller takes over the camera
+    ///
+    /// # Arguments
+    /// * `blend_secs` - Blend duration in seconds, or 0 to cut
+    #[inline]
+    pub fn with_activation_blend(mut self, blend_secs: f32) -> Self {
+        self.activation_blend = blend_secs;
+        self
+    }
+
+    /// Lets the controller compete for its camera again
+    #[inline]
+    pub fn activate(&mut self) {
+        self.active = true;
+    }
+
+    /// Withdraws the controller from its camera, handing it to the next highest priority
+    #[inline]
+    pub fn deactivate(&mut self) {
+        self.active = false;
+    }
+
+    /// Returns whether the controller competes for its camera
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Returns whether the controller was picked to drive its camera
+    #[inline]
+    pub fn is_live(&self) -> bool {
+        self.live
+    }
+
+    /// Slowly orbits the Orbit anchor around the controller entity once rotation input
+    /// has been idle, handing control back as soon as new input arrives
+    ///
+    /// # Arguments
+    /// * `idle_orbit` - Idle orbit configuration
+    #[inline]
+    pub fn with_idle_orbit(mut self, idle_orbit: IdleOrbit) -> Self {
+        self.idle_orbit = Some(idle_orbit);
+        self
+    }
+
+    /// Turns the Orbit anchor back behind the controller entity's facing once rotation
+    /// input has been idle, as third person cameras do when the stick is released
+    ///
+    /// # Arguments
+    /// * `recenter` - Recenter configuration
+    #[inline]
+    pub fn with_recenter(mut self, recenter: Recenter) -> Self {
+        self.recenter = Some(recenter);
+        self
+    }
+
+    /// Sets the minimum height of the camera above the ground.
+    /// The solved camera position is raised along the yaw axis whenever a downward
+    /// ray cast finds the ground closer than this height.
+    ///
+    /// # Arguments
+    /// * `min_height` - Minimum height above the ground
+    #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+    #[inline]
+    pub fn with_min_ground_height(mut self, min_height: f32) -> Self {
+        self.min_ground_height = Some(min_height);
+        self
+    }
+
+    /// Sets a spring arm for the Orbit anchor, pulling the camera in front of
+    /// obstacles between it and the target
+    ///
+    /// # Arguments
+    /// * `spring_arm` - Spring arm settings
+    #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+    #[inline]
+    pub fn with_spring_arm(mut self, spring_arm: SpringArm) -> Self {
+        self.spring_arm = Some(spring_arm);
+        self
+    }
+
+    /// Sets the collision layers the camera collides with, such as to let it pass through
+    /// foliage or other players
+    ///
+    /// # Arguments
+    /// * `mask` - Collision layers the camera collides with
+    #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+    #[inline]
+    pub fn with_collision_mask(mut self, mask: impl Into<CollisionMask>) -> Self {
+        self.collision_mask = mask.into();
+        self
+    }
+
+    /// Builds the filter used for camera collision, colliding with the collision mask and
+    /// excluding the controller entity and all of its descendants, such as a character's
+    /// limbs and held items
+    ///
+    /// # Arguments
+    /// * `entity` - Controller entity
+    /// * `children` - Query for the children of entities
+    #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+    pub fn collision_filter(&self, entity: Entity, children: &Query<&Children>) -> CollisionFilter {
+        super::collision::exclude_hierarchy([entity], children, self.collision_mask)
+    }
+
+    /// Gives the controller entity and view target transform interpolation when they are
+    /// rigid bodies without it, so cameras following bodies moved in the fixed timestep
+    /// see smooth motion every frame instead of stuttering
+    ///
+    /// # Arguments
+    /// * `interpolate_bodies` - Whether followed rigid bodies are interpolated
+    #[cfg(feature = "avian3d")]
+    #[inline]
+    pub fn with_body_interpolation(mut self, interpolate_bodies: bool) -> Self {
+        self.interpolate_bodies = interpolate_bodies;
+        self
+    }
+
+    /// Adds a camera slaved to the controlled camera, for stereo rigs,
+    /// scope render targets or picture-in-picture views driven by one controller
+    ///
+    /// # Arguments
+    /// * `camera` - Entity ID of the secondary camera
+    /// * `offset` - Transform in the controlled camera's local space
+    #[inline]
+    pub fn with_secondary_camera(mut self, camera: Entity, offset: Transform) -> Self {
+        self.secondary_cameras
+            .push(SecondaryCamera::new(camera, offset));
+        self
+    }
+
+    /// Switches to a new anchor and view, blending the camera from its current pose
+    /// to the new one over the duration instead of snapping
+    ///
+    /// # Arguments
+    /// * `anchor` - The anchor to switch to
+    /// * `view` - The view to switch to
+    /// * `duration` - Duration of the blend in seconds
+    /// * `ease` - Easing applied to the blend
+    pub fn transition_to(
+        &mut self,
+        anchor: CameraAnchor,
+        view: CameraView,
+        duration: f32,
+        ease: EaseFunction,
+    ) {
+        self.anchor = anchor;
+        self.view = view;
+        self.transition = Some(CameraTransition::new(duration, ease));
+    }
+
+    /// Switches the view target, turning the look direction from the previous
+    /// target to the new one over the blend time instead of snapping
+    ///
+    /// # Arguments
+    /// * `target` - Entity to look at
+    /// * `blend_secs` - Duration of the blend in seconds
+    pub fn retarget(&mut self, target: Entity, blend_secs: f32) {
+        self.retarget = match self.view {
+            CameraView::Target(previous) if previous != target && blend_secs > 0.0 => {
+                Some(Retarget {
+                    previous,
+                    duration: blend_secs,
+                    elapsed: 0.0,
+                })
+            }
+            _ => None,
+        };
+        self.view = CameraView::Target(target);
+    }
+
+    /// Mirrors the camera to the other shoulder, animating the X component of the
+    /// offset to its negation. Swapping again mid blend heads back to the first shoulder.
+    ///
+    /// # Arguments
+    /// * `blend_secs` - Duration of the blend in seconds
+    pub fn swap_shoulder(&mut self, blend_secs: f32) {
+        let to = match &self.shoulder_swap {
+            Some(swap) => -swap.to,
+            None => -self.offset.x,
+        };
+        self.shoulder_swap = Some(ShoulderSwap {
+            from: self.offset.x,
+            to,
+            duration: blend_secs,
+            elapsed: 0.0,
+        });
+    }
+
+    /// Returns the lateral offset the camera rests on once any shoulder swap finishes,
+    /// positive values are over the right shoulder
+    #[inline]
+    pub fn shoulder_offset(&self) -> f32 {
+        self.shoulder_swap.map_or(self.offset.x, |swap| swap.to)
+    }
+
+    /// Advances a shoulder swap in progress, writing the blended lateral offset
+    ///
+    /// # Arguments
+    /// * `dt` - Time elapsed since last update in seconds
+    pub(crate) fn advance_shoulder_swap(&mut self, dt: f32) {
+        let Some(swap) = self.shoulder_swap.as_mut() else {
+            return;
+        };
+        swap.elapsed += dt;
+        let t = if swap.duration > 0.0 {
+            swap.elapsed / swap.duration
+        } else {
+            1.0
+        };
+        self.offset.x = swap
+            .from
+            .lerp(swap.to, EaseFunction::SmoothStep.sample_clamped(t));
+        if t >= 1.0 {
+            self.shoulder_swap = None;
+        }
+    }
+
+    /// Orbits a point in the world instead of the controller entity, moving the pivot there
+    /// over the blend time, such as to orbit around a clicked object in an editor
+    ///
+    /// # Arguments
+    /// * `point` - World space pivot in world units
+    /// * `blend_secs` - Duration of the blend in seconds
+    pub fn set_orbit_pivot(&mut self, point: Vec3, blend_secs: f32) {
+        self.orbit_pivot = Some(OrbitPivot {
+            from: self.orbit_pivot.and_then(|pivot| pivot.current),
+            to: point,
+            current: None,
+            duration: blend_secs,
+            elapsed: 0.0,
+        });
+    }
+
+    /// Orbits the center of a target at the distance that fits it in the camera's view,
+    /// moving there over the duration, like focusing the selection in an editor. The
+    /// camera keeps its rotation, and the target is fit once its bounds are known on the
+    /// next update.
+    ///
+    /// # Arguments
+    /// * `target` - World space bounds or an entity, fit with its descendants' mesh bounds
+    /// * `margin` - Fraction of the target's size left around it
+    /// * `duration` - Duration of the move in seconds
+    pub fn frame(&mut self, target: impl Into<FrameTarget>, margin: f32, duration: f32) {
+        self.frame_request = Some(FrameRequest {
+            target: target.into(),
+            margin,
+            duration,
+        });
+    }
+
+    /// Returns to orbiting the controller entity
+    #[inline]
+    pub fn clear_orbit_pivot(&mut self) {
+        self.orbit_pivot = None;
+    }
+
+    /// Returns the world space point orbited instead of the controller entity, if any
+    #[inline]
+    pub fn orbit_pivot(&self) -> Option<Vec3> {
+        self.orbit_pivot.map(|pivot| pivot.to)
+    }
+
+    /// Gets the world space ray through the center of the controlled camera's view,
+    /// for aiming where the camera looks
+    ///
+    /// # Arguments
+    /// * `camera_transform` - Transform of the controlled camera
+    /// * `projection` - Projection of the controlled camera
+    #[inline]
+    pub fn aim_ray(&self, camera_transform: &Transform, projection: &Projection) -> Ray3d {
+        self.aim_ray_at(camera_transform, projection, Vec2::ZERO)
+    }
+
+    /// Gets the world space ray through a point of the controlled camera's view,
+    /// starting on the near plane
+    ///
+    /// # Arguments
+    /// * `camera_transform` - Transform of the controlled camera
+    /// * `projection` - Projection of the controlled camera
+    /// * `ndc` - Point in normalized device coordinates, from -1 to 1 with y pointing up
+    pub fn aim_ray_at(
+        &self,
+        camera_transform: &Transform,
+        projection: &Projection,
+        ndc: Vec2,
+    ) -> Ray3d {
+        let view_from_clip = projection.get_clip_from_view().inverse();
+        let world_from_view = camera_transform.compute_matrix();
+        // projections use reversed depth, with the near plane at 1
+        let near = world_from_view.transform_point3(view_from_clip.project_point3(ndc.extend(1.0)));
+        let far = world_from_view
+            .transform_point3(view_from_clip.project_point3(ndc.extend(f32::EPSILON)));
+        Ray3d::new(
+            near,
+            Dir3::new(far - near).unwrap_or(camera_transform.forward()),
+        )
+    }
+
+    /// Resolves an aim ray against colliders, returning the entity hit and the hit point
+    ///
+    /// # Arguments
+    /// * `spatial_query` - Spatial query used to find colliders
+    /// * `ray` - Aim ray, such as one from [`CameraController::aim_ray`]
+    /// * `max_distance` - Maximum distance along the ray in world units
+    /// * `filter` - Filter excluding colliders such as the player's own
+    #[cfg(feature = "avian3d")]
+    pub fn resolve_aim(
+        &self,
+        spatial_query: &SpatialQuery,
+        ray: Ray3d,
+        max_distance: f32,
+        filter: &SpatialQueryFilter,
+    ) -> Option<(Entity, Vec3)> {
+        spatial_query
+            .cast_ray(ray.origin, ray.direction, max_distance, true, filter)
+            .map(|hit| (hit.entity, ray.get_point(hit.distance)))
+    }
+
+    /// Saves the current view into a slot during the next update,
+    /// replacing any view already saved there
+    ///
+    /// # Arguments
+    /// * `slot` - Slot to save the view into
+    #[inline]
+    pub fn save_view(&mut self, slot: u32) {
+        self.snapshot_requests.push(SnapshotRequest::Save(slot));
+    }
+
+    /// Restores the view saved in a slot during the next update, blending to it
+    /// over the duration. Does nothing if the slot is empty.
+    ///
+    /// # Arguments
+    /// * `slot` - Slot to restore the view from
+    /// * `blend_duration` - Duration of the blend in seconds
+    #[inline]
+    pub fn restore_view(&mut self, slot: u32, blend_duration: f32) {
+        self.snapshot_requests
+            .push(SnapshotRequest::Restore(slot, blend_duration));
+    }
+
+    /// Returns the view saved in a slot
+    ///
+    /// # Arguments
+    /// * `slot` - Slot to read
+    #[inline]
+    pub fn snapshot(&self, slot: u32) -> Option<&CameraSnapshot> {
+        self.snapshots.get(&slot)
+    }
+
+    /// Removes the view saved in a slot
+    ///
+    /// # Arguments
+    /// * `slot` - Slot to clear
+    #[inline]
+    pub fn clear_snapshot(&mut self, slot: u32) -> Option<CameraSnapshot> {
+        self.snapshots.remove(&slot)
+    }
+
+    /// Animates the controlled camera toward a perspective or orthographic projection.
+    /// Blending toward orthographic narrows the field of view while backing the camera
+    /// away, keeping the plane through the view target, or the controller entity for a free
+    /// view, the same size before swapping projections. Blending back restores the
+    /// perspective projection held before. Reversing mid blend continues from the current state.
+    ///
+    /// # Arguments
+    /// * `mode` - Projection to blend toward
+    /// * `duration` - Duration of a full blend in seconds
+    pub fn blend_projection(&mut self, mode: ProjectionMode, duration: f32) {
+        self.projection_blend.start(mode, duration);
+    }
+
+    /// Returns the projection the controlled camera is blending toward or resting on
+    #[inline]
+    pub fn projection_mode(&self) -> ProjectionMode {
+        self.projection_blend.mode
+    }
+
+    /// Returns the blend in progress after switching anchor or view
+    #[inline]
+    pub fn transition(&self) -> Option<&CameraTransition> {
+        self.transition.as_ref()
+    }
+
+    /// Gets rotation delta for this frame, with smooth decay
+    /// subtracting the delta from the accumulated delta
+    ///
+    /// # Arguments
+    /// * `delta_buffer` - Delta buffer to decay
+    /// * `dt` - Time elapsed since last update in seconds
+    pub fn get_rotation_delta(&self, delta_buffer: &mut CameraBuffer, dt: f32) -> Vec2 {
+        let scale = delta_buffer.region_scale;
+        let sensitivity = self.sensitivity * scale.sensitivity;
+        let smoothing = self.rotation_smoothing.map(|s| s.scaled(scale.smoothing));
+        delta_buffer.smooth_xy(smoothing, dt) * sensitivity
+    }
+
+    /// Gets translation delta for this frame, with smooth decay
+    /// subtracting the delta from the accumulated delta
+    ///
+    /// # Arguments
+    /// * `delta_buffer` - Delta buffer to decay
+    /// * `dt` - Time elapsed since last update in seconds
+    pub fn get_translation_delta(&self, delta_buffer: &mut CameraBuffer, dt: f32) -> Vec2 {
+        let scale = delta_buffer.region_scale;
+        let sensitivity = self.pan_sensitivity.unwrap_or(self.sensitivity) * scale.sensitivity;
+        let smoothing = self.pan_smoothing().map(|s| s.scaled(scale.smoothing));
+        delta_buffer.smooth_xy(smoothing, dt) * sensitivity
+    }
+
+    /// Gets pan delta for this frame, with smooth decay
+    /// subtracting the delta from the accumulated pan
+    ///
+    /// # Arguments
+    /// * `delta_buffer` - Delta buffer to decay
+    /// * `dt` - Time elapsed since last update in seconds
+    pub fn get_pan_delta(&self, delta_buffer: &mut CameraBuffer, dt: f32) -> Vec2 {
+        let scale = delta_buffer.region_scale;
+        let sensitivity = self.pan_sensitivity.unwrap_or(self.sensitivity) * scale.sensitivity;
+        let smoothing = self.pan_smoothing().map(|s| s.scaled(scale.smoothing));
+        delta_buffer.smooth_pan(smoothing, dt) * sensitivity
+    }
+
+    /// Gets zoom delta for this frame, with smooth decay
+    /// subtracting the delta from the accumulated zoom
+    ///
+    /// # Arguments
+    /// * `delta_buffer` - Delta buffer to decay
+    /// * `dt` - Time elapsed since last update in seconds
+    pub fn get_zoom_delta(&self, delta_buffer: &mut CameraBuffer, dt: f32) -> f32 {
+        delta_buffer.smooth_zoom(self.zoom_smoothing, dt) * self.zoom_sensitivity
+    }
+
+    /// Gets roll delta for this frame, with smooth decay using the rotation
+    /// smoothing and subtracting the delta from the accumulated roll
+    ///
+    /// # Arguments
+    /// * `delta_buffer` - Delta buffer to decay
+    /// * `dt` - Time elapsed since last update in seconds
+    pub fn get_roll_delta(&self, delta_buffer: &mut CameraBuffer, dt: f32) -> f32 {
+        let scale = delta_buffer.region_scale;
+        let sensitivity = self.roll_sensitivity * scale.sensitivity;
+        let smoothing = self.rotation_smoothing[0].scaled(scale.smoothing);
+        delta_buffer.smooth_roll(smoothing, dt) * sensitivity
+    }
+
+    /// Gets the roll of a rotation in radians around its forward axis,
+    /// relative to being upright with respect to the yaw axis
+    ///
+    /// # Arguments
+    /// * `rotation` - Camera rotation
+    pub fn get_roll(&self, rotation: Quat) -> f32 {
+        let forward = rotation * Vec3::NEG_Z;
+        let Some(upright) = self
+            .yaw_axis
+            .as_vec3()
+            .reject_from_normalized(forward)
+            .try_normalize()
+        else {
+            return 0.0;
+        };
+        let up = rotation * Vec3::Y;
+        // positive roll banks left, turning the up vector counterclockwise around the view
+        (-forward).dot(upright.cross(up)).atan2(upright.dot(up))
+    }
+
+    /// Checks if a roll rotation would exceed configured angle limits
+    ///
+    /// # Arguments
+    /// * `roll` - Proposed roll rotation in radians
+    /// * `rotation` - Current camera rotation
+    pub fn can_rotate_roll(&self, roll: f32, rotation: Quat) -> bool {
+        match self.roll_limits {
+            Some((min, max)) => {
+                // rotations back toward the limits are allowed when already outside them
+                let current = self.get_roll(rotation);
+                let next = self.get_roll(rotation * Quat::from_rotation_z(roll));
+                !(next < min && next < current || next > max && next > current)
+            }
+            _ => true,
+        }
+    }
+
+    /// Gets rotation delta for this frame with yaw snapped to discrete increments.
+    /// A snap is triggered when the horizontal input rate crosses the threshold,
+    /// and the next snap requires the input to drop back below half the threshold.
+    ///
+    /// # Arguments
+    /// * `snap_turn` - Snap turn configuration
+    /// * `delta_buffer` - Delta buffer to take input from
+    /// * `dt` - Time elapsed since last update in seconds
+    pub fn get_snap_turn_delta(
+        &self,
+        snap_turn: &SnapTurn,
+        delta_buffer: &mut CameraBuffer,
+        dt: f32,
+    ) -> Vec2 {
+        let input = delta_buffer.take();
+        // compare input per second so flicks behave the same at any frame rate
+        let rate = if dt > 0.0 { input.x / dt } else { 0.0 };
+
+        let mut yaw = 0.0;
+        if delta_buffer.snap_latched {
+            if rate.abs() < snap_turn.threshold * 0.5 {
+                delta_buffer.snap_latched = false;
+            }
+        } else if rate.abs() >= snap_turn.threshold {
+            yaw = snap_turn.angle.copysign(rate);
+            delta_buffer.snap_latched = true;
+        }
+        Vec2::new(yaw, input.y * self.sensitivity.y)
+    }
+
+    /// Gets the signed yaw angle around the yaw axis that would turn the given
+    /// rotation to face along the given heading, if both are not parallel to the yaw axis
+    ///
+    /// # Arguments
+    /// * `rotation` - Current camera rotation
+    /// * `heading` - Direction to face
+    pub fn yaw_to_heading(&self, rotation: Quat, heading: Vec3) -> Option<f32> {
+        let axis = self.yaw_axis.as_vec3();
+        let from = (rotation * Vec3::NEG_Z)
+            .reject_from_normalized(axis)
+            .try_normalize()?;
+        let to = heading.reject_from_normalized(axis).try_normalize()?;
+
+        Some(axis.dot(from.cross(to)).atan2(from.dot(to)))
+    }
+
+    /// Checks if a pitch rotation would exceed configured angle limits
+    ///
+    /// # Arguments
+    /// * `pitch` - Proposed pitch rotation in radians
+    /// * `rotation` - Current camera rotation
+    pub fn can_rotate_pitch(&self, pitch: f32, rotation: Quat) -> bool {
+        let rotated = rotation * Quat::from_rotation_x(pitch);
+        let within_range = match self.pitch_range {
+            Some(pitch_range) => {
+                let up = rotated * Vec3::Y;
+                up.dot(self.yaw_axis.as_vec3()) >= pitch_range
+            }
+            _ => true,
+        };
+        let within_limits = match self.pitch_limits {
+            Some((min, max)) => {
+                // rotations back toward the limits are allowed when already outside them
+                let (current, next) = (self.get_pitch(rotation), self.get_pitch(rotated));
+                !(next < min && next < current || next > max && next > current)
+            }
+            _ => true,
+        };
+        within_range && within_limits
+    }
+}
+
+#[derive(Default, Clone, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CameraAnchor {
+    #[default]
+    /// Constrains camera to point with respect to controller for first person control
+    Point,
+    /// Constrains camera to radial orbit around controller to allow for 3D third person control
+    Orbit { distance: f32 },
+    /// Pans the camera from buffered input instead of rotating it, like a crane. Vertical
+    /// input moves along the yaw axis and horizontal input along the camera's local X axis,
+    /// using the pan sensitivity and smoothing. The rotation is left to the view, so a
+    /// [`Target`](CameraView::Target) view keeps looking at its target while panning.
+    Yaw,
+    /// Constrains camera to plane to allow for 2D panning control across plane defined by normal
+    Plane { normal: Dir3 },
+    /// Constrains camera to the [`CameraRail`](super::CameraRail) on the given entity, dolly tracking
+    /// the point nearest to the view target, or the controller entity for a free view
+    Rail(Entity),
+    /// Orbits the controller at a fixed pitch below the horizon, with yaw snapped to one of
+    /// `directions` evenly spaced headings starting from the negative Z axis. Horizontal input
+    /// past half a step turns to the next heading with an animated rotation.
+    Isometric {
+        distance: f32,
+        pitch: f32,
+        directions: u32,
+    },
+}
+
+impl MapEntities for CameraAnchor {
+    fn map_entities<E: EntityMapper>(&mut self, entity_mapper: &mut E) {
+        if let Self::Rail(rail) = self {
+            *rail = entity_mapper.get_mapped(*rail);
+        }
+    }
+}
+
+impl CameraAnchor {
+    /// Creates an isometric anchor with the true isometric pitch of about 35.26 degrees
+    ///
+    /// # Arguments
+    /// * `distance` - Distance from the controller in meters
+    /// * `directions` - Number of snapped headings, such as 4 for 90 degree or 8 for 45 degree turns
+    pub fn isometric(distance: f32, directions: u32) -> Self {
+        Self::Isometric {
+            distance,
+            pitch: core::f32::consts::FRAC_1_SQRT_2.atan(),
+            directions,
+        }
+    }
+}
+
+/// Snapped heading and turn animation of an isometric anchor or a snapping orbit
+#[derive(Clone, Copy)]
+pub(crate) struct IsometricState {
+    /// Index of the heading being turned toward
+    pub step: i32,
+    /// Horizontal input accumulated toward the next step, in radians
+    pub accumulated: f32,
+    /// Yaw the current turn started from, in radians
+    pub from: f32,
+    /// Time elapsed since the current turn started
+    pub elapsed: f32,
+}
+
+impl IsometricState {
+    /// Creates a state resting on the given heading
+    ///
+    /// # Arguments
+    /// * `step` - Index of the heading
+    /// * `step_angle` - Angle between headings in radians
+    pub fn new(step: i32, step_angle: f32) -> Self {
+        Self {
+            step,
+            accumulated: 0.0,
+            from: step as f32 * step_angle,
+            elapsed: f32::INFINITY,
+        }
+    }
+
+    /// Gets the animated yaw in radians
+    ///
+    /// # Arguments
+    /// * `step_angle` - Angle between headings in radians
+    /// * `turn_time` - Duration of a turn in seconds
+    pub fn yaw(&self, step_angle: f32, turn_time: f32) -> f32 {
+        let to = self.step as f32 * step_angle;
+        let t = if turn_time > 0.0 {
+            self.elapsed / turn_time
+        } else {
+            1.0
+        };
+        self.from + (to - self.from) * EaseFunction::SmoothStep.sample_clamped(t)
+    }
+}
+
+/// A look blend from a previous view target, started with [`CameraController::retarget`]
+#[derive(Clone, Copy)]
+pub(crate) struct Retarget {
+    /// Target the look direction blends away from
+    pub previous: Entity,
+    /// Duration of the blend in seconds
+    pub duration: f32,
+    /// Time elapsed since the blend started
+    pub elapsed: f32,
+}
+
+impl Retarget {
+    /// Advances the blend, returning the smoothed weight of the new target
+    ///
+    /// # Arguments
+    /// * `dt` - Time elapsed since last update in seconds
+    pub fn advance(&mut self, dt: f32) -> f32 {
+        self.elapsed += dt;
+        EaseFunction::SmoothStep.sample_clamped(self.elapsed / self.duration)
+    }
+
+    /// Checks whether the blend has finished
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+/// A lateral offset blend started with [`CameraController::swap_shoulder`]
+#[derive(Clone, Copy)]
+pub(crate) struct ShoulderSwap {
+    /// Lateral offset the blend started from
+    pub from: f32,
+    /// Lateral offset the blend ends on
+    pub to: f32,
+    /// Duration of the blend in seconds
+    pub duration: f32,
+    /// Time elapsed since the blend started
+    pub elapsed: f32,
+}
+
+/// A world space orbit pivot set with [`CameraController::set_orbit_pivot`]
+#[derive(Clone, Copy)]
+pub(crate) struct OrbitPivot {
+    /// Pivot the blend started from, or the controller entity's pivot if unset
+    pub from: Option<Vec3>,
+    /// Pivot the blend ends on
+    pub to: Vec3,
+    /// Pivot orbited during the last update
+    pub current: Option<Vec3>,
+    /// Duration of the blend in seconds
+    pub duration: f32,
+    /// Time elapsed since the blend started
+    pub elapsed: f32,
+}
+
+impl OrbitPivot {
+    /// Advances the blend, returning the pivot to orbit
+    ///
+    /// # Arguments
+    /// * `default` - Pivot around the controller entity
+    /// * `dt` - Time elapsed since last update in seconds
+    pub fn advance(&mut self, default: Vec3, dt: f32) -> Vec3 {
+        self.elapsed += dt;
+        let t = if self.duration > 0.0 {
+            self.elapsed / self.duration
+        } else {
+            1.0
+        };
+        let pivot = self
+            .from
+            .unwrap_or(default)
+            .lerp(self.to, EaseFunction::SmoothStep.sample_clamped(t));
+        self.current = Some(pivot);
+        pivot
+    }
+}
+
+/// Cameras slaved to a controller's solved camera pose, see
+/// [`CameraController::with_secondary_camera`]
+#[derive(Clone, Default, Deref, DerefMut, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SecondaryCameras(pub Vec<SecondaryCamera>);
+
+impl MapEntities for SecondaryCameras {
+    fn map_entities<E: EntityMapper>(&mut self, entity_mapper: &mut E) {
+        for secondary in self.0.iter_mut() {
+            secondary.camera = entity_mapper.get_mapped(secondary.camera);
+        }
+    }
+}
+
+/// A camera slaved to a controller's solved camera pose, sharing its smoothing state
+#[derive(Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SecondaryCamera {
+    /// Entity ID of the secondary camera
+    pub camera: Entity,
+    /// Transform applied on top of the solved pose, in the controlled camera's local space
+    pub offset: Transform,
+}
+
+impl SecondaryCamera {
+    /// Creates a new SecondaryCamera
+    ///
+    /// # Arguments
+    /// * `camera` - Entity ID of the secondary camera
+    /// * `offset` - Transform in the controlled camera's local space
+    pub fn new(camera: Entity, offset: Transform) -> Self {
+        Self { camera, offset }
+    }
+
+    /// Creates a pair of secondary cameras offset sideways for a stereo rig
+    ///
+    /// # Arguments
+    /// * `left` - Entity ID of the left eye camera
+    /// * `right` - Entity ID of the right eye camera
+    /// * `separation` - Distance between the eyes
+    pub fn stereo(left: Entity, right: Entity, separation: f32) -> [Self; 2] {
+        let half = separation / 2.0;
+        [
+            Self::new(left, Transform::from_xyz(-half, 0.0, 0.0)),
+            Self::new(right, Transform::from_xyz(half, 0.0, 0.0)),
+        ]
+    }
+}
+
+/// Lean configuration, see [`CameraController::with_lean`]
+#[derive(Clone, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CameraLean {
+    /// Roll in radians at full lean
+    pub max_roll: f32,
+    /// Lateral offset in meters at full lean
+    pub max_offset: f32,
+    /// Rate at which the lean decays toward its target with smooth interpolation
+    decay_rate: f32,
+}
+
+impl CameraLean {
+    /// Creates a new CameraLean with a smoothing of 0.1
+    ///
+    /// # Arguments
+    /// * `max_roll` - Roll in radians at full lean
+    /// * `max_offset` - Lateral offset in meters at full lean
+    pub fn new(max_roll: f32, max_offset: f32) -> Self {
+        Self {
+            max_roll,
+            max_offset,
+            decay_rate: 1.0 / 0.1,
+        }
+    }
+
+    /// Sets smoothing factor for the lean.
+    /// Larger values give smoother movement.
+    ///
+    /// # Arguments
+    /// * `smoothing` - Smoothing factor for leaning
+    #[inline]
+    pub fn with_smoothing(mut self, smoothing: f32) -> Self {
+        self.decay_rate = 1.0 / smoothing;
+        self
+    }
+
+    /// Moves a lean toward its target
+    ///
+    /// # Arguments
+    /// * `lean` - Current lean
+    /// * `target` - Lean to move toward
+    /// * `dt` - Time elapsed since last update in seconds
+    pub(crate) fn smooth(&self, mut lean: f32, target: f32, dt: f32) -> f32 {
+        if self.decay_rate.is_finite() {
+            lean.smooth_nudge(&target, self.decay_rate, dt);
+            lean
+        } else {
+            target
+        }
+    }
+}
+
+/// Speed scaling configuration, see [`CameraController::with_speed_scaling`]
+#[derive(Clone, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpeedScaling {
+    /// Speed in meters per second below which nothing is added
+    pub min_speed: f32,
+    /// Speed in meters per second at which the full amounts are added
+    pub max_speed: f32,
+    /// Field of view in radians added at full speed
+    pub fov: f32,
+    /// Orbit distance in meters added at full speed
+    pub distance: f32,
+    /// Rate at which the scaling decays toward the current speed with smooth interpolation
+    decay_rate: f32,
+}
+
+impl SpeedScaling {
+    /// Creates a new SpeedScaling adding nothing, with a smoothing of 0.3
+    ///
+    /// # Arguments
+    /// * `min_speed` - Speed in meters per second below which nothing is added
+    /// * `max_speed` - Speed in meters per second at which the full amounts are added
+    pub fn new(min_speed: f32, max_speed: f32) -> Self {
+        Self {
+            min_speed: min_speed.min(max_speed),
+            max_speed: max_speed.max(min_speed),
+            fov: 0.0,
+            distance: 0.0,
+            decay_rate: 1.0 / 0.3,
+        }
+    }
+
+    /// Sets the field of view added at full speed
+    ///
+    /// # Arguments
+    /// * `fov` - Field of view in radians
+    #[inline]
+    pub fn with_fov(mut self, fov: f32) -> Self {
+        self.fov = fov;
+        self
+    }
+
+    /// Sets the orbit distance added at full speed
+    ///
+    /// # Arguments
+    /// * `distance` - Orbit distance in meters
+    #[inline]
+    pub fn with_distance(mut self, distance: f32) -> Self {
+        self.distance = distance;
+        self
+    }
+
+    /// Sets smoothing factor for the scaling.
+    /// Larger values give smoother changes.
+    ///
+    /// # Arguments
+    /// * `smoothing` - Smoothing factor for speed scaling
+    #[inline]
+    pub fn with_smoothing(mut self, smoothing: f32) -> Self {
+        self.decay_rate = 1.0 / smoothing;
+        self
+    }
+
+    /// Moves the scaling factor toward the one for the current speed
+    ///
+    /// # Arguments
+    /// * `factor` - Current factor from 0 to 1
+    /// * `speed` - Current speed in meters per second
+    /// * `dt` - Time elapsed since last update in seconds
+    pub(crate) fn smooth(&self, mut factor: f32, speed: f32, dt: f32) -> f32 {
+        let range = self.max_speed - self.min_speed;
+        let target = if range > 0.0 {
+            ((speed - self.min_speed) / range).clamp(0.0, 1.0)
+        } else {
+            (speed >= self.max_speed) as i32 as f32
+        };
+        if self.decay_rate.is_finite() {
+            factor.smooth_nudge(&target, self.decay_rate, dt);
+            factor
+        } else {
+            target
+        }
+    }
+}
+
+/// Orbit yaw snapping configuration, see [`CameraController::with_orbit_snap`]
+#[derive(Clone, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrbitSnap {
+    /// Number of evenly spaced headings the camera snaps to
+    pub directions: u32,
+    /// Duration in seconds of the animated turn to a heading
+    pub turn_time: f32,
+}
+
+impl OrbitSnap {
+    /// Creates a new OrbitSnap with a turn time of 0.25 seconds
+    ///
+    /// # Arguments
+    /// * `directions` - Number of evenly spaced headings
+    pub fn new(directions: u32) -> Self {
+        Self {
+            directions: directions.max(1),
+            turn_time: 0.25,
+        }
+    }
+
+    /// Sets the duration of the animated turn to a heading
+    ///
+    /// # Arguments
+    /// * `turn_time` - Duration in seconds
+    #[inline]
+    pub fn with_turn_time(mut self, turn_time: f32) -> Self {
+        self.turn_time = turn_time;
+        self
+    }
+}
+
+/// Discrete yaw turning configuration, commonly used as a comfort option in XR
+#[derive(Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnapTurn {
+    /// Yaw angle in radians applied per snap
+    pub angle: f32,
+    /// Horizontal input per second required to trigger a snap
+    pub threshold: f32,
+}
+
+impl SnapTurn {
+    /// Creates a new SnapTurn configuration
+    ///
+    /// # Arguments
+    /// * `angle` - Yaw angle in radians applied per snap
+    /// * `threshold` - Horizontal input per second required to trigger a snap
+    pub fn new(angle: f32, threshold: f32) -> Self {
+        Self { angle, threshold }
+    }
+}
+
+/// Automatic orbiting for showroom and menu cameras, see [`CameraController::with_idle_orbit`]
+#[derive(Clone, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IdleOrbit {
+    /// Seconds without rotation input before orbiting starts
+    pub delay: f32,
+    /// Angular speed of the orbit in radians per second, positive values turn counterclockwise
+    pub speed: f32,
+}
+
+impl IdleOrbit {
+    /// Creates a new IdleOrbit configuration
+    ///
+    /// # Arguments
+    /// * `delay` - Seconds without rotation input before orbiting starts
+    /// * `speed` - Angular speed in radians per second
+    pub fn new(delay: f32, speed: f32) -> Self {
+        Self { delay, speed }
+    }
+}
+
+/// Automatic recentering behind the controller entity, see [`CameraController::with_recenter`]
+#[derive(Clone, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Recenter {
+    /// Whether recentering is active, allowing it to be paused without losing the settings
+    pub enabled: bool,
+    /// Seconds without rotation input before recentering starts
+    pub delay: f32,
+    /// Maximum angular speed of the recentering in radians per second
+    pub speed: f32,
+}
+
+impl Recenter {
+    /// Creates a new enabled Recenter configuration
+    ///
+    /// # Arguments
+    /// * `delay` - Seconds without rotation input before recentering starts
+    /// * `speed` - Maximum angular speed in radians per second
+    pub fn new(delay: f32, speed: f32) -> Self {
+        Self {
+            enabled: true,
+            delay,
+            speed,
+        }
+    }
+}
+
+#[derive(Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CameraView {
+    #[default]
+    /// Allows for camera view to be dependent on input
+    Free,
+    /// Constrains camera to look at an Entity
+    Target(Entity),
+}
+
+impl CameraView {
+    /// Builds a filter for queries between the camera and what it follows, excluding the
+    /// controller entity and the view target so neither blocks the camera's own view
+    ///
+    /// # Arguments
+    /// * `controller` - Controller entity
+    /// * `mask` - Collision layers included in the query
+    #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+    pub fn collision_filter(
+        &self,
+        controller: Entity,
+        mask: impl Into<CollisionMask>,
+    ) -> CollisionFilter {
+        let filter = CollisionFilter::from_mask(mask).with_excluded_entities([controller]);
+        match self {
+            Self::Target(target) => filter.with_excluded_entities([*target]),
+            Self::Free => filter,
+        }
+    }
+}
+
+/// How rotation input turns the camera of a [`CameraController`]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RotationMode {
+    /// Yaws around the fixed yaw axis and pitches around the camera's local X axis, keeping
+    /// the horizon level
+    #[default]
+    Turntable,
+    /// Turns the camera like dragging a virtual sphere, around the local axis perpendicular
+    /// to the input, without any axis kept upright, as preferred by CAD and model viewers.
+    /// Pitch limits are not applied.
+    Arcball,
+}
+
+/// What a [`CameraController`] does once its [`CameraView::Target`] no longer exists
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TargetLost {
+    /// Keeps looking the way the camera last looked, until the view is changed
+    Hold,
+    /// Switches to a free view starting from the last rotation
+    #[default]
+    Free,
+}
+
+impl MapEntities for CameraView {
+    fn map_entities<E: EntityMapper>(&mut self, entity_mapper: &mut E) {
+        if let Self::Target(target) = self {
+            *target = entity_mapper.get_mapped(*target);
+        }
+    }
+}
+
+/// A buffer component that stores and manages data for the controller to use
+/// contains fields that are expected to be frequently mutated
+#[derive(Component, Default, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CameraBuffer {
+    /// The current accumulated 2D input from mouse or joystick
+    input: DeltaBuffer<Vec2>,
+    /// The current accumulated zoom input from a scroll wheel or trigger,
+    /// positive values move the camera closer
+    zoom: DeltaBuffer<f32>,
+    /// The current accumulated roll input, positive values bank left
+    roll: DeltaBuffer<f32>,
+    /// The current accumulated pan input, moving the camera or the orbited point across the
+    /// view instead of turning it
+    pan: DeltaBuffer<Vec2>,
+    /// World space offset of the orbited point from the controller entity, accumulated
+    /// from pan input
+    pub(crate) pan_offset: Vec3,
+    /// The current rotation that would allow camera to point the desired direction.
+    /// To allow for targetting functionallity, this variable is used for rotation
+    /// control independent of the current camera orientation
+    pub(crate) rotation: Quat,
+    /// Whether rotation input was added since the buffer was last consumed
+    received_input: bool,
+    /// Seconds since rotation input was last added
+    pub(crate) idle_time: f32,
+    /// Current smoothed lean
+    pub(crate) lean: f32,
+    /// Lean offset applied to the camera during the last update
+    pub(crate) lean_applied: Option<(Vec3, Quat)>,
+    /// Kick rotation applied to the camera during the last update
+    pub(crate) kick_applied: Option<Quat>,
+    /// Smoothed speed scaling factor from 0 to 1
+    pub(crate) speed_factor: f32,
+    /// Position of the controller entity, or the target a 2D controller follows, during the
+    /// last update, used to measure its speed and detect teleports
+    pub(crate) previous_translation: Option<Vec3>,
+    /// Whether a snap turn has been triggered and input has not yet been released
+    pub(crate) snap_latched: bool,
+    /// Named input channels that are summed into the input when consumed
+    #[cfg_attr(feature = "serde", serde(skip))]
+    channels: Vec<BufferChannel>,
+    /// Combined scale of the regions the controller entity is inside
+    pub(crate) region_scale: RegionScale,
+    /// Whether the camera was pushed by collision during the last update
+    pub(crate) colliding: bool,
+    /// Anchor during the last update, used to report anchor changes
+    pub(crate) anchor: Option<CameraAnchor>,
+    /// View during the last update, used to report view changes
+    pub(crate) view: Option<CameraView>,
+    /// View target reported lost, so it is only reported once
+    pub(crate) lost_target: Option<Entity>,
+    /// Whether zoom was stopped by a distance limit during the last update
+    pub(crate) zoom_limited: bool,
+    /// Curve parameter of the camera along its rail
+    pub(crate) rail_parameter: Option<f32>,
+    /// Smoothed 2D camera center before pixel snapping
+    pub(crate) unsnapped_center: Option<Vec2>,
+    /// Velocities of the smoothed channels
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) velocity: SmoothingVelocity,
+    /// Rotation speed in radians per second kept spinning by inertia
+    pub(crate) angular_velocity: Vec2,
+    /// Snapped heading of an isometric anchor
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) isometric: Option<IsometricState>,
+    /// Snapped heading of a snapping orbit
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) orbit_snap: Option<IsometricState>,
+    /// Unobstructed orbit distance and spring arm length from the last update
+    #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+    pub(crate) spring_arm: Option<(f32, f32)>,
+}
+
+/// A named input channel of a [`CameraBuffer`], allowing multiple systems
+/// to contribute input that can be scaled and toggled independently
+#[derive(Clone, Debug, Reflect)]
+pub struct BufferChannel {
+    /// Name identifying the writer of the channel
+    pub name: &'static str,
+    /// Input accumulated since the last consumption
+    input: Vec2,
+    /// Multiplier applied to the channel input when summed
+    pub scale: f32,
+    /// Whether the channel contributes input, disabled channels discard their input
+    pub enabled: bool,
+}
+
+impl BufferChannel {
+    /// Returns the input accumulated since the last consumption, without scaling
+    #[inline]
+    pub fn read(&self) -> Vec2 {
+        self.input
+    }
+}
+
+impl CameraBuffer {
+    /// Clamps the accumulated look input to a largest magnitude, so input piling up during
+    /// hitches or while paused cannot whip the camera around
+    ///
+    /// # Arguments
+    /// * `max_magnitude` - Largest magnitude of the accumulated look input
+    #[inline]
+    pub fn with_max_magnitude(mut self, max_magnitude: f32) -> Self {
+        self.input.set_max_magnitude(Some(max_magnitude));
+        self
+    }
+
+    /// Drops accumulated look, zoom, roll and pan input once no new input has been added for
+    /// longer than an age, including while the controller is paused. This also cuts off
+    /// the tail of smoothed input.
+    ///
+    /// # Arguments
+    /// * `max_age` - Longest time input is kept without new input
+    #[inline]
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.input.set_max_age(Some(max_age));
+        self.zoom.set_max_age(Some(max_age));
+        self.roll.set_max_age(Some(max_age));
+        self.pan.set_max_age(Some(max_age));
+        self
+    }
+
+    /// Advances the time since input was last added, dropping input that has gone without
+    /// new input for longer than the max age
+    ///
+    /// # Arguments
+    /// * `dt` - Time elapsed since last update in seconds
+    pub fn expire(&mut self, dt: f32) {
+        self.input.expire(dt);
+        self.zoom.expire(dt);
+        self.roll.expire(dt);
+        self.pan.expire(dt);
+    }
+
+    /// Adds the given delta to the buffer's current value
+    #[inline]
+    pub fn update(&mut self, delta: Vec2) {
+        self.input.update(delta);
+        self.received_input |= delta != Vec2::ZERO;
+    }
+
+    /// Subtracts the given delta from the buffer's current value
+    #[inline]
+    pub fn consume(&mut self, delta: Vec2) {
+        self.input.consume(delta);
+    }
+
+    /// Resets the buffer's delta value to zero
+    #[inline]
+    pub fn reset(&mut self) {
+        self.input.reset();
+    }
+
+    /// Resets the buffer's delta, zoom, roll and pan values and the pending input of all
+    /// channels
+    pub fn clear(&mut self) {
+        self.reset();
+        self.zoom.reset();
+        self.roll.reset();
+        self.pan.reset();
+        for channel in self.channels.iter_mut() {
+            channel.input = Vec2::ZERO;
+        }
+    }
+
+    /// Fades the buffer's delta, zoom, roll and pan values and the pending input of all
+    /// channels, discarding the faded part
+    ///
+    /// # Arguments
+    /// * `rate` - The rate at which to fade the values
+    /// * `dt` - The time increment
+    pub fn fade(&mut self, rate: f32, dt: f32) {
+        self.flush_channels();
+        self.input.decay(rate, dt);
+        self.zoom.decay(rate, dt);
+        self.roll.decay(rate, dt);
+        self.pan.decay(rate, dt);
+    }
+
+    /// Returns the current delta value without modifying it
+    #[inline]
+    pub fn read(&self) -> Vec2 {
+        self.input.read()
+    }
+
+    /// Reduces the delta value using smooth interpolation with separate rates per axis,
+    /// taking the whole value along axes with an infinite rate
+    ///
+    /// # Arguments
+    /// * `rate` - The rates at which to decay each axis
+    /// * `dt` - The time increment
+    #[inline]
+    pub fn decay_xy(&mut self, rate: Vec2, dt: f32) -> Vec2 {
+        let input = self.input.read();
+        let mut consumed = input;
+        for axis in 0..2 {
+            if rate[axis].is_finite() {
+                consumed[axis] = 0.0;
+                consumed[axis].smooth_nudge(&input[axis], rate[axis], dt);
+            }
+        }
+        self.consume(consumed);
+        consumed
+    }
+
+    /// Consumes part of the delta value using a response model per axis
+    ///
+    /// # Arguments
+    /// * `smoothing` - Response models for each axis
+    /// * `dt` - The time increment
+    pub fn smooth_xy(&mut self, smoothing: [Smoothing; 2], dt: f32) -> Vec2 {
+        let input = self.input.read();
+        let mut consumed = Vec2::ZERO;
+        for axis in 0..2 {
+            consumed[axis] =
+                smoothing[axis].step(0.0, input[axis], &mut self.velocity.input[axis], dt);
+        }
+        self.consume(consumed);
+        consumed
+    }
+
+    /// Adds the given delta to the buffer's zoom value,
+    /// positive values move the camera closer
+    #[inline]
+    pub fn update_zoom(&mut self, delta: f32) {
+        self.zoom.update(delta);
+    }
+
+    /// Returns the current zoom value without modifying it
+    #[inline]
+    pub fn read_zoom(&self) -> f32 {
+        self.zoom.read()
+    }
+
+    /// Returns the current zoom value and resets it
+    #[inline]
+    pub fn take_zoom(&mut self) -> f32 {
+        self.zoom.take()
+    }
+
+    /// Reduces the zoom value using smooth interpolation
+    ///
+    /// # Arguments
+    /// * `rate` - The rate at which to decay the value
+    /// * `dt` - The time increment
+    #[inline]
+    pub fn decay_zoom(&mut self, rate: f32, dt: f32) -> f32 {
+        self.zoom.decay(rate, dt)
+    }
+
+    /// Consumes part of the zoom value using a response model
+    ///
+    /// # Arguments
+    /// * `smoothing` - Response model for zoom
+    /// * `dt` - The time increment
+    pub fn smooth_zoom(&mut self, smoothing: Smoothing, dt: f32) -> f32 {
+        let consumed = smoothing.step(0.0, self.zoom.read(), &mut self.velocity.zoom, dt);
+        self.zoom.consume(consumed);
+        consumed
+    }
+
+    /// Adds the given delta to the buffer's pan value, which moves the
+    /// [`Yaw`](CameraAnchor::Yaw) and [`Plane`](CameraAnchor::Plane) anchors like look input
+    /// and slides the point the [`Orbit`](CameraAnchor::Orbit) anchor orbits across the view,
+    /// without ever turning the camera. The orbited point keeps following the controller
+    /// entity at the panned offset until [`reset_pan_offset`](Self::reset_pan_offset)
+    #[inline]
+    pub fn update_pan(&mut self, delta: Vec2) {
+        self.pan.update(delta);
+    }
+
+    /// Returns the current pan value without modifying it
+    #[inline]
+    pub fn read_pan(&self) -> Vec2 {
+        self.pan.read()
+    }
+
+    /// Returns the current pan value and resets it
+    #[inline]
+    pub fn take_pan(&mut self) -> Vec2 {
+        self.pan.take()
+    }
+
+    /// Consumes part of the pan value using a response model per axis
+    ///
+    /// # Arguments
+    /// * `smoothing` - Response models for each axis
+    /// * `dt` - The time increment
+    pub fn smooth_pan(&mut self, smoothing: [Smoothing; 2], dt: f32) -> Vec2 {
+        let input = self.pan.read();
+        let mut consumed = Vec2::ZERO;
+        for axis in 0..2 {
+            consumed[axis] =
+                smoothing[axis].step(0.0, input[axis], &mut self.velocity.pan[axis], dt);
+        }
+        self.pan.consume(consumed);
+        consumed
+    }
+
+    /// Adds the given delta to the buffer's roll value,
+    /// positive values bank the camera left
+    #[inline]
+    pub fn update_roll(&mut self, delta: f32) {
+        self.roll.update(delta);
+    }
+
+    /// Returns the current roll value without modifying it
+    #[inline]
+    pub fn read_roll(&self) -> f32 {
+        self.roll.read()
+    }
+
+    /// Returns the current roll value and resets it
+    #[inline]
+    pub fn take_roll(&mut self) -> f32 {
+        self.roll.take()
+    }
+
+    /// Reduces the roll value using smooth interpolation
+    ///
+    /// # Arguments
+    /// * `rate` - The rate at which to decay the value
+    /// * `dt` - The time increment
+    #[inline]
+    pub fn decay_roll(&mut self, rate: f32, dt: f32) -> f32 {
+        self.roll.decay(rate, dt)
+    }
+
+    /// Consumes part of the roll value using a response model
+    ///
+    /// # Arguments
+    /// * `smoothing` - Response model for roll
+    /// * `dt` - The time increment
+    pub fn smooth_roll(&mut self, smoothing: Smoothing, dt: f32) -> f32 {
+        let consumed = smoothing.step(0.0, self.roll.read(), &mut self.velocity.roll, dt);
+        self.roll.consume(consumed);
+        consumed
+    }
+
+    /// Adds the given delta to a named input channel, creating the channel if needed
+    ///
+    /// # Arguments
+    /// * `name` - Name of the channel
+    /// * `delta` - Delta to add to the channel
+    pub fn update_channel(&mut self, name: &'static str, delta: Vec2) {
+        self.channel_mut(name).input += delta;
+    }
+
+    /// Sets the multiplier applied to a named channel, creating the channel if needed
+    ///
+    /// # Arguments
+    /// * `name` - Name of the channel
+    /// * `scale` - Multiplier applied to the channel input
+    pub fn set_channel_scale(&mut self, name: &'static str, scale: f32) {
+        self.channel_mut(name).scale = scale;
+    }
+
+    /// Enables or disables a named channel, creating the channel if needed.
+    /// Disabling a channel discards its pending input.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the channel
+    /// * `enabled` - Whether the channel contributes input
+    pub fn set_channel_enabled(&mut self, name: &'static str, enabled: bool) {
+        let channel = self.channel_mut(name);
+        channel.enabled = enabled;
+        if !enabled {
+            channel.input = Vec2::ZERO;
+        }
+    }
+
+    /// Returns the named channel if it exists
+    #[inline]
+    pub fn channel(&self, name: &str) -> Option<&BufferChannel> {
+        self.channels.iter().find(|channel| channel.name == name)
+    }
+
+    /// Returns all channels of the buffer
+    #[inline]
+    pub fn channels(&self) -> &[BufferChannel] {
+        &self.channels
+    }
+
+    /// Removes the named channel, discarding its pending input
+    pub fn remove_channel(&mut self, name: &str) {
+        self.channels.retain(|channel| channel.name != name);
+    }
+
+    /// Sums the scaled input of all enabled channels into the buffer's value
+    /// and clears the channels, disabled channels have their input discarded
+    pub fn flush_channels(&mut self) {
+        for channel in self.channels.iter_mut() {
+            if channel.enabled {
+                self.input.update(channel.input * channel.scale);
+                self.received_input |= channel.input != Vec2::ZERO;
+            }
+            channel.input = Vec2::ZERO;
+        }
+    }
+
+    fn channel_mut(&mut self, name: &'static str) -> &mut BufferChannel {
+        let index = match self
+            .channels
+            .iter()
+            .position(|channel| channel.name == name)
+        {
+            Some(index) => index,
+            None => {
+                self.channels.push(BufferChannel {
+                    name,
+                    input: Vec2::ZERO,
+                    scale: 1.0,
+                    enabled: true,
+                });
+                self.channels.len() - 1
+            }
+        };
+        &mut self.channels[index]
+    }
+
+    /// Returns the combined scale of the regions the controller entity is inside
+    #[inline]
+    pub fn region_scale(&self) -> RegionScale {
+        self.region_scale
+    }
+
+    /// Advances the idle time, restarting it if rotation input was added since the last call
+    ///
+    /// # Arguments
+    /// * `dt` - Time elapsed since last update in seconds
+    pub(crate) fn update_idle_time(&mut self, dt: f32) {
+        if core::mem::take(&mut self.received_input) {
+            self.idle_time = 0.0;
+        } else {
+            self.idle_time += dt;
+        }
+    }
+
+    /// Measures the rotation speed while input is received, and once it stops returns the
+    /// rotation to keep spinning by this frame, slowing with friction
+    ///
+    /// # Arguments
+    /// * `delta` - Rotation from input this frame in radians
+    /// * `friction` - Rate at which the spin slows
+    /// * `dt` - Time elapsed since last update in seconds
+    pub(crate) fn spin(&mut self, delta: Vec2, friction: f32, dt: f32) -> Vec2 {
+        if dt <= 0.0 {
+            return delta;
+        }
+        if self.idle_time == 0.0 {
+            // follow the speed of the last few frames rather than a single one
+            self.angular_velocity
+                .smooth_nudge(&(delta / dt), SPIN_VELOCITY_DECAY_RATE, dt);
+            return delta;
+        }
+        if friction.is_finite() {
+            self.angular_velocity *= (-friction * dt).exp();
+        } else {
+            self.angular_velocity = Vec2::ZERO;
+        }
+        if self.angular_velocity.length() < MIN_SPIN_SPEED {
+            self.angular_velocity = Vec2::ZERO;
+        }
+        self.angular_velocity * dt
+    }
+
+    /// Stops any spin kept by inertia
+    #[inline]
+    pub fn stop_spin(&mut self) {
+        self.angular_velocity = Vec2::ZERO;
+    }
+
+    /// Returns the seconds since rotation input was last added to the buffer
+    #[inline]
+    pub fn idle_time(&self) -> f32 {
+        self.idle_time
+    }
+
+    /// Returns the world space offset of the orbited point from the controller entity,
+    /// accumulated from pan input
+    #[inline]
+    pub fn pan_offset(&self) -> Vec3 {
+        self.pan_offset
+    }
+
+    /// Recenters a panned orbit on the controller entity, also done when leaving the
+    /// [`Orbit`](CameraAnchor::Orbit) anchor
+    #[inline]
+    pub fn reset_pan_offset(&mut self) {
+        self.pan_offset = Vec3::ZERO;
+    }
+
+    /// Returns whether the camera was pushed by collision during the last update
+    #[inline]
+    pub fn is_colliding(&self) -> bool {
+        self.colliding
+    }
+
+    /// Returns the rotation the controller is currently steering towards
+    #[inline]
+    pub fn rotation(&self) -> Quat {
+        self.rotation
+    }
+
+    /// Returns the current delta value and resets the buffer
+    #[inline]
+    pub fn take(&mut self) -> Vec2 {
+        self.input.take()
+    }
+
+    /// Reduces the delta value using smooth interpolation
+    ///
+    /// # Arguments
+    /// * `rate` - The rate at which to decay the value
+    /// * `dt` - The time increment
+    #[inline]
+    pub fn decay(&mut self, rate: f32, dt: f32) -> Vec2 {
+        self.input.decay(rate, dt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f32::consts::FRAC_PI_4;
+
+    use super::*;
+
+    /// Feeds one frame of horizontal input through the snap turn, returning the snapped yaw
+    fn snap(controller: &CameraController, buffer: &mut CameraBuffer, input: f32) -> f32 {
+        let snap_turn = SnapTurn::new(FRAC_PI_4, 2.0);
+        buffer.update(Vec2::new(input, 0.0));
+        controller.get_snap_turn_delta(&snap_turn, buffer, 0.1).x
+    }
+
+    #[test]
+    fn snap_turn_triggers_once_per_flick() {
+        let controller =
+            CameraController::new(Entity::PLACEHOLDER, CameraAnchor::Point, CameraView::Free);
+        let mut buffer = CameraBuffer::default();
+
+        // below the threshold of 2 per second
+        assert_eq!(snap(&controller, &mut buffer, 0.1), 0.0);
+        // a flick snaps in the direction of the input
+        assert_eq!(snap(&controller, &mut buffer, -0.3), -FRAC_PI_4);
+        // holding the stick does not snap again
+        assert_eq!(snap(&controller, &mut buffer, -0.3), 0.0);
+        assert_eq!(snap(&controller, &mut buffer, -0.15), 0.0);
+        // releasing below half the threshold rearms the next flick
+        assert_eq!(snap(&controller, &mut buffer, 0.05), 0.0);
+        assert_eq!(snap(&controller, &mut buffer, 0.2), FRAC_PI_4);
+    }
+
+    #[test]
+    fn snap_turn_ignores_zero_time_steps() {
+        let controller =
+            CameraController::new(Entity::PLACEHOLDER, CameraAnchor::Point, CameraView::Free);
+        let mut buffer = CameraBuffer::default();
+        buffer.update(Vec2::new(1.0, 0.0));
+
+        let delta =
+            controller.get_snap_turn_delta(&SnapTurn::new(FRAC_PI_4, 2.0), &mut buffer, 0.0);
+        assert_eq!(delta.x, 0.0);
     }
 }
@@ -0,0 +1,565 @@
+use bevy::prelude::*;
+use core::time::Duration;
+
+#[cfg(feature = "avian2d")]
+use super::CameraCollision2d;
+use super::{
+    CameraBuffer, CameraTimeSource, Smoothing,
+    smoothing::{decay_to_half_life, half_life_to_decay},
+};
+
+/// A 2D camera controller component that follows a target or pans from buffered input,
+/// driving the translation, rotation and orthographic scale of a `Camera2d` with smooth interpolation.
+///
+/// Rotation around the Z axis is driven by the buffer's roll input, see [`CameraBuffer::update_roll`],
+/// and panning and following happen along the rotated screen axes.
+#[derive(Component)]
+#[require(CameraBuffer)]
+pub struct CameraController2d {
+    /// Entity ID of the camera being controlled
+    pub camera: Entity,
+    /// View configuration for the camera
+    pub view: CameraView2d,
+    /// Sensitivity of manual panning
+    pub sensitivity: f32,
+    /// Offset of the followed point from the target along the screen axes, in meters
+    pub offset: Vec2,
+    /// Optional rectangular dead zone replacing the radial follow distance
+    pub follow_window: Option<FollowWindow>,
+    /// Rate at which horizontal and vertical translation decay with smooth interpolation
+    translation_decay_rate: Vec2,
+    /// Rate at which zoom decays with smooth interpolation
+    zoom_decay_rate: f32,
+    /// Sensitivity of rotation input, in radians per unit of roll input
+    pub rotation_sensitivity: f32,
+    /// Rate at which rotation decays with smooth interpolation
+    rotation_decay_rate: f32,
+    /// Optional angle in radians the rotation snaps to multiples of
+    pub rotation_snap: Option<f32>,
+    /// Rotation around the Z axis in radians the camera turns toward, before snapping
+    pub(crate) rotation: f32,
+    /// Orthographic scale the camera zooms toward
+    pub(crate) zoom: f32,
+    /// Optional minimum and maximum orthographic scale reachable by zooming
+    pub(crate) zoom_limits: Option<(f32, f32)>,
+    /// Viewport position kept stationary while zooming, or the screen center if unset
+    pub(crate) zoom_anchor: Option<Vec2>,
+    /// Optional number of pixels per world unit the final translation is snapped to
+    pub pixel_snap: Option<f32>,
+    /// Clock the controller advances its smoothing with, also used by the shake, drag pan,
+    /// gamepad look and paused input systems acting on it
+    pub time_source: CameraTimeSource,
+    /// Optional fastest the camera moves in meters per second, after smoothing
+    pub max_speed: Option<f32>,
+    /// Optional fastest the camera turns in radians per second, after smoothing
+    pub max_angular_speed: Option<f32>,
+    /// Optional distance in meters the followed target can jump in one update before the
+    /// camera snaps after it instead of smoothing across
+    pub teleport_distance: Option<f32>,
+    /// Whether [`CameraController2d::snap_to_target`] was called since the last update
+    snap_requested: bool,
+    /// Optional collision keeping the camera center from passing through level geometry
+    #[cfg(feature = "avian2d")]
+    pub collision: Option<CameraCollision2d>,
+}
+
+impl CameraController2d {
+    /// Creates a new CameraController2d instance with default settings:
+    /// - Sensitivity: 1.0
+    /// - No offset
+    /// - No follow window
+    /// - No smoothing (instant movement)
+    /// - Zoom: 1.0, without limits
+    /// - Rotation sensitivity: 1.0, without snapping
+    /// - No pixel snapping
+    ///
+    /// # Arguments
+    /// * `camera` - Entity ID of the camera to control
+    /// * `view` - The initial view configuration for the camera
+    pub fn new(camera: Entity, view: CameraView2d) -> Self {
+        Self {
+            camera,
+            view,
+
+            sensitivity: 1.0,
+            offset: Vec2::ZERO,
+            follow_window: None,
+
+            translation_decay_rate: Vec2::INFINITY,
+            zoom_decay_rate: f32::INFINITY,
+            zoom: 1.0,
+            zoom_limits: None,
+            rotation_sensitivity: 1.0,
+            rotation_decay_rate: f32::INFINITY,
+            rotation_snap: None,
+            rotation: 0.0,
+            pixel_snap: None,
+            zoom_anchor: None,
+            time_source: CameraTimeSource::Virtual,
+            max_speed: None,
+            max_angular_speed: None,
+            teleport_distance: None,
+            snap_requested: false,
+            #[cfg(feature = "avian2d")]
+            collision: None,
+        }
+    }
+
+    #[inline]
+    pub fn get_translation_decay_rate(&self) -> Vec2 {
+        self.translation_decay_rate
+    }
+
+    #[inline]
+    pub fn get_zoom_decay_rate(&self) -> f32 {
+        self.zoom_decay_rate
+    }
+
+    #[inline]
+    pub fn get_rotation_decay_rate(&self) -> f32 {
+        self.rotation_decay_rate
+    }
+
+    /// Returns the horizontal and vertical time translation takes to cover half the
+    /// remaining distance to its goal
+    #[inline]
+    pub fn translation_half_life(&self) -> [Duration; 2] {
+        self.translation_decay_rate
+            .to_array()
+            .map(decay_to_half_life)
+    }
+
+    /// Returns the time zoom takes to cover half the remaining distance to its goal
+    #[inline]
+    pub fn zoom_half_life(&self) -> Duration {
+        decay_to_half_life(self.zoom_decay_rate)
+    }
+
+    /// Returns the time rotation takes to cover half the remaining angle to its goal
+    #[inline]
+    pub fn rotation_half_life(&self) -> Duration {
+        decay_to_half_life(self.rotation_decay_rate)
+    }
+
+    /// Sets the sensitivity multiplier for manual panning
+    ///
+    /// # Arguments
+    /// * `sensitivity` - Multiplier for panning sensitivity
+    #[inline]
+    pub fn with_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.sensitivity = sensitivity;
+        self
+    }
+
+    /// Sets the offset of the followed point from the target
+    ///
+    /// # Arguments
+    /// * `offset` - 2D vector offset in meters
+    #[inline]
+    pub fn with_offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the clock the controller advances its smoothing with, such as real time so
+    /// slow motion does not slow the camera down
+    ///
+    /// # Arguments
+    /// * `time_source` - Clock to advance with
+    #[inline]
+    pub fn with_time_source(mut self, time_source: CameraTimeSource) -> Self {
+        self.time_source = time_source;
+        self
+    }
+
+    /// Sets a rectangular dead zone, so the camera only moves when the target leaves it
+    ///
+    /// # Arguments
+    /// * `follow_window` - Dead zone configuration
+    #[inline]
+    pub fn with_follow_window(mut self, follow_window: FollowWindow) -> Self {
+        self.follow_window = Some(follow_window);
+        self
+    }
+
+    /// Sets smoothing factor for both translation and zoom.
+    /// Larger values give smoother movement.
+    ///
+    /// # Arguments
+    /// * `smoothing` - Smoothing factor for camera movement
+    #[inline]
+    pub fn with_smoothing(mut self, smoothing: f32) -> Self {
+        let decay_rate = 1.0 / smoothing;
+        self.translation_decay_rate = Vec2::splat(decay_rate);
+        self.zoom_decay_rate = decay_rate;
+        self
+    }
+
+    /// Sets smoothing for both translation and zoom by the time taken to cover half the
+    /// remaining distance to the goal, where zero moves instantly
+    ///
+    /// # Arguments
+    /// * `half_life` - Time to cover half the remaining distance
+    #[inline]
+    pub fn with_smoothing_half_life(mut self, half_life: Duration) -> Self {
+        let decay_rate = half_life_to_decay(half_life);
+        self.translation_decay_rate = Vec2::splat(decay_rate);
+        self.zoom_decay_rate = decay_rate;
+        self
+    }
+
+    /// Sets smoothing for translation only by the time taken to cover half the remaining
+    /// distance to the goal
+    ///
+    /// # Arguments
+    /// * `half_life` - Time to cover half the remaining distance
+    #[inline]
+    pub fn with_translation_half_life(mut self, half_life: Duration) -> Self {
+        self.translation_decay_rate = Vec2::splat(half_life_to_decay(half_life));
+        self
+    }
+
+    /// Sets smoothing factor for translation only.
+    /// Larger values give smoother movement.
+    ///
+    /// # Arguments
+    /// * `smoothing` - Smoothing factor for translation movement
+    #[inline]
+    pub fn with_translation_smoothing(mut self, smoothing: f32) -> Self {
+        self.translation_decay_rate = Vec2::splat(1.0 / smoothing);
+        self
+    }
+
+    /// Sets separate horizontal and vertical smoothing factors for translation.
+    /// Larger values give smoother movement.
+    ///
+    /// # Arguments
+    /// * `smoothing` - Horizontal and vertical smoothing factors
+    #[inline]
+    pub fn with_translation_smoothing_xy(mut self, smoothing: Vec2) -> Self {
+        self.translation_decay_rate = smoothing.recip();
+        self
+    }
+
+    /// Sets smoothing factor for zoom only.
+    /// Larger values give smoother movement.
+    ///
+    /// # Arguments
+    /// * `smoothing` - Smoothing factor for zoom
+    #[inline]
+    pub fn with_zoom_smoothing(mut self, smoothing: f32) -> Self {
+        self.zoom_decay_rate = 1.0 / smoothing;
+        self
+    }
+
+    /// Sets smoothing for zoom only by the time taken to cover half the remaining
+    /// distance to the goal
+    ///
+    /// # Arguments
+    /// * `half_life` - Time to cover half the remaining distance
+    #[inline]
+    pub fn with_zoom_half_life(mut self, half_life: Duration) -> Self {
+        self.zoom_decay_rate = half_life_to_decay(half_life);
+        self
+    }
+
+    /// Snaps the final camera translation to the pixel grid along the screen axes.
+    /// Smoothing continues from the unsnapped translation, so motion stays fluid
+    /// while sprites no longer shimmer.
+    ///
+    /// # Arguments
+    /// * `pixels_per_unit` - Number of pixels in one world unit
+    #[inline]
+    pub fn with_pixel_snap(mut self, pixels_per_unit: f32) -> Self {
+        self.pixel_snap = Some(pixels_per_unit);
+        self
+    }
+
+    /// Keeps the camera center from passing through level geometry between it and the
+    /// followed point, or from panning through walls in the manual view
+    ///
+    /// # Arguments
+    /// * `collision` - Collision settings
+    #[cfg(feature = "avian2d")]
+    #[inline]
+    pub fn with_collision(mut self, collision: CameraCollision2d) -> Self {
+        self.collision = Some(collision);
+        self
+    }
+
+    /// Snaps a translation to the pixel grid if enabled
+    ///
+    /// # Arguments
+    /// * `translation` - Translation in world units
+    /// * `rotation` - Rotation of the screen axes
+    pub fn snap_translation(&self, translation: Vec2, rotation: Rot2) -> Vec2 {
+        match self.pixel_snap {
+            Some(pixels_per_unit) if pixels_per_unit > 0.0 => {
+                let local = rotation.inverse() * translation;
+                rotation * ((local * pixels_per_unit).round() / pixels_per_unit)
+            }
+            _ => translation,
+        }
+    }
+
+    /// Sets the sensitivity of rotation input
+    ///
+    /// # Arguments
+    /// * `sensitivity` - Radians turned per unit of roll input
+    #[inline]
+    pub fn with_rotation_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.rotation_sensitivity = sensitivity;
+        self
+    }
+
+    /// Sets smoothing factor for rotation only.
+    /// Larger values give smoother movement.
+    ///
+    /// # Arguments
+    /// * `smoothing` - Smoothing factor for rotation
+    #[inline]
+    pub fn with_rotation_smoothing(mut self, smoothing: f32) -> Self {
+        self.rotation_decay_rate = 1.0 / smoothing;
+        self
+    }
+
+    /// Sets smoothing for rotation only by the time taken to cover half the remaining
+    /// angle to the goal
+    ///
+    /// # Arguments
+    /// * `half_life` - Time to cover half the remaining angle
+    #[inline]
+    pub fn with_rotation_half_life(mut self, half_life: Duration) -> Self {
+        self.rotation_decay_rate = half_life_to_decay(half_life);
+        self
+    }
+
+    /// Limits how fast the camera moves, so a target jumping across the level is chased
+    /// rather than followed in a single frame
+    ///
+    /// # Arguments
+    /// * `speed` - Speed in meters per second
+    #[inline]
+    pub fn with_max_speed(mut self, speed: f32) -> Self {
+        self.max_speed = Some(speed);
+        self
+    }
+
+    /// Limits how fast the camera turns
+    ///
+    /// # Arguments
+    /// * `speed` - Speed in radians per second
+    #[inline]
+    pub fn with_max_angular_speed(mut self, speed: f32) -> Self {
+        self.max_angular_speed = Some(speed);
+        self
+    }
+
+    /// Snaps the camera after the followed target when it jumps further than a distance in
+    /// one update, such as when respawning, triggering
+    /// [`CameraTargetTeleported`](super::CameraTargetTeleported)
+    ///
+    /// # Arguments
+    /// * `distance` - Distance in meters
+    #[inline]
+    pub fn with_teleport_distance(mut self, distance: f32) -> Self {
+        self.teleport_distance = Some(distance);
+        self
+    }
+
+    /// Moves, zooms and turns the camera straight to its goal on the next update, skipping
+    /// smoothing and speed limits
+    #[inline]
+    pub fn snap_to_target(&mut self) {
+        self.snap_requested = true;
+    }
+
+    /// Takes whether a snap was requested since the last update
+    #[inline]
+    pub(crate) fn take_snap_request(&mut self) -> bool {
+        std::mem::take(&mut self.snap_requested)
+    }
+
+    /// Snaps the rotation to multiples of an angle, turning once input passes half a step
+    ///
+    /// # Arguments
+    /// * `angle` - Snap angle in radians
+    #[inline]
+    pub fn with_rotation_snap(mut self, angle: f32) -> Self {
+        self.rotation_snap = Some(angle);
+        self
+    }
+
+    /// Sets the rotation the camera turns toward
+    ///
+    /// # Arguments
+    /// * `rotation` - Rotation around the Z axis in radians
+    #[inline]
+    pub fn set_rotation(&mut self, rotation: f32) {
+        self.rotation = rotation;
+    }
+
+    /// Returns the rotation the camera turns toward, snapped if enabled
+    #[inline]
+    pub fn target_rotation(&self) -> f32 {
+        match self.rotation_snap {
+            Some(angle) if angle > 0.0 => (self.rotation / angle).round() * angle,
+            _ => self.rotation,
+        }
+    }
+
+    /// Sets the minimum and maximum orthographic scale reachable by zooming,
+    /// clamping the current zoom into range
+    ///
+    /// # Arguments
+    /// * `min` - Minimum orthographic scale, the closest zoom
+    /// * `max` - Maximum orthographic scale, the furthest zoom
+    #[inline]
+    pub fn with_zoom_limits(mut self, min: f32, max: f32) -> Self {
+        let min = min.min(max).max(f32::EPSILON);
+        self.zoom_limits = Some((min, max.max(min)));
+        self.zoom = self.clamp_zoom(self.zoom);
+        self
+    }
+
+    /// Returns the minimum and maximum orthographic scale reachable by zooming
+    #[inline]
+    pub fn zoom_limits(&self) -> Option<(f32, f32)> {
+        self.zoom_limits
+    }
+
+    /// Returns the orthographic scale the camera zooms toward
+    #[inline]
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    /// Clamps an orthographic scale to the zoom limits
+    ///
+    /// # Arguments
+    /// * `zoom` - Orthographic scale to clamp
+    #[inline]
+    pub(crate) fn clamp_zoom(&self, zoom: f32) -> f32 {
+        match self.zoom_limits {
+            Some((min, max)) => zoom.clamp(min, max),
+            None => zoom,
+        }
+    }
+
+    /// Sets the orthographic scale the camera zooms toward, clamped to the zoom limits.
+    /// Zero, negative and non-finite values are ignored, since they would invert or break
+    /// the projection.
+    ///
+    /// # Arguments
+    /// * `zoom` - Orthographic scale, larger values show more of the world
+    #[inline]
+    pub fn set_zoom(&mut self, zoom: f32) {
+        if !zoom.is_finite() || zoom <= 0.0 {
+            return;
+        }
+        self.zoom = self.clamp_zoom(zoom);
+        self.zoom_anchor = None;
+    }
+
+    /// Multiplies the orthographic scale the camera zooms toward
+    ///
+    /// # Arguments
+    /// * `factor` - Multiplier for the orthographic scale
+    #[inline]
+    pub fn zoom_by(&mut self, factor: f32) {
+        self.set_zoom(self.zoom * factor);
+    }
+
+    /// Multiplies the orthographic scale the camera zooms toward, keeping the world point
+    /// under a viewport position stationary by translating the camera as the scale changes
+    ///
+    /// # Arguments
+    /// * `factor` - Multiplier for the orthographic scale
+    /// * `viewport_position` - Logical viewport position to zoom toward, such as the cursor
+    #[inline]
+    pub fn zoom_at(&mut self, factor: f32, viewport_position: Vec2) {
+        self.zoom_by(factor);
+        self.zoom_anchor = Some(viewport_position);
+    }
+
+    /// Gets translation delta for manual panning this frame from the accumulated delta and
+    /// [pan input](CameraBuffer::update_pan), with smooth decay subtracting the delta from both
+    ///
+    /// # Arguments
+    /// * `delta_buffer` - Delta buffer to decay
+    /// * `dt` - Time elapsed since last update in seconds
+    pub fn get_translation_delta(&self, delta_buffer: &mut CameraBuffer, dt: f32) -> Vec2 {
+        let decay = self.translation_decay_rate;
+        let pan = delta_buffer.smooth_pan(
+            [
+                Smoothing::Exponential { decay: decay.x },
+                Smoothing::Exponential { decay: decay.y },
+            ],
+            dt,
+        );
+        let delta = if decay.is_finite() {
+            delta_buffer.decay_xy(decay, dt)
+        } else {
+            delta_buffer.take()
+        };
+        (delta + pan) * self.sensitivity
+    }
+
+    /// Takes rotation input from the buffer's roll channel and adds it to the target rotation
+    ///
+    /// # Arguments
+    /// * `delta_buffer` - Delta buffer to take roll input from
+    pub fn consume_rotation(&mut self, delta_buffer: &mut CameraBuffer) {
+        self.rotation += delta_buffer.take_roll() * self.rotation_sensitivity;
+    }
+
+    /// Gets the camera center needed to keep the focus point within the follow area,
+    /// moving the camera only as far as needed
+    ///
+    /// # Arguments
+    /// * `center` - Current camera center
+    /// * `focus` - Point being followed
+    /// * `radius` - Radial follow distance used without a follow window
+    /// * `view_half_size` - Half size of the visible area in world units
+    pub fn get_follow_goal(
+        &self,
+        center: Vec2,
+        focus: Vec2,
+        radius: f32,
+        view_half_size: Vec2,
+    ) -> Vec2 {
+        let offset = focus - center;
+        match &self.follow_window {
+            Some(window) => {
+                let half_extents = window.half_extents * view_half_size;
+                focus - offset.clamp(-half_extents, half_extents)
+            }
+            None => focus - offset.clamp_length_max(radius),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum CameraView2d {
+    /// Follows an entity, moving once it drifts further than a radial distance from the center
+    Follow { target: Entity, radius: f32 },
+    /// Allows for camera to be panned from buffered input
+    Manual,
+}
+
+/// A rectangular dead zone in screen space for following targets
+#[derive(Clone, Copy)]
+pub struct FollowWindow {
+    /// Half size of the dead zone as a fraction of the visible area, where 1 is the screen edge
+    pub half_extents: Vec2,
+}
+
+impl FollowWindow {
+    /// Creates a new FollowWindow
+    ///
+    /// # Arguments
+    /// * `half_extents` - Half size as a fraction of the visible area
+    pub fn new(half_extents: Vec2) -> Self {
+        Self { half_extents }
+    }
+}
@@ -0,0 +1,200 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_egui::{EguiContextPass, EguiContexts, egui};
+
+use super::{CameraAnchor, CameraController, CameraView, Smoothing};
+
+/// Plugin showing an egui window that lists every [`CameraController`] and lets its
+/// sensitivity, smoothing, offset, pitch limits, orbit distance, anchor and view be tuned
+/// while the game runs. Requires `bevy_egui`'s `EguiPlugin` to be added.
+#[derive(Default)]
+pub struct CameraDebugUiPlugin;
+
+impl Plugin for CameraDebugUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(EguiContextPass, camera_debug_ui);
+    }
+}
+
+/// Draws the camera controller window, writing back only the values that changed
+///
+/// # Arguments
+/// * `contexts` - egui contexts of the primary window
+/// * `controllers` - Query for camera controllers and their optional names
+/// * `targets` - Last view target of each controller, restored when leaving the free view
+fn camera_debug_ui(
+    mut contexts: EguiContexts,
+    mut controllers: Query<(Entity, &mut CameraController, Option<&Name>)>,
+    mut targets: Local<HashMap<Entity, Entity>>,
+) {
+    let Some(ctx) = contexts.try_ctx_mut() else {
+        return;
+    };
+
+    egui::Window::new("Camera Controllers").show(ctx, |ui| {
+        let mut entities: Vec<_> = controllers.iter().map(|(entity, ..)| entity).collect();
+        entities.sort_unstable();
+        targets.retain(|entity, _| entities.contains(entity));
+
+        for entity in entities {
+            let Ok((_, mut controller, name)) = controllers.get_mut(entity) else {
+                continue;
+            };
+            let label = name.map_or_else(|| format!("{entity}"), |name| format!("{name}"));
+
+            egui::CollapsingHeader::new(label)
+                .id_salt(entity)
+                .show(ui, |ui| {
+                    controller_ui(ui, entity, &mut controller, &mut targets);
+                });
+        }
+    });
+}
+
+/// Draws the settings of a single controller
+fn controller_ui(
+    ui: &mut egui::Ui,
+    entity: Entity,
+    controller: &mut Mut<CameraController>,
+    targets: &mut HashMap<Entity, Entity>,
+) {
+    let mut enabled = controller.enabled;
+    if ui.checkbox(&mut enabled, "Enabled").changed() {
+        controller.enabled = enabled;
+    }
+
+    let mut sensitivity = controller.sensitivity;
+    ui.horizontal(|ui| {
+        ui.label("Sensitivity");
+        let x = ui.add(egui::DragValue::new(&mut sensitivity.x).speed(0.01));
+        let y = ui.add(egui::DragValue::new(&mut sensitivity.y).speed(0.01));
+        if x.changed() || y.changed() {
+            controller.sensitivity = sensitivity;
+        }
+    });
+
+    // only exponential smoothing maps onto a single slider
+    if let [Smoothing::Exponential { decay }, ..] = controller.translation_smoothing {
+        let mut seconds = if decay.is_finite() {
+            decay.recip()
+        } else {
+            0.0
+        };
+        if ui
+            .add(egui::Slider::new(&mut seconds, 0.0..=1.0).text("Smoothing"))
+            .changed()
+        {
+            let smoothing = Smoothing::exponential(seconds);
+            controller.translation_smoothing = [smoothing; 2];
+            controller.rotation_smoothing = [smoothing; 2];
+        }
+    }
+
+    let mut offset = controller.offset;
+    ui.horizontal(|ui| {
+        ui.label("Offset");
+        let x = ui.add(egui::DragValue::new(&mut offset.x).speed(0.05));
+        let y = ui.add(egui::DragValue::new(&mut offset.y).speed(0.05));
+        let z = ui.add(egui::DragValue::new(&mut offset.z).speed(0.05));
+        if x.changed() || y.changed() || z.changed() {
+            controller.offset = offset;
+        }
+    });
+
+    let (min, max) = controller
+        .pitch_limits
+        .unwrap_or((-std::f32::consts::FRAC_PI_2, std::f32::consts::FRAC_PI_2));
+    let (mut min, mut max) = (min.to_degrees(), max.to_degrees());
+    let min_changed = ui
+        .add(egui::Slider::new(&mut min, -90.0..=90.0).text("Min pitch"))
+        .changed();
+    let max_changed = ui
+        .add(egui::Slider::new(&mut max, -90.0..=90.0).text("Max pitch"))
+        .changed();
+    if min_changed || max_changed {
+        let limit = std::f32::consts::FRAC_PI_2 - 0.001;
+        let (min, max) = (min.min(max).to_radians(), min.max(max).to_radians());
+        controller.pitch_limits = Some((min.clamp(-limit, limit), max.clamp(-limit, limit)));
+    }
+
+    anchor_ui(ui, entity, controller);
+    view_ui(ui, entity, controller, targets);
+}
+
+/// Draws the anchor selection, with the orbit distance when orbiting
+fn anchor_ui(ui: &mut egui::Ui, entity: Entity, controller: &mut Mut<CameraController>) {
+    let current = anchor_name(&controller.anchor);
+    let distance = match controller.anchor {
+        CameraAnchor::Orbit { distance } | CameraAnchor::Isometric { distance, .. } => distance,
+        _ => 5.0,
+    };
+
+    let mut selected = current;
+    egui::ComboBox::from_id_salt(("anchor", entity))
+        .selected_text(current)
+        .show_ui(ui, |ui| {
+            for name in ["Point", "Orbit", "Yaw", "Isometric"] {
+                ui.selectable_value(&mut selected, name, name);
+            }
+        });
+    if selected != current {
+        controller.anchor = match selected {
+            "Orbit" => CameraAnchor::Orbit { distance },
+            "Yaw" => CameraAnchor::Yaw,
+            "Isometric" => CameraAnchor::isometric(distance, 4),
+            _ => CameraAnchor::Point,
+        };
+    }
+
+    if let CameraAnchor::Orbit { distance } = controller.anchor {
+        let mut value = distance;
+        let (min, max) = controller.distance_limits.unwrap_or((0.5, 50.0));
+        if ui
+            .add(egui::Slider::new(&mut value, min..=max).text("Orbit distance"))
+            .changed()
+        {
+            controller.anchor = CameraAnchor::Orbit { distance: value };
+        }
+    }
+}
+
+/// Draws the view selection, remembering the last target so it can be restored
+fn view_ui(
+    ui: &mut egui::Ui,
+    entity: Entity,
+    controller: &mut Mut<CameraController>,
+    targets: &mut HashMap<Entity, Entity>,
+) {
+    if let CameraView::Target(target) = controller.view {
+        targets.insert(entity, target);
+    }
+
+    ui.horizontal(|ui| {
+        ui.label("View");
+        let free = matches!(controller.view, CameraView::Free);
+        if ui.selectable_label(free, "Free").clicked() && !free {
+            controller.view = CameraView::Free;
+        }
+        if let Some(&target) = targets.get(&entity) {
+            let targeted = controller.view == CameraView::Target(target);
+            if ui
+                .selectable_label(targeted, format!("Target {target}"))
+                .clicked()
+                && !targeted
+            {
+                controller.view = CameraView::Target(target);
+            }
+        }
+    });
+}
+
+/// Returns the display name of an anchor
+fn anchor_name(anchor: &CameraAnchor) -> &'static str {
+    match anchor {
+        CameraAnchor::Point => "Point",
+        CameraAnchor::Orbit { .. } => "Orbit",
+        CameraAnchor::Yaw => "Yaw",
+        CameraAnchor::Plane { .. } => "Plane",
+        CameraAnchor::Rail(_) => "Rail",
+        CameraAnchor::Isometric { .. } => "Isometric",
+    }
+}
@@ -0,0 +1,250 @@
+use bevy::{
+    math::{NormedVectorSpace, VectorSpace},
+    prelude::*,
+};
+use core::time::Duration;
+
+/// Accumulates input deltas of any vector type until they are consumed, either all at
+/// once or gradually with smooth decay.
+///
+/// [`CameraBuffer`](super::CameraBuffer) uses a `DeltaBuffer<Vec2>` for look input and
+/// `DeltaBuffer<f32>` for zoom and roll. Zoom wheels, trigger axes or 3D flight input in
+/// game code can use the same machinery through [`DeltaBuffer1d`] and [`DeltaBuffer3d`].
+///
+/// Input piling up during frame hitches or while nothing consumes the buffer can be limited
+/// with [`DeltaBuffer::with_max_magnitude`], and input left unconsumed too long dropped with
+/// [`DeltaBuffer::with_max_age`], so it never whips the camera around once consumed.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeltaBuffer<T: VectorSpace> {
+    /// The current accumulated delta
+    value: T,
+    /// Optional largest magnitude the accumulated delta is clamped to
+    max_magnitude: Option<f32>,
+    /// Optional seconds input may go without new input before it is dropped
+    max_age: Option<f32>,
+    /// Seconds since input was last added
+    age: f32,
+    /// Whether input was added since the age was last advanced
+    fresh: bool,
+}
+
+/// Delta buffer for scalar input such as zoom wheels and trigger axes
+pub type DeltaBuffer1d = DeltaBuffer<f32>;
+/// Delta buffer for 2D input such as mouse motion and sticks
+pub type DeltaBuffer2d = DeltaBuffer<Vec2>;
+/// Delta buffer for 3D input such as flight or free movement
+pub type DeltaBuffer3d = DeltaBuffer<Vec3>;
+
+impl<T: NormedVectorSpace + StableInterpolate> DeltaBuffer<T> {
+    /// Creates a new empty DeltaBuffer
+    pub fn new() -> Self {
+        Self {
+            value: T::ZERO,
+            max_magnitude: None,
+            max_age: None,
+            age: 0.0,
+            fresh: false,
+        }
+    }
+
+    /// Clamps the accumulated delta to a largest magnitude, so input piling up during
+    /// hitches is limited
+    ///
+    /// # Arguments
+    /// * `max_magnitude` - Largest magnitude of the accumulated delta
+    #[inline]
+    pub fn with_max_magnitude(mut self, max_magnitude: f32) -> Self {
+        self.max_magnitude = Some(max_magnitude);
+        self
+    }
+
+    /// Drops the accumulated delta once no input has been added for longer than an age,
+    /// checked by [`DeltaBuffer::expire`]. This also cuts off the tail of smoothed input.
+    ///
+    /// # Arguments
+    /// * `max_age` - Longest time input is kept without new input
+    #[inline]
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age.as_secs_f32());
+        self
+    }
+
+    /// Sets the largest magnitude the accumulated delta is clamped to, or removes the limit
+    ///
+    /// # Arguments
+    /// * `max_magnitude` - Largest magnitude of the accumulated delta
+    pub fn set_max_magnitude(&mut self, max_magnitude: Option<f32>) {
+        self.max_magnitude = max_magnitude;
+        self.clamp();
+    }
+
+    /// Sets the longest time input is kept without new input, or removes the limit
+    ///
+    /// # Arguments
+    /// * `max_age` - Longest time input is kept without new input
+    #[inline]
+    pub fn set_max_age(&mut self, max_age: Option<Duration>) {
+        self.max_age = max_age.map(|max_age| max_age.as_secs_f32());
+    }
+
+    /// Adds the given delta to the buffer's current value
+    #[inline]
+    pub fn update(&mut self, delta: T) {
+        self.value = self.value + delta;
+        if delta.norm_squared() > 0.0 {
+            self.age = 0.0;
+            self.fresh = true;
+        }
+        self.clamp();
+    }
+
+    /// Advances the time since input was last added, dropping the accumulated delta once
+    /// it has gone without new input for longer than the max age. Called by camera
+    /// controllers for their buffers, and by game code for standalone buffers.
+    ///
+    /// # Arguments
+    /// * `dt` - Time elapsed since last update in seconds
+    pub fn expire(&mut self, dt: f32) {
+        // input added since the last call is kept even after a long frame
+        if !core::mem::take(&mut self.fresh) {
+            self.age += dt;
+        }
+        if self.max_age.is_some_and(|max_age| self.age > max_age) {
+            self.reset();
+        }
+    }
+
+    /// Clamps the accumulated delta to the max magnitude
+    fn clamp(&mut self) {
+        let Some(max_magnitude) = self.max_magnitude else {
+            return;
+        };
+        let magnitude = self.value.norm();
+        if magnitude > max_magnitude {
+            self.value = self.value * (max_magnitude / magnitude);
+        }
+    }
+
+    /// Subtracts the given delta from the buffer's current value
+    #[inline]
+    pub fn consume(&mut self, delta: T) {
+        self.value = self.value - delta;
+    }
+
+    /// Resets the buffer's value to zero
+    #[inline]
+    pub fn reset(&mut self) {
+        self.value = T::ZERO;
+    }
+
+    /// Returns the current value without modifying it
+    #[inline]
+    pub fn read(&self) -> T {
+        self.value
+    }
+
+    /// Returns the current value and resets the buffer
+    #[inline]
+    pub fn take(&mut self) -> T {
+        core::mem::replace(&mut self.value, T::ZERO)
+    }
+
+    /// Reduces the value using smooth interpolation, returning the consumed part
+    ///
+    /// # Arguments
+    /// * `rate` - The rate at which to decay the value
+    /// * `dt` - The time increment
+    #[inline]
+    pub fn decay(&mut self, rate: f32, dt: f32) -> T {
+        let mut consumed = T::ZERO;
+        consumed.smooth_nudge(&self.value, rate, dt);
+        self.consume(consumed);
+        consumed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accumulates_and_consumes() {
+        let mut buffer = DeltaBuffer2d::new();
+        buffer.update(Vec2::new(1.0, 2.0));
+        buffer.update(Vec2::new(0.5, -1.0));
+        assert_eq!(buffer.read(), Vec2::new(1.5, 1.0));
+        buffer.consume(Vec2::new(1.0, 0.0));
+        assert_eq!(buffer.take(), Vec2::new(0.5, 1.0));
+        assert_eq!(buffer.read(), Vec2::ZERO);
+    }
+
+    #[test]
+    fn clamps_to_max_magnitude_keeping_direction() {
+        let mut buffer = DeltaBuffer2d::new().with_max_magnitude(1.0);
+        buffer.update(Vec2::new(3.0, 4.0));
+        let value = buffer.read();
+        assert!((value.length() - 1.0).abs() < 1e-6);
+        assert!((value - Vec2::new(0.6, 0.8)).length() < 1e-6);
+    }
+
+    #[test]
+    fn lowering_max_magnitude_clamps_existing_input() {
+        let mut buffer = DeltaBuffer1d::new();
+        buffer.update(5.0);
+        buffer.set_max_magnitude(Some(2.0));
+        assert_eq!(buffer.read(), 2.0);
+        buffer.update(-10.0);
+        assert_eq!(buffer.read(), -2.0);
+    }
+
+    #[test]
+    fn keeps_input_added_since_the_last_expiry_after_a_long_frame() {
+        let mut buffer = DeltaBuffer1d::new().with_max_age(Duration::from_millis(100));
+        buffer.update(1.0);
+        buffer.expire(1.0);
+        assert_eq!(buffer.read(), 1.0);
+    }
+
+    #[test]
+    fn drops_stale_input_on_the_expiry_that_exceeds_max_age() {
+        let mut buffer = DeltaBuffer1d::new().with_max_age(Duration::from_millis(100));
+        buffer.update(1.0);
+        buffer.expire(0.016);
+        buffer.expire(0.05);
+        assert_eq!(buffer.read(), 1.0);
+        // a hitch ages the input past the limit within a single expiry
+        buffer.expire(0.5);
+        assert_eq!(buffer.read(), 0.0);
+    }
+
+    #[test]
+    fn new_input_restarts_the_age() {
+        let mut buffer = DeltaBuffer1d::new().with_max_age(Duration::from_millis(100));
+        buffer.update(1.0);
+        buffer.expire(0.016);
+        buffer.expire(0.08);
+        buffer.update(1.0);
+        buffer.expire(0.08);
+        assert_eq!(buffer.read(), 2.0);
+    }
+
+    #[test]
+    fn never_expires_without_max_age() {
+        let mut buffer = DeltaBuffer1d::new();
+        buffer.update(1.0);
+        buffer.expire(0.016);
+        buffer.expire(100.0);
+        assert_eq!(buffer.read(), 1.0);
+    }
+
+    #[test]
+    fn decay_consumes_part_of_the_value() {
+        let mut buffer = DeltaBuffer1d::new();
+        buffer.update(1.0);
+        let consumed = buffer.decay(10.0, 0.1);
+        assert!(consumed > 0.0 && consumed < 1.0);
+        assert!((buffer.read() + consumed - 1.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,156 @@
+use core::time::Duration;
+
+use bevy::{
+    diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic},
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+
+/// Plugin measuring time spent in the camera systems and exposing it through [`Diagnostics`].
+/// Timing is only collected while this plugin is added.
+#[derive(Default)]
+pub struct CameraDiagnosticsPlugin {
+    /// Time budget per controller for collision casts, warned about once when exceeded
+    pub collision_budget: Option<Duration>,
+}
+
+impl CameraDiagnosticsPlugin {
+    /// Time spent consuming controller buffers, in milliseconds
+    pub const CONSUME_BUFFERS: DiagnosticPath = DiagnosticPath::const_new("camera/consume_buffers");
+    /// Time spent updating camera transforms, in milliseconds
+    pub const UPDATE_CAMERA: DiagnosticPath = DiagnosticPath::const_new("camera/update_camera");
+    /// Time spent in collision casts across all controllers, in milliseconds
+    pub const COLLISION: DiagnosticPath = DiagnosticPath::const_new("camera/collision");
+    /// Number of camera controllers updated
+    pub const CONTROLLERS: DiagnosticPath = DiagnosticPath::const_new("camera/controllers");
+}
+
+impl Plugin for CameraDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(CameraTimings {
+            collision_budget: self.collision_budget,
+            ..default()
+        })
+        .register_diagnostic(Diagnostic::new(Self::CONSUME_BUFFERS).with_suffix("ms"))
+        .register_diagnostic(Diagnostic::new(Self::UPDATE_CAMERA).with_suffix("ms"))
+        .register_diagnostic(Diagnostic::new(Self::COLLISION).with_suffix("ms"))
+        .register_diagnostic(Diagnostic::new(Self::CONTROLLERS))
+        .add_systems(
+            PostUpdate,
+            record_camera_diagnostics.after(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+/// Timings of the camera systems for a single frame
+#[derive(Default, Clone)]
+pub struct FrameTimings {
+    /// Time spent consuming controller buffers
+    pub consume_buffers: Duration,
+    /// Time spent updating camera transforms
+    pub update_camera: Duration,
+    /// Time spent updating each controller
+    pub controllers: HashMap<Entity, Duration>,
+    /// Time spent in collision casts for each controller
+    pub collisions: HashMap<Entity, Duration>,
+}
+
+/// Timings of the camera systems, collected while [`CameraDiagnosticsPlugin`] is added
+#[derive(Resource, Default)]
+pub struct CameraTimings {
+    /// Timings being collected for the current frame
+    pub(crate) frame: FrameTimings,
+    /// Timings of the last completed frame
+    last: FrameTimings,
+    /// Time budget per controller for collision casts
+    pub collision_budget: Option<Duration>,
+    /// Controllers that have already been warned about exceeding the collision budget
+    warned: HashSet<Entity>,
+}
+
+impl CameraTimings {
+    /// Returns the timings of the last completed frame
+    #[inline]
+    pub fn last_frame(&self) -> &FrameTimings {
+        &self.last
+    }
+
+    /// Returns the time spent updating the given controller during the last frame
+    #[inline]
+    pub fn controller(&self, entity: Entity) -> Option<Duration> {
+        self.last.controllers.get(&entity).copied()
+    }
+
+    /// Returns the time spent in collision casts for the given controller during the last frame
+    #[inline]
+    pub fn collision(&self, entity: Entity) -> Option<Duration> {
+        self.last.collisions.get(&entity).copied()
+    }
+
+    /// Iterates the controllers updated during the last frame, slowest first
+    pub fn slowest(&self) -> impl Iterator<Item = (Entity, Duration)> {
+        let mut controllers: Vec<_> = self
+            .last
+            .controllers
+            .iter()
+            .map(|(e, d)| (*e, *d))
+            .collect();
+        controllers.sort_by_key(|(_, duration)| core::cmp::Reverse(*duration));
+        controllers.into_iter()
+    }
+
+    /// Records time spent updating a controller
+    ///
+    /// # Arguments
+    /// * `entity` - Entity with the camera controller
+    /// * `duration` - Time spent
+    #[inline]
+    pub fn record_controller(&mut self, entity: Entity, duration: Duration) {
+        *self.frame.controllers.entry(entity).or_default() += duration;
+    }
+
+    /// Records time spent in collision casts for a controller
+    ///
+    /// # Arguments
+    /// * `entity` - Entity with the camera controller
+    /// * `duration` - Time spent
+    #[inline]
+    pub fn record_collision(&mut self, entity: Entity, duration: Duration) {
+        *self.frame.collisions.entry(entity).or_default() += duration;
+    }
+}
+
+/// Publishes collected timings as diagnostics, warns about slow collision casts
+/// and starts collecting timings for the next frame
+fn record_camera_diagnostics(mut timings: ResMut<CameraTimings>, mut diagnostics: Diagnostics) {
+    let timings = &mut *timings;
+    let frame = core::mem::take(&mut timings.frame);
+
+    if let Some(budget) = timings.collision_budget {
+        for (entity, duration) in frame.collisions.iter() {
+            if *duration > budget && timings.warned.insert(*entity) {
+                warn!(
+                    "camera controller {entity} spent {:.3}ms in collision casts, exceeding the budget of {:.3}ms",
+                    duration.as_secs_f64() * 1000.0,
+                    budget.as_secs_f64() * 1000.0,
+                );
+            }
+        }
+    }
+
+    let collision: Duration = frame.collisions.values().sum();
+    diagnostics.add_measurement(&CameraDiagnosticsPlugin::CONSUME_BUFFERS, || {
+        frame.consume_buffers.as_secs_f64() * 1000.0
+    });
+    diagnostics.add_measurement(&CameraDiagnosticsPlugin::UPDATE_CAMERA, || {
+        frame.update_camera.as_secs_f64() * 1000.0
+    });
+    diagnostics.add_measurement(&CameraDiagnosticsPlugin::COLLISION, || {
+        collision.as_secs_f64() * 1000.0
+    });
+    diagnostics.add_measurement(&CameraDiagnosticsPlugin::CONTROLLERS, || {
+        frame.controllers.len() as f64
+    });
+
+    timings.last = frame;
+}
@@ -0,0 +1,63 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+
+use super::{CameraBuffer, CameraController, CameraTransition};
+
+/// Picks the live controller for every camera, so controllers sharing a camera never fight.
+///
+/// The active controller with the highest priority drives the camera, with ties broken by
+/// entity order, which is stable but unrelated to when controllers were spawned. Other
+/// controllers sharing the camera are skipped and their input discarded. When the live
+/// controller changes, the new one blends in from the camera's current pose over its
+/// activation blend.
+///
+/// # Arguments
+/// * `camera_controllers` - Query for controllers and buffers
+/// * `live` - Live controller of each camera during the last update
+pub(crate) fn direct_cameras(
+    mut camera_controllers: Query<(Entity, &mut CameraController, &mut CameraBuffer)>,
+    mut live: Local<HashMap<Entity, Entity>>,
+) {
+    // find the highest priority active controller of each camera
+    let mut chosen: HashMap<Entity, (i32, Entity)> = HashMap::default();
+    for (entity, controller, _) in camera_controllers.iter() {
+        if !controller.is_active() {
+            continue;
+        }
+        let candidate = (controller.priority, entity);
+        chosen
+            .entry(controller.camera)
+            .and_modify(|best| {
+                if candidate.0 > best.0 || candidate.0 == best.0 && candidate.1 < best.1 {
+                    *best = candidate;
+                }
+            })
+            .or_insert(candidate);
+    }
+
+    for (entity, mut controller, mut buffer) in camera_controllers.iter_mut() {
+        let is_live = chosen
+            .get(&controller.camera)
+            .is_some_and(|(_, best)| *best == entity);
+
+        if is_live && live.get(&controller.camera) != Some(&entity) {
+            // blend in from the pose left by the previous controller
+            let previous = live.insert(controller.camera, entity);
+            if previous.is_some() && controller.activation_blend > 0.0 {
+                controller.transition = Some(CameraTransition::new(
+                    controller.activation_blend,
+                    EaseFunction::SmoothStep,
+                ));
+            }
+        }
+        if !is_live {
+            // input meant for the live controller should not build up
+            buffer.clear();
+        }
+        if controller.live != is_live {
+            controller.live = is_live;
+        }
+    }
+
+    // forget cameras without any active controller
+    live.retain(|camera, _| chosen.contains_key(camera));
+}
@@ -0,0 +1,204 @@
+use bevy::{input::mouse::AccumulatedMouseMotion, prelude::*, window::PrimaryWindow};
+
+use super::{
+    CameraAnchor, CameraController, CameraController2d, CameraControllerPaused, CameraPlayer,
+    CameraTimeSource, InputSource, InputSources,
+    target::{self, TargetTransform},
+    time::CameraTime,
+};
+
+/// Rate at which the measured drag velocity follows the cursor, so a flick carries the
+/// speed of the last few frames rather than a single one
+const VELOCITY_DECAY_RATE: f32 = 20.0;
+
+/// Speed in world units per second below which momentum stops
+const MIN_SPEED: f32 = 1e-3;
+
+/// A component on a controller entity letting the world be dragged around with the mouse,
+/// like a map. While the button is held, the point under the cursor stays under the cursor
+/// at any zoom or projection, and releasing it flicks the camera onward with momentum that
+/// decays with friction.
+///
+/// Works with a [`CameraController2d`] using the [`Manual`](super::CameraView2d::Manual)
+/// view, and with a [`CameraController`] using the
+/// [`Plane`](CameraAnchor::Plane) anchor, where the dragged surface is the plane through the
+/// controller entity perpendicular to the anchor's normal, such as the ground of a map.
+#[derive(Component, Clone, Debug)]
+pub struct DragPan {
+    /// Held to drag the world under the cursor
+    pub button: MouseButton,
+    /// Rate at which momentum decays after releasing, or infinite to stop immediately
+    pub friction: f32,
+    /// Velocity of the camera in world units per second
+    velocity: Vec3,
+    /// Whether the world is being dragged
+    dragging: bool,
+}
+
+impl Default for DragPan {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DragPan {
+    /// Creates a new DragPan with default settings:
+    /// - Left mouse button to drag
+    /// - Friction: 5.0
+    pub fn new() -> Self {
+        Self {
+            button: MouseButton::Left,
+            friction: 5.0,
+            velocity: Vec3::ZERO,
+            dragging: false,
+        }
+    }
+
+    /// Sets the button held to drag
+    ///
+    /// # Arguments
+    /// * `button` - Mouse button
+    #[inline]
+    pub fn with_button(mut self, button: MouseButton) -> Self {
+        self.button = button;
+        self
+    }
+
+    /// Sets the rate at which momentum decays after releasing
+    ///
+    /// # Arguments
+    /// * `friction` - Decay rate, larger values stop sooner
+    #[inline]
+    pub fn with_friction(mut self, friction: f32) -> Self {
+        self.friction = friction;
+        self
+    }
+
+    /// Checks whether the world is being dragged
+    #[inline]
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Returns the velocity of the camera in world units per second
+    #[inline]
+    pub fn velocity(&self) -> Vec3 {
+        self.velocity
+    }
+
+    /// Stops any momentum left from a flick
+    #[inline]
+    pub fn stop(&mut self) {
+        self.velocity = Vec3::ZERO;
+    }
+}
+
+/// Drags cameras with the world under the cursor, and glides them with decaying momentum
+/// once released
+///
+/// # Arguments
+/// * `drag_pans` - Query for drag pans, their controllers and input routing
+/// * `cameras` - Query for cameras and their transforms
+/// * `target_transforms` - Query for controller transforms
+/// * `windows` - Query for the primary window, used for the cursor position
+/// * `buttons` - Mouse button input, if the input plugin is present
+/// * `motion` - Mouse motion accumulated this frame
+/// * `time` - Clocks providing frame timing for each controller's time source
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_drag_pans(
+    mut drag_pans: Query<
+        (
+            Entity,
+            &mut DragPan,
+            Option<&CameraController>,
+            Option<&CameraController2d>,
+            Option<&CameraPlayer>,
+            Option<&InputSources>,
+        ),
+        Without<CameraControllerPaused>,
+    >,
+    mut cameras: Query<(&Camera, &mut Transform)>,
+    target_transforms: Query<TargetTransform, Without<Camera>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    buttons: Option<Res<ButtonInput<MouseButton>>>,
+    motion: Option<Res<AccumulatedMouseMotion>>,
+    time: CameraTime,
+) {
+    let cursor = windows.single().ok().and_then(Window::cursor_position);
+    let motion = motion.map_or(Vec2::ZERO, |motion| motion.delta);
+
+    for (entity, mut drag, controller, controller2d, player, sources) in drag_pans.iter_mut() {
+        // 3D controllers are dragged across the plane of their anchor
+        let (camera, normal) = match (controller, controller2d) {
+            (Some(controller), _) => match controller.anchor {
+                CameraAnchor::Plane { normal } if controller.enabled && controller.is_live() => {
+                    (controller.camera, Some(normal))
+                }
+                _ => continue,
+            },
+            (None, Some(controller)) => (controller.camera, None),
+            (None, None) => continue,
+        };
+        let dt = time.delta_secs(CameraTimeSource::of(controller, controller2d));
+        if dt <= 0.0 {
+            continue;
+        }
+        let Ok((camera, mut transform)) = cameras.get_mut(camera) else {
+            continue;
+        };
+
+        let held = buttons
+            .as_deref()
+            .is_some_and(|buttons| buttons.pressed(drag.button))
+            && InputSources::routes(sources, player, InputSource::Mouse);
+        // drags only start over the window
+        drag.dragging = held && (drag.dragging || cursor.is_some());
+
+        let displacement = if drag.dragging {
+            let camera_transform = GlobalTransform::from(*transform);
+            // world points under the cursor before and after this frame's motion
+            let world_point = |cursor: Vec2| match normal {
+                Some(normal) => {
+                    let origin = target::get_transform(&target_transforms, entity)
+                        .map_or(Vec3::ZERO, |controller| controller.translation);
+                    let ray = camera.viewport_to_world(&camera_transform, cursor).ok()?;
+                    let distance = ray.intersect_plane(origin, InfinitePlane3d::new(normal))?;
+                    Some(ray.get_point(distance))
+                }
+                None => camera
+                    .viewport_to_world_2d(&camera_transform, cursor)
+                    .ok()
+                    .map(|point| point.extend(0.0)),
+            };
+            let displacement = match cursor {
+                Some(cursor) if motion != Vec2::ZERO => {
+                    match (world_point(cursor - motion), world_point(cursor)) {
+                        (Some(from), Some(to)) => from - to,
+                        _ => Vec3::ZERO,
+                    }
+                }
+                _ => Vec3::ZERO,
+            };
+            // measure the drag speed for the flick on release
+            drag.velocity
+                .smooth_nudge(&(displacement / dt), VELOCITY_DECAY_RATE, dt);
+            displacement
+        } else {
+            // glide with decaying momentum
+            let friction = drag.friction;
+            if friction.is_finite() {
+                drag.velocity *= (-friction * dt).exp();
+            } else {
+                drag.velocity = Vec3::ZERO;
+            }
+            if drag.velocity.length() < MIN_SPEED {
+                drag.velocity = Vec3::ZERO;
+            }
+            drag.velocity * dt
+        };
+
+        if displacement != Vec3::ZERO {
+            transform.translation += displacement;
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+use super::{CameraAnchor, CameraView};
+
+/// Triggered on a controller entity when its view changes, such as switching from a free
+/// view to looking at a target or between targets
+#[derive(Event, Clone)]
+pub struct CameraViewChanged {
+    /// View before the change
+    pub previous: CameraView,
+    /// View after the change
+    pub view: CameraView,
+}
+
+/// Triggered on a controller entity when its anchor switches to a different kind,
+/// such as from first person to orbit. Zooming or changing settings within the same
+/// kind of anchor is not reported.
+#[derive(Event, Clone)]
+pub struct CameraAnchorChanged {
+    /// Anchor before the change
+    pub previous: CameraAnchor,
+    /// Anchor after the change
+    pub anchor: CameraAnchor,
+}
+
+/// Triggered on a controller entity when collision starts pushing its camera
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CameraCollisionStarted;
+
+/// Triggered on a controller entity when collision stops pushing its camera
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CameraCollisionEnded;
+
+/// Triggered once on a controller entity when its view target no longer exists, such as
+/// after being despawned. What the camera does next is set by
+/// [`CameraController::with_target_lost`](super::CameraController::with_target_lost).
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CameraTargetLost {
+    /// View target that no longer exists
+    pub target: Entity,
+}
+
+/// Triggered on a controller entity when it jumps further than its
+/// [teleport distance](super::CameraController::with_teleport_distance) in one update,
+/// after which the camera snaps after it
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CameraTargetTeleported {
+    /// Distance jumped in meters
+    pub distance: f32,
+}
+
+/// Triggered on a controller entity when a blend started by
+/// [`CameraController::transition_to`](super::CameraController::transition_to) finishes
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CameraTransitionFinished;
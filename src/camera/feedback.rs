@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+
+/// Kind of notable camera event reported through [`CameraFeedback`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CameraFeedbackKind {
+    /// Camera was pulled in by collision
+    CollisionClamp,
+    /// Camera shake was triggered
+    Shake,
+    /// Zoom input was stopped by a zoom limit
+    ZoomLimit,
+}
+
+/// Event emitted when a notable camera event occurs, allowing controller
+/// feedback such as rumble or sounds to mirror what the camera is doing
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CameraFeedback {
+    /// Entity with the camera controller that produced the event
+    pub controller: Entity,
+    /// Kind of camera event
+    pub kind: CameraFeedbackKind,
+    /// Normalized intensity of the event in the range 0 to 1
+    pub intensity: f32,
+}
+
+impl CameraFeedback {
+    /// Creates a new CameraFeedback event, clamping intensity to the range 0 to 1
+    ///
+    /// # Arguments
+    /// * `controller` - Entity with the camera controller
+    /// * `kind` - Kind of camera event
+    /// * `intensity` - Intensity of the event
+    pub fn new(controller: Entity, kind: CameraFeedbackKind, intensity: f32) -> Self {
+        Self {
+            controller,
+            kind,
+            intensity: intensity.clamp(0.0, 1.0),
+        }
+    }
+}
+
+#[cfg(feature = "rumble")]
+pub(crate) use rumble::rumble_feedback;
+#[cfg(feature = "rumble")]
+pub use rumble::{FeedbackGamepad, RumbleSettings};
+
+#[cfg(feature = "rumble")]
+mod rumble {
+    use core::time::Duration;
+
+    use bevy::{
+        input::gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest},
+        prelude::*,
+    };
+
+    use super::{CameraFeedback, CameraFeedbackKind};
+
+    /// Restricts rumble from a controller's feedback to a single gamepad.
+    /// Controllers without this component rumble every connected gamepad.
+    #[derive(Component, Clone, Copy)]
+    pub struct FeedbackGamepad(pub Entity);
+
+    /// Settings for converting camera feedback into gamepad rumble
+    #[derive(Resource, Clone)]
+    pub struct RumbleSettings {
+        /// Duration of rumble for an event of full intensity
+        pub duration: Duration,
+        /// Multiplier applied to event intensity
+        pub strength: f32,
+    }
+
+    impl Default for RumbleSettings {
+        fn default() -> Self {
+            Self {
+                duration: Duration::from_millis(150),
+                strength: 1.0,
+            }
+        }
+    }
+
+    /// Forwards camera feedback events to gamepad rumble requests
+    pub(crate) fn rumble_feedback(
+        mut feedback: EventReader<CameraFeedback>,
+        mut requests: EventWriter<GamepadRumbleRequest>,
+        feedback_gamepads: Query<&FeedbackGamepad>,
+        gamepads: Query<Entity, With<Gamepad>>,
+        settings: Res<RumbleSettings>,
+    ) {
+        for event in feedback.read() {
+            let intensity = (event.intensity * settings.strength).clamp(0.0, 1.0);
+            if intensity <= 0.0 {
+                continue;
+            }
+            // heavy events use the strong motor, light events the weak motor
+            let intensity = match event.kind {
+                CameraFeedbackKind::CollisionClamp => {
+                    GamepadRumbleIntensity::strong_motor(intensity)
+                }
+                CameraFeedbackKind::Shake => GamepadRumbleIntensity {
+                    strong_motor: intensity,
+                    weak_motor: intensity,
+                },
+                CameraFeedbackKind::ZoomLimit => GamepadRumbleIntensity::weak_motor(intensity),
+            };
+            let duration = settings.duration.mul_f32(event.intensity.max(0.25));
+
+            match feedback_gamepads.get(event.controller) {
+                Ok(FeedbackGamepad(gamepad)) => {
+                    requests.write(GamepadRumbleRequest::Add {
+                        gamepad: *gamepad,
+                        intensity,
+                        duration,
+                    });
+                }
+                Err(_) => {
+                    for gamepad in gamepads.iter() {
+                        requests.write(GamepadRumbleRequest::Add {
+                            gamepad,
+                            intensity,
+                            duration,
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+
+use super::{
+    CameraController,
+    target::{CameraFilter, SkippedEntities},
+};
+
+/// A component that moves a controller's camera solve into `FixedPostUpdate`,
+/// interpolating the camera between the last two fixed poses every frame.
+///
+/// Use it when the followed target is a physics body updated in `FixedUpdate`,
+/// so the camera moves in step with the body instead of jittering against it.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct FixedCameraUpdate {
+    /// Camera pose solved in the previous fixed update
+    previous: Option<Transform>,
+    /// Camera pose solved in the latest fixed update
+    current: Option<Transform>,
+}
+
+impl FixedCameraUpdate {
+    /// Returns the camera pose solved in the latest fixed update
+    #[inline]
+    pub fn current(&self) -> Option<Transform> {
+        self.current
+    }
+}
+
+/// Restores the last solved fixed pose before solving again,
+/// so smoothing continues from the solved pose rather than the interpolated one
+///
+/// # Arguments
+/// * `camera_controllers` - Query for fixed timestep controllers
+/// * `camera_transforms` - Query for camera transforms to restore
+pub(crate) fn restore_fixed_poses(
+    camera_controllers: Query<(&CameraController, &FixedCameraUpdate)>,
+    mut camera_transforms: Query<&mut Transform, CameraFilter>,
+) {
+    for (controller, fixed) in camera_controllers.iter() {
+        if let Some(current) = fixed.current
+            && let Ok(mut camera_transform) = camera_transforms.get_mut(controller.camera)
+        {
+            *camera_transform = current;
+        }
+    }
+}
+
+/// Records the pose solved during the fixed update
+///
+/// # Arguments
+/// * `camera_controllers` - Query for fixed timestep controllers
+/// * `camera_transforms` - Query for solved camera transforms
+/// * `skipped` - Controllers skipped for missing entities, warned about once
+pub(crate) fn record_fixed_poses(
+    mut camera_controllers: Query<(Entity, &CameraController, &mut FixedCameraUpdate)>,
+    camera_transforms: Query<&Transform, CameraFilter>,
+    mut skipped: Local<SkippedEntities>,
+) {
+    for (entity, controller, mut fixed) in camera_controllers.iter_mut() {
+        let Some(&solved) = skipped.check(entity, camera_transforms.get(controller.camera)) else {
+            continue;
+        };
+        fixed.previous = Some(fixed.current.unwrap_or(solved));
+        fixed.current = Some(solved);
+    }
+}
+
+/// Interpolates fixed timestep cameras between their last two solved poses
+///
+/// # Arguments
+/// * `camera_controllers` - Query for fixed timestep controllers
+/// * `camera_transforms` - Query for camera transforms to modify
+/// * `time` - Resource providing fixed timestep progress
+/// * `skipped` - Controllers skipped for missing entities, warned about once
+pub(crate) fn interpolate_fixed_poses(
+    camera_controllers: Query<(Entity, &CameraController, &FixedCameraUpdate)>,
+    mut camera_transforms: Query<&mut Transform, CameraFilter>,
+    time: Res<Time<Fixed>>,
+    mut skipped: Local<SkippedEntities>,
+) {
+    let fraction = time.overstep_fraction();
+
+    for (entity, controller, fixed) in camera_controllers.iter() {
+        let (Some(previous), Some(current)) = (fixed.previous, fixed.current) else {
+            continue;
+        };
+        let Some(mut camera_transform) =
+            skipped.check(entity, camera_transforms.get_mut(controller.camera))
+        else {
+            continue;
+        };
+        camera_transform.translation = previous.translation.lerp(current.translation, fraction);
+        camera_transform.rotation = previous.rotation.slerp(current.rotation, fraction);
+    }
+}
@@ -0,0 +1,376 @@
+use bevy::prelude::*;
+
+use super::{
+    CameraBuffer, CameraControllerPaused, CameraPlayer, InputSource, InputSources, WorldUnits,
+    target::{self, SkippedEntities, TargetTransform},
+};
+
+/// Keys used by a [`FlightController`]
+#[derive(Clone, Debug)]
+pub struct FlightBindings {
+    pub forward: KeyCode,
+    pub back: KeyCode,
+    pub left: KeyCode,
+    pub right: KeyCode,
+    pub up: KeyCode,
+    pub down: KeyCode,
+    pub roll_left: KeyCode,
+    pub roll_right: KeyCode,
+    /// Held to thrust harder
+    pub boost: KeyCode,
+}
+
+impl Default for FlightBindings {
+    fn default() -> Self {
+        Self {
+            forward: KeyCode::KeyW,
+            back: KeyCode::KeyS,
+            left: KeyCode::KeyA,
+            right: KeyCode::KeyD,
+            up: KeyCode::Space,
+            down: KeyCode::ControlLeft,
+            roll_left: KeyCode::KeyQ,
+            roll_right: KeyCode::KeyE,
+            boost: KeyCode::ShiftLeft,
+        }
+    }
+}
+
+/// A six degrees of freedom flight controller for ships and aircraft, added to the entity
+/// being flown. Pitch and yaw come from the look input of its [`CameraBuffer`] and roll from
+/// the buffer's roll channel or the roll keys, all around the ship's own axes. Thrust
+/// accelerates along the ship's local axes.
+///
+/// With damping the ship slows down on its own, otherwise it keeps drifting like a body in
+/// space until thrust is applied against its motion. Pair it with a [`ChaseCamera`] rather
+/// than a [`CameraController`](super::CameraController) on the same entity, since both
+/// would consume the same buffer.
+#[derive(Component, Clone, Debug)]
+#[require(CameraBuffer)]
+pub struct FlightController {
+    /// Whether the ship responds to input
+    pub enabled: bool,
+    /// Radians turned per unit of pitch, yaw and roll input
+    pub sensitivity: Vec3,
+    /// Radians per second rolled while a roll key is held
+    pub roll_speed: f32,
+    /// Acceleration in meters per second squared while thrusting
+    pub thrust: f32,
+    /// Multiplier applied to thrust while the boost key is held
+    pub boost_multiplier: f32,
+    /// Optional maximum speed in meters per second
+    pub max_speed: Option<f32>,
+    /// Rate at which velocity decays without thrust, or `None` for Newtonian drift
+    pub damping: Option<f32>,
+    /// Keys used for control
+    pub bindings: FlightBindings,
+    /// Velocity of the ship in world units per second
+    pub(crate) velocity: Vec3,
+}
+
+impl Default for FlightController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FlightController {
+    /// Creates a new FlightController with default settings:
+    /// - Sensitivity: 1.0 for pitch, yaw and roll
+    /// - Roll speed: 2.0 radians per second
+    /// - Thrust: 20.0 meters per second squared, boost multiplier: 3.0
+    /// - Max speed: 50.0 meters per second
+    /// - Damping: 1.0
+    /// - WASD to thrust, space and left control to rise and sink, Q and E to roll
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            sensitivity: Vec3::ONE,
+            roll_speed: 2.0,
+            thrust: 20.0,
+            boost_multiplier: 3.0,
+            max_speed: Some(50.0),
+            damping: Some(1.0),
+            bindings: FlightBindings::default(),
+            velocity: Vec3::ZERO,
+        }
+    }
+
+    /// Sets the sensitivity of pitch, yaw and roll input
+    ///
+    /// # Arguments
+    /// * `sensitivity` - Radians turned per unit of pitch, yaw and roll input
+    #[inline]
+    pub fn with_sensitivity(mut self, sensitivity: Vec3) -> Self {
+        self.sensitivity = sensitivity;
+        self
+    }
+
+    /// Sets the thrust acceleration
+    ///
+    /// # Arguments
+    /// * `thrust` - Acceleration in meters per second squared
+    #[inline]
+    pub fn with_thrust(mut self, thrust: f32) -> Self {
+        self.thrust = thrust;
+        self
+    }
+
+    /// Sets the maximum speed
+    ///
+    /// # Arguments
+    /// * `max_speed` - Speed in meters per second
+    #[inline]
+    pub fn with_max_speed(mut self, max_speed: f32) -> Self {
+        self.max_speed = Some(max_speed);
+        self
+    }
+
+    /// Sets the rate at which velocity decays without thrust
+    ///
+    /// # Arguments
+    /// * `damping` - Decay rate, larger values stop sooner
+    #[inline]
+    pub fn with_damping(mut self, damping: f32) -> Self {
+        self.damping = Some(damping);
+        self
+    }
+
+    /// Removes damping and the maximum speed, so the ship drifts until thrust is applied
+    /// against its motion
+    #[inline]
+    pub fn newtonian(mut self) -> Self {
+        self.damping = None;
+        self.max_speed = None;
+        self
+    }
+
+    /// Sets the keys used for control
+    ///
+    /// # Arguments
+    /// * `bindings` - Key bindings
+    #[inline]
+    pub fn with_bindings(mut self, bindings: FlightBindings) -> Self {
+        self.bindings = bindings;
+        self
+    }
+
+    /// Returns the velocity of the ship in world units per second
+    #[inline]
+    pub fn velocity(&self) -> Vec3 {
+        self.velocity
+    }
+
+    /// Sets the velocity of the ship
+    ///
+    /// # Arguments
+    /// * `velocity` - Velocity in world units per second
+    #[inline]
+    pub fn set_velocity(&mut self, velocity: Vec3) {
+        self.velocity = velocity;
+    }
+}
+
+/// A chase camera added directly to a camera entity, trailing behind a target at an offset
+/// in the target's local space and banking with it, such as behind a [`FlightController`]
+#[derive(Component, Clone, Debug)]
+pub struct ChaseCamera {
+    /// Entity the camera chases
+    pub target: Entity,
+    /// Offset from the target in meters, in the target's local space
+    pub offset: Vec3,
+    /// Distance in meters ahead of the target the camera looks at
+    pub look_ahead: f32,
+    /// Rate at which translation decays with smooth interpolation
+    translation_decay_rate: f32,
+    /// Rate at which rotation decays with smooth interpolation
+    rotation_decay_rate: f32,
+}
+
+impl ChaseCamera {
+    /// Creates a new ChaseCamera preset with default settings:
+    /// - Offset: 2 meters above and 8 meters behind the target
+    /// - Look ahead: 10 meters
+    /// - Translation smoothing: 0.1, rotation smoothing: 0.15
+    ///
+    /// # Arguments
+    /// * `target` - Entity the camera chases
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            offset: Vec3::new(0.0, 2.0, 8.0),
+            look_ahead: 10.0,
+            translation_decay_rate: 1.0 / 0.1,
+            rotation_decay_rate: 1.0 / 0.15,
+        }
+    }
+
+    /// Sets the offset from the target
+    ///
+    /// # Arguments
+    /// * `offset` - Offset in meters, in the target's local space
+    #[inline]
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the distance ahead of the target the camera looks at
+    ///
+    /// # Arguments
+    /// * `look_ahead` - Distance in meters
+    #[inline]
+    pub fn with_look_ahead(mut self, look_ahead: f32) -> Self {
+        self.look_ahead = look_ahead;
+        self
+    }
+
+    /// Sets smoothing factors for translation and rotation.
+    /// Larger values give smoother movement.
+    ///
+    /// # Arguments
+    /// * `translation` - Smoothing factor for translation
+    /// * `rotation` - Smoothing factor for rotation
+    #[inline]
+    pub fn with_smoothing(mut self, translation: f32, rotation: f32) -> Self {
+        self.translation_decay_rate = 1.0 / translation;
+        self.rotation_decay_rate = 1.0 / rotation;
+        self
+    }
+}
+
+/// Turns and thrusts flight controllers from their buffers and keyboard input,
+/// skipping ships that are not routed the keyboard and mouse for thrust
+///
+/// # Arguments
+/// * `ships` - Query for flight controllers, their buffers, transforms and input routing
+/// * `keys` - Keyboard input, if the input plugin is present
+/// * `units` - Resource describing the scale of the world
+/// * `time` - Resource providing frame timing information
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_flight_controllers(
+    mut ships: Query<
+        (
+            &mut FlightController,
+            &mut CameraBuffer,
+            &mut Transform,
+            Option<&CameraPlayer>,
+            Option<&InputSources>,
+        ),
+        Without<CameraControllerPaused>,
+    >,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    units: Res<WorldUnits>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+
+    for (mut ship, mut buffer, mut transform, player, sources) in ships.iter_mut() {
+        if !ship.enabled {
+            buffer.clear();
+            continue;
+        }
+
+        let keys = keys
+            .as_deref()
+            .filter(|_| InputSources::routes(sources, player, InputSource::Mouse));
+        let bindings = &ship.bindings;
+        let axis = |positive: KeyCode, negative: KeyCode| {
+            keys.map_or(0.0, |keys| {
+                keys.pressed(positive) as i32 as f32 - keys.pressed(negative) as i32 as f32
+            })
+        };
+
+        // turn around the ship's own axes
+        buffer.flush_channels();
+        let look = buffer.take();
+        let roll = buffer.take_roll() * ship.sensitivity.z
+            + axis(bindings.roll_left, bindings.roll_right) * ship.roll_speed * dt;
+        let turn = Quat::from_euler(
+            EulerRot::YXZ,
+            look.x * ship.sensitivity.y,
+            look.y * ship.sensitivity.x,
+            roll,
+        );
+        if turn != Quat::IDENTITY {
+            transform.rotation = (transform.rotation * turn).normalize();
+        }
+
+        // thrust along the ship's local axes
+        let input = Vec3::new(
+            axis(bindings.right, bindings.left),
+            axis(bindings.up, bindings.down),
+            axis(bindings.back, bindings.forward),
+        )
+        .normalize_or_zero();
+        let mut thrust = units.to_world(ship.thrust);
+        if keys.is_some_and(|keys| keys.pressed(bindings.boost)) {
+            thrust *= ship.boost_multiplier;
+        }
+        let mut velocity = ship.velocity + transform.rotation * input * thrust * dt;
+
+        if let Some(damping) = ship.damping {
+            velocity *= (-damping * dt).exp();
+        }
+        if let Some(max_speed) = ship.max_speed {
+            velocity = velocity.clamp_length_max(units.to_world(max_speed));
+        }
+        ship.velocity = velocity;
+
+        if velocity != Vec3::ZERO {
+            transform.translation += velocity * dt;
+        }
+    }
+}
+
+/// Moves chase cameras behind their targets with smooth interpolation
+///
+/// # Arguments
+/// * `cameras` - Query for chase cameras and their transforms
+/// * `target_transforms` - Query for target transforms
+/// * `units` - Resource describing the scale of the world
+/// * `time` - Resource providing frame timing information
+/// * `skipped` - Chase cameras skipped for missing targets, warned about once
+pub(crate) fn update_chase_cameras(
+    mut cameras: Query<(Entity, &ChaseCamera, &mut Transform), With<Camera>>,
+    target_transforms: Query<TargetTransform, Without<Camera>>,
+    units: Res<WorldUnits>,
+    time: Res<Time>,
+    mut skipped: Local<SkippedEntities>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, chase, mut transform) in cameras.iter_mut() {
+        let Some(target_transform) = skipped.check(
+            entity,
+            target::get_transform(&target_transforms, chase.target),
+        ) else {
+            continue;
+        };
+
+        let goal =
+            target_transform.translation + target_transform.rotation * units.to_world(chase.offset);
+        let mut translation = transform.translation;
+        if chase.translation_decay_rate.is_finite() {
+            translation.smooth_nudge(&goal, chase.translation_decay_rate, dt);
+        } else {
+            translation = goal;
+        }
+
+        // look ahead of the target, banking with it
+        let look_point = target_transform.translation
+            + target_transform.forward() * units.to_world(chase.look_ahead);
+        let goal = Transform::from_translation(translation)
+            .looking_at(look_point, target_transform.up())
+            .rotation;
+        let mut rotation = transform.rotation;
+        if chase.rotation_decay_rate.is_finite() {
+            rotation.smooth_nudge(&goal, chase.rotation_decay_rate, dt);
+        } else {
+            rotation = goal;
+        }
+
+        transform.translation = translation;
+        transform.rotation = rotation;
+    }
+}
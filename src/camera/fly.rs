@@ -0,0 +1,218 @@
+use bevy::{
+    input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll, MouseScrollUnit},
+    prelude::*,
+};
+
+use super::{CameraControllerPaused, CameraPlayer, InputSource, InputSources, WorldUnits};
+
+/// Keys and buttons used by a [`FlyCameraController`]
+#[derive(Clone, Debug)]
+pub struct FlyCameraBindings {
+    pub forward: KeyCode,
+    pub back: KeyCode,
+    pub left: KeyCode,
+    pub right: KeyCode,
+    pub up: KeyCode,
+    pub down: KeyCode,
+    /// Held to move faster
+    pub fast: KeyCode,
+    /// Held to move slower
+    pub slow: KeyCode,
+    /// Held to look around with the mouse, or always look if unset
+    pub look: Option<MouseButton>,
+}
+
+impl Default for FlyCameraBindings {
+    fn default() -> Self {
+        Self {
+            forward: KeyCode::KeyW,
+            back: KeyCode::KeyS,
+            left: KeyCode::KeyA,
+            right: KeyCode::KeyD,
+            up: KeyCode::KeyE,
+            down: KeyCode::KeyQ,
+            fast: KeyCode::ShiftLeft,
+            slow: KeyCode::ControlLeft,
+            look: Some(MouseButton::Right),
+        }
+    }
+}
+
+/// A free flying spectator camera for debug builds and editor tools, added directly
+/// to a camera entity. Moves with WASD, looks with the mouse and changes speed by scrolling.
+#[derive(Component, Clone, Debug)]
+pub struct FlyCameraController {
+    /// Whether the camera responds to input
+    pub enabled: bool,
+    /// Movement speed in meters per second
+    pub speed: f32,
+    /// Minimum and maximum speed reachable by scrolling
+    pub speed_limits: (f32, f32),
+    /// Multiplier applied to speed per scroll line
+    pub scroll_factor: f32,
+    /// Multiplier applied to speed while the fast key is held
+    pub fast_multiplier: f32,
+    /// Multiplier applied to speed while the slow key is held
+    pub slow_multiplier: f32,
+    /// Radians turned per pixel of mouse movement
+    pub sensitivity: f32,
+    /// Keys and buttons used for control
+    pub bindings: FlyCameraBindings,
+    /// Yaw and pitch of the camera, read from the transform on the first update
+    angles: Option<Vec2>,
+}
+
+impl Default for FlyCameraController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FlyCameraController {
+    /// Creates a new FlyCameraController with default settings:
+    /// - Speed: 5.0 meters per second, scrollable from 0.1 to 500.0
+    /// - Fast multiplier: 4.0, slow multiplier: 0.25
+    /// - Sensitivity: 0.003 radians per pixel
+    /// - WASD to move, E and Q to rise and sink, hold right mouse button to look
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            speed: 5.0,
+            speed_limits: (0.1, 500.0),
+            scroll_factor: 1.1,
+            fast_multiplier: 4.0,
+            slow_multiplier: 0.25,
+            sensitivity: 0.003,
+            bindings: FlyCameraBindings::default(),
+            angles: None,
+        }
+    }
+
+    /// Sets the movement speed
+    ///
+    /// # Arguments
+    /// * `speed` - Speed in meters per second
+    #[inline]
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Sets the mouse look sensitivity
+    ///
+    /// # Arguments
+    /// * `sensitivity` - Radians turned per pixel of mouse movement
+    #[inline]
+    pub fn with_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.sensitivity = sensitivity;
+        self
+    }
+
+    /// Sets the keys and buttons used for control
+    ///
+    /// # Arguments
+    /// * `bindings` - Key and button bindings
+    #[inline]
+    pub fn with_bindings(mut self, bindings: FlyCameraBindings) -> Self {
+        self.bindings = bindings;
+        self
+    }
+}
+
+/// Moves fly cameras from keyboard and mouse input, skipping cameras that are not
+/// routed the keyboard and mouse
+///
+/// # Arguments
+/// * `fly_cameras` - Query for fly cameras, their transforms, players and input sources
+/// * `keys` - Keyboard input, if the input plugin is present
+/// * `buttons` - Mouse button input, if the input plugin is present
+/// * `motion` - Mouse motion accumulated this frame
+/// * `scroll` - Mouse scrolling accumulated this frame
+/// * `units` - Resource describing the scale of the world
+/// * `time` - Resource providing frame timing information
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_fly_cameras(
+    mut fly_cameras: Query<
+        (
+            &mut FlyCameraController,
+            &mut Transform,
+            Option<&CameraPlayer>,
+            Option<&InputSources>,
+        ),
+        Without<CameraControllerPaused>,
+    >,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    buttons: Option<Res<ButtonInput<MouseButton>>>,
+    motion: Option<Res<AccumulatedMouseMotion>>,
+    scroll: Option<Res<AccumulatedMouseScroll>>,
+    units: Res<WorldUnits>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+
+    for (mut fly, mut transform, player, sources) in fly_cameras.iter_mut() {
+        if !fly.enabled || !InputSources::routes(sources, player, InputSource::Mouse) {
+            continue;
+        }
+
+        // scrolling changes speed rather than moving
+        if let Some(scroll) = scroll.as_deref() {
+            let lines = match scroll.unit {
+                MouseScrollUnit::Line => scroll.delta.y,
+                MouseScrollUnit::Pixel => scroll.delta.y / 16.0,
+            };
+            if lines != 0.0 {
+                let (min, max) = fly.speed_limits;
+                fly.speed = (fly.speed * fly.scroll_factor.powf(lines)).clamp(min, max);
+            }
+        }
+
+        // look around while the look button is held
+        let looking = match fly.bindings.look {
+            Some(button) => buttons.as_deref().is_some_and(|b| b.pressed(button)),
+            None => true,
+        };
+        let mut angles = fly.angles.unwrap_or_else(|| {
+            let (yaw, pitch, _) = transform.rotation.to_euler(EulerRot::YXZ);
+            Vec2::new(yaw, pitch)
+        });
+        if looking && let Some(motion) = motion.as_deref() {
+            angles -= motion.delta * fly.sensitivity;
+            let limit = std::f32::consts::FRAC_PI_2 - 0.01;
+            angles.y = angles.y.clamp(-limit, limit);
+        }
+        if fly.angles != Some(angles) {
+            fly.angles = Some(angles);
+            transform.rotation = Quat::from_euler(EulerRot::YXZ, angles.x, angles.y, 0.0);
+        }
+
+        let Some(keys) = keys.as_deref() else {
+            continue;
+        };
+        let bindings = &fly.bindings;
+        let axis = |positive: KeyCode, negative: KeyCode| {
+            keys.pressed(positive) as i32 as f32 - keys.pressed(negative) as i32 as f32
+        };
+        let input = Vec3::new(
+            axis(bindings.right, bindings.left),
+            axis(bindings.up, bindings.down),
+            axis(bindings.back, bindings.forward),
+        );
+        if input == Vec3::ZERO {
+            continue;
+        }
+
+        let mut speed = fly.speed;
+        if keys.pressed(bindings.fast) {
+            speed *= fly.fast_multiplier;
+        }
+        if keys.pressed(bindings.slow) {
+            speed *= fly.slow_multiplier;
+        }
+
+        // move horizontally relative to the view and vertically along world up
+        let horizontal = transform.rotation * Vec3::new(input.x, 0.0, input.z);
+        let direction = (horizontal + Vec3::Y * input.y).normalize_or_zero();
+        transform.translation += direction * units.to_world(speed) * dt;
+    }
+}
@@ -0,0 +1,143 @@
+use bevy::{
+    math::bounding::{Aabb3d, BoundingVolume},
+    prelude::*,
+    render::primitives::Aabb,
+};
+
+use super::{CameraAnchor, CameraController, WorldUnits};
+
+/// Radius in meters framed around targets without bounds
+const MIN_RADIUS: f32 = 0.5;
+
+/// What [`CameraController::frame`] fits in view
+#[derive(Clone, Copy, Debug)]
+pub enum FrameTarget {
+    /// World space bounds in world units
+    Bounds(Aabb3d),
+    /// An entity, fitting the mesh bounds of it and its descendants
+    Entity(Entity),
+}
+
+impl From<Aabb3d> for FrameTarget {
+    fn from(bounds: Aabb3d) -> Self {
+        Self::Bounds(bounds)
+    }
+}
+
+impl From<Entity> for FrameTarget {
+    fn from(entity: Entity) -> Self {
+        Self::Entity(entity)
+    }
+}
+
+/// A request to fit a target in view, made with [`CameraController::frame`]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct FrameRequest {
+    /// What to fit in view
+    pub target: FrameTarget,
+    /// Fraction of the target's size added around it
+    pub margin: f32,
+    /// Duration in seconds of the move
+    pub duration: f32,
+}
+
+/// Returns the world space bounds of an entity and its descendants, from their mesh bounds
+/// or their positions where they have none
+///
+/// # Arguments
+/// * `entity` - Root entity
+/// * `transforms` - Query for global transforms and mesh bounds
+/// * `children` - Query for children
+fn entity_bounds(
+    entity: Entity,
+    transforms: &Query<(&GlobalTransform, Option<&Aabb>)>,
+    children: &Query<&Children>,
+) -> Option<Aabb3d> {
+    let mut bounds: Option<Aabb3d> = None;
+    let mut points = Vec::new();
+    for entity in core::iter::once(entity).chain(children.iter_descendants(entity)) {
+        let Ok((transform, aabb)) = transforms.get(entity) else {
+            continue;
+        };
+        points.clear();
+        match aabb {
+            Some(aabb) => {
+                let (center, half) = (Vec3::from(aabb.center), Vec3::from(aabb.half_extents));
+                for corner in 0..8 {
+                    let sign = Vec3::new(
+                        if corner & 1 == 0 { -1.0 } else { 1.0 },
+                        if corner & 2 == 0 { -1.0 } else { 1.0 },
+                        if corner & 4 == 0 { -1.0 } else { 1.0 },
+                    );
+                    points.push(transform.transform_point(center + half * sign));
+                }
+            }
+            None => points.push(transform.translation()),
+        }
+        let entity_bounds = Aabb3d::from_point_cloud(Isometry3d::IDENTITY, points.iter().copied());
+        bounds = Some(match bounds {
+            Some(bounds) => bounds.merge(&entity_bounds),
+            None => entity_bounds,
+        });
+    }
+    bounds
+}
+
+/// Resolves frame requests, orbiting the center of each target at the distance that fits
+/// its bounding sphere in the camera's field of view
+///
+/// # Arguments
+/// * `camera_controllers` - Query for controllers
+/// * `projections` - Query for camera projections
+/// * `transforms` - Query for global transforms and mesh bounds of framed entities
+/// * `children` - Query for children of framed entities
+/// * `units` - Resource describing the scale of the world
+pub(crate) fn frame_targets(
+    mut camera_controllers: Query<&mut CameraController>,
+    projections: Query<&Projection>,
+    transforms: Query<(&GlobalTransform, Option<&Aabb>)>,
+    children: Query<&Children>,
+    units: Res<WorldUnits>,
+) {
+    for mut controller in camera_controllers.iter_mut() {
+        let Some(request) = controller.frame_request else {
+            continue;
+        };
+        controller.frame_request = None;
+        let bounds = match request.target {
+            FrameTarget::Bounds(bounds) => bounds,
+            FrameTarget::Entity(entity) => match entity_bounds(entity, &transforms, &children) {
+                Some(bounds) => bounds,
+                None => continue,
+            },
+        };
+
+        let center = Vec3::from(bounds.center());
+        let radius = Vec3::from(bounds.half_size())
+            .length()
+            .max(units.to_world(MIN_RADIUS))
+            * (1.0 + request.margin);
+        // fit the sphere in the narrower of the vertical and horizontal field of view
+        let distance = match projections.get(controller.camera) {
+            Ok(Projection::Perspective(perspective)) => {
+                let half_fov = perspective.fov * 0.5;
+                let half_fov = half_fov.min((half_fov.tan() * perspective.aspect_ratio).atan());
+                radius / half_fov.sin().max(f32::EPSILON)
+            }
+            _ => radius * 2.0,
+        };
+        let mut distance = units.to_meters(distance);
+        if let Some((min, max)) = controller.distance_limits {
+            distance = distance.clamp(min, max);
+        }
+
+        let view = controller.view.clone();
+        controller.set_orbit_pivot(center, 0.0);
+        controller.transition_to(
+            CameraAnchor::Orbit { distance },
+            view,
+            request.duration,
+            EaseFunction::SmoothStep,
+        );
+    }
+}
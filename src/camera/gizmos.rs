@@ -0,0 +1,275 @@
+use bevy::{color::palettes::css, prelude::*};
+
+use super::{
+    CameraAnchor, CameraBounds, CameraBuffer, CameraConfiner2d, CameraController,
+    CameraController2d, CameraView, CameraView2d, WorldUnits,
+    target::{self, CameraFilter, SkippedEntities, TargetFilter, TargetTransform},
+};
+
+/// Resource drawing gizmos for every camera rig while present, which helps explain why a
+/// camera ended up where it is. Each part of the rig can be toggled separately.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct CameraGizmos {
+    /// Draws the point the camera is anchored to and the point it looks at
+    pub targets: bool,
+    /// Draws the offset from the controller entity to the anchored point
+    pub offsets: bool,
+    /// Draws the orbit sphere and arm of orbiting and isometric cameras
+    pub orbits: bool,
+    /// Draws the cones of reachable pitch angles
+    pub pitch_limits: bool,
+    /// Draws the follow distance or follow window of 2D cameras
+    pub dead_zones: bool,
+    /// Draws [`CameraBounds`] and [`CameraConfiner2d`] regions
+    pub bounds: bool,
+    /// Draws the spring arm ray, green up to the camera and red where it is blocked
+    pub collision: bool,
+}
+
+impl Default for CameraGizmos {
+    fn default() -> Self {
+        Self {
+            targets: true,
+            offsets: true,
+            orbits: true,
+            pitch_limits: true,
+            dead_zones: true,
+            bounds: true,
+            collision: true,
+        }
+    }
+}
+
+impl CameraGizmos {
+    /// Creates a new CameraGizmos drawing every part of the rig
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Draws the rig of each 3D camera controller
+///
+/// # Arguments
+/// * `settings` - Resource selecting the parts of the rig to draw
+/// * `gizmos` - Gizmos used for drawing
+/// * `controllers` - Query for camera controllers, their buffers and optional bounds
+/// * `cameras` - Query for camera transforms
+/// * `target_transforms` - Query for controller and target transforms
+/// * `units` - Resource converting meters to world units
+/// * `skipped` - Controllers skipped for missing entities, warned about once
+pub(crate) fn draw_camera_gizmos(
+    settings: Res<CameraGizmos>,
+    mut gizmos: Gizmos,
+    controllers: Query<(
+        Entity,
+        &CameraController,
+        &CameraBuffer,
+        Option<&CameraBounds>,
+    )>,
+    cameras: Query<&Transform, CameraFilter>,
+    target_transforms: Query<TargetTransform, TargetFilter>,
+    units: Res<WorldUnits>,
+    mut skipped: Local<SkippedEntities>,
+) {
+    for (entity, controller, buffer, bounds) in controllers.iter() {
+        if !controller.enabled {
+            continue;
+        }
+        let Ok(camera_transform) = cameras.get(controller.camera) else {
+            continue;
+        };
+        let Some(controller_transform) =
+            skipped.check(entity, target::get_transform(&target_transforms, entity))
+        else {
+            continue;
+        };
+        let pivot = controller_transform.translation
+            + controller_transform.rotation * units.to_world(controller.offset)
+            + buffer.pan_offset;
+        let yaw_axis = controller.yaw_axis.as_vec3();
+
+        if settings.offsets {
+            gizmos.line(controller_transform.translation, pivot, css::ORANGE);
+        }
+
+        if settings.targets {
+            gizmos.sphere(Isometry3d::from_translation(pivot), 0.1, css::YELLOW);
+            if let CameraView::Target(target) = controller.view
+                && let Ok(target_transform) = target::get_transform(&target_transforms, target)
+            {
+                let look_point = target_transform.translation
+                    + target_transform.rotation * units.to_world(controller.look_offset);
+                gizmos.sphere(Isometry3d::from_translation(look_point), 0.1, css::AQUA);
+                gizmos.line(camera_transform.translation, look_point, css::AQUA);
+            }
+        }
+
+        let distance = match controller.anchor {
+            CameraAnchor::Orbit { distance } | CameraAnchor::Isometric { distance, .. } => {
+                Some(units.to_world(distance))
+            }
+            _ => None,
+        };
+
+        if settings.orbits
+            && let Some(distance) = distance
+        {
+            let rotation = Quat::from_rotation_arc(Vec3::Z, yaw_axis);
+            gizmos.sphere(
+                Isometry3d::new(pivot, rotation),
+                distance,
+                css::GRAY.with_alpha(0.3),
+            );
+            // the arm the camera is placed along before collision
+            gizmos.line(
+                pivot,
+                pivot + buffer.rotation * Vec3::Z * distance,
+                css::GRAY,
+            );
+        }
+
+        if settings.pitch_limits
+            && let Some((min, max)) = controller.pitch_limits()
+        {
+            // orbiting cameras sit opposite their view direction around the pivot,
+            // first person cameras look out from their own position
+            let (apex, length, sign) = match distance {
+                Some(distance) => (pivot, distance, -1.0),
+                None => (camera_transform.translation, units.to_world(1.0), 1.0),
+            };
+            for pitch in [min, max] {
+                pitch_cone(&mut gizmos, apex, yaw_axis, sign * pitch, length);
+            }
+        }
+
+        if settings.bounds
+            && let Some(bounds) = bounds
+        {
+            draw_bounds(&mut gizmos, bounds);
+        }
+
+        #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+        if settings.collision
+            && let Some((unobstructed, length)) = buffer.spring_arm
+        {
+            let direction = buffer.rotation * Vec3::Z;
+            gizmos.line(pivot, pivot + direction * length, css::LIME);
+            if unobstructed > length {
+                gizmos.line(
+                    pivot + direction * length,
+                    pivot + direction * unobstructed,
+                    css::RED,
+                );
+            }
+        }
+    }
+}
+
+/// Draws the dead zones and bounds of each 2D camera controller
+///
+/// # Arguments
+/// * `settings` - Resource selecting the parts of the rig to draw
+/// * `gizmos` - Gizmos used for drawing
+/// * `controllers` - Query for 2D camera controllers and their optional bounds
+/// * `cameras` - Query for camera transforms and projections
+/// * `target_transforms` - Query for target transforms
+/// * `units` - Resource converting meters to world units
+#[allow(clippy::type_complexity)]
+pub(crate) fn draw_camera_gizmos_2d(
+    settings: Res<CameraGizmos>,
+    mut gizmos: Gizmos,
+    controllers: Query<(
+        &CameraController2d,
+        Option<&CameraBounds>,
+        Option<&CameraConfiner2d>,
+    )>,
+    cameras: Query<(&Transform, &Projection), With<Camera>>,
+    target_transforms: Query<TargetTransform, Without<Camera>>,
+    units: Res<WorldUnits>,
+) {
+    for (controller, bounds, confiner) in controllers.iter() {
+        let Ok((camera_transform, Projection::Orthographic(orthographic))) =
+            cameras.get(controller.camera)
+        else {
+            continue;
+        };
+        let center = camera_transform.translation.truncate();
+        let rotation = Rot2::radians(camera_transform.rotation.to_euler(EulerRot::ZYX).0);
+
+        if settings.dead_zones {
+            let isometry = Isometry2d::new(center, rotation);
+            match (&controller.follow_window, &controller.view) {
+                (Some(window), _) => {
+                    let size = window.half_extents * orthographic.area.half_size() * 2.0;
+                    gizmos.rect_2d(isometry, size, css::ORANGE);
+                }
+                (None, CameraView2d::Follow { radius, .. }) => {
+                    gizmos.circle_2d(isometry, units.to_world(*radius), css::ORANGE);
+                }
+                (None, CameraView2d::Manual) => {}
+            }
+        }
+
+        if settings.targets
+            && let CameraView2d::Follow { target, .. } = controller.view
+            && let Ok(target_transform) = target::get_transform(&target_transforms, target)
+        {
+            let focus = target_transform.translation.truncate() + rotation * controller.offset;
+            gizmos.cross_2d(Isometry2d::from_translation(focus), 0.25, css::YELLOW);
+        }
+
+        if settings.bounds {
+            if let Some(bounds) = bounds {
+                draw_bounds(&mut gizmos, bounds);
+            }
+            if let Some(confiner) = confiner {
+                gizmos.rect_2d(
+                    Isometry2d::from_translation(confiner.rect.center()),
+                    confiner.rect.size(),
+                    css::FUCHSIA,
+                );
+            }
+        }
+    }
+}
+
+/// Draws the circle of directions at a pitch angle, with lines from the apex
+///
+/// # Arguments
+/// * `gizmos` - Gizmos used for drawing
+/// * `apex` - Point the cone opens from
+/// * `yaw_axis` - Axis the cone is centered on
+/// * `pitch` - Angle in radians above the plane normal to the yaw axis
+/// * `length` - Length of the cone's sides
+fn pitch_cone(gizmos: &mut Gizmos, apex: Vec3, yaw_axis: Vec3, pitch: f32, length: f32) {
+    let center = apex + yaw_axis * pitch.sin() * length;
+    let radius = pitch.cos() * length;
+    gizmos.circle(
+        Isometry3d::new(center, Quat::from_rotation_arc(Vec3::Z, yaw_axis)),
+        radius,
+        css::YELLOW_GREEN,
+    );
+    let (a, b) = yaw_axis.any_orthonormal_pair();
+    for side in [a, b, -a, -b] {
+        gizmos.line(apex, center + side * radius, css::YELLOW_GREEN);
+    }
+}
+
+/// Draws the region of a [`CameraBounds`]
+///
+/// # Arguments
+/// * `gizmos` - Gizmos used for drawing
+/// * `bounds` - Bounds to draw
+fn draw_bounds(gizmos: &mut Gizmos, bounds: &CameraBounds) {
+    match *bounds {
+        CameraBounds::Box { min, max } => gizmos.cuboid(
+            Transform::from_translation((min + max) / 2.0).with_scale(max - min),
+            css::FUCHSIA,
+        ),
+        CameraBounds::Rect(rect) => gizmos.rect_2d(
+            Isometry2d::from_translation(rect.center()),
+            rect.size(),
+            css::FUCHSIA,
+        ),
+    }
+}
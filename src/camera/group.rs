@@ -0,0 +1,162 @@
+use bevy::{ecs::query::QueryFilter, prelude::*};
+
+use super::{
+    WorldUnits,
+    target::{self, TargetTransform},
+};
+
+/// A target entity that is part of a [`CameraTargetGroup`]
+#[derive(Clone, Copy, Debug)]
+pub struct GroupTarget {
+    /// Entity to keep on screen
+    pub entity: Entity,
+    /// Influence of the target on the center of the group, targets with zero weight are ignored
+    pub weight: f32,
+    /// Radius around the target that is kept on screen, in meters
+    pub radius: f32,
+}
+
+/// A component that frames several targets at once, keeping them all on screen.
+///
+/// When added to a controller entity the controller follows the weighted center of
+/// the group instead of its own target. 3D orbit controllers move further out and 2D
+/// controllers zoom out until the group fits within the screen margin, but never closer
+/// than the controller's own distance or zoom.
+#[derive(Component, Clone, Debug)]
+pub struct CameraTargetGroup {
+    /// Targets to keep on screen
+    pub targets: Vec<GroupTarget>,
+    /// Fraction of each half of the screen kept free around the group, from 0 to 1
+    pub margin: f32,
+}
+
+impl Default for CameraTargetGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Weighted center and extent of a [`CameraTargetGroup`] in world units
+pub(crate) struct GroupBounds {
+    /// Weighted center of the group
+    pub center: Vec3,
+    /// Half size of the box around the center that contains every target
+    pub half_extents: Vec3,
+    /// Radius of the sphere around the center that contains every target
+    pub radius: f32,
+}
+
+impl CameraTargetGroup {
+    /// Creates a new empty CameraTargetGroup with a margin of 0.1
+    pub fn new() -> Self {
+        Self {
+            targets: Vec::new(),
+            margin: 0.1,
+        }
+    }
+
+    /// Adds a target to the group
+    ///
+    /// # Arguments
+    /// * `entity` - Entity to keep on screen
+    /// * `weight` - Influence of the target on the center of the group
+    /// * `radius` - Radius around the target kept on screen, in meters
+    #[inline]
+    pub fn with_target(mut self, entity: Entity, weight: f32, radius: f32) -> Self {
+        self.targets.push(GroupTarget {
+            entity,
+            weight,
+            radius,
+        });
+        self
+    }
+
+    /// Sets the fraction of the screen kept free around the group
+    ///
+    /// # Arguments
+    /// * `margin` - Fraction of each half of the screen, clamped between 0 and 0.95
+    #[inline]
+    pub fn with_margin(mut self, margin: f32) -> Self {
+        self.margin = margin.clamp(0.0, 0.95);
+        self
+    }
+
+    /// Removes a target from the group
+    ///
+    /// # Arguments
+    /// * `entity` - Entity to remove
+    pub fn remove_target(&mut self, entity: Entity) {
+        self.targets.retain(|target| target.entity != entity);
+    }
+
+    /// Computes the weighted center and extent of the group,
+    /// returns None if no target has any weight
+    ///
+    /// # Arguments
+    /// * `query` - Query for target transforms
+    /// * `units` - Resource describing the scale of the world
+    pub(crate) fn bounds<F: QueryFilter>(
+        &self,
+        query: &Query<TargetTransform, F>,
+        units: &WorldUnits,
+    ) -> Result<Option<GroupBounds>, BevyError> {
+        let mut points = Vec::with_capacity(self.targets.len());
+        let mut weighted = Vec3::ZERO;
+        let mut total_weight = 0.0;
+        for target in self.targets.iter().filter(|target| target.weight > 0.0) {
+            let translation = target::get_transform(query, target.entity)?.translation;
+            weighted += translation * target.weight;
+            total_weight += target.weight;
+            points.push((translation, units.to_world(target.radius)));
+        }
+        if total_weight <= 0.0 {
+            return Ok(None);
+        }
+
+        let center = weighted / total_weight;
+        let (half_extents, radius) = points.iter().fold(
+            (Vec3::ZERO, 0.0f32),
+            |(half_extents, radius), (translation, target_radius)| {
+                let offset = *translation - center;
+                (
+                    half_extents.max(offset.abs() + *target_radius),
+                    radius.max(offset.length() + target_radius),
+                )
+            },
+        );
+        Ok(Some(GroupBounds {
+            center,
+            half_extents,
+            radius,
+        }))
+    }
+}
+
+impl CameraTargetGroup {
+    /// Gets the distance a perspective camera needs to keep the group sphere on screen
+    ///
+    /// # Arguments
+    /// * `bounds` - Extent of the group
+    /// * `perspective` - Projection of the camera
+    pub(crate) fn fit_distance(
+        &self,
+        bounds: &GroupBounds,
+        perspective: &PerspectiveProjection,
+    ) -> f32 {
+        let half_vertical = perspective.fov * 0.5;
+        let half_horizontal = (half_vertical.tan() * perspective.aspect_ratio).atan();
+        // shrink the usable field of view by the margin
+        let half_fov = (half_vertical.min(half_horizontal).tan() * (1.0 - self.margin)).atan();
+        bounds.radius / half_fov.sin().max(f32::EPSILON)
+    }
+
+    /// Gets the orthographic scale needed to keep the group box on screen
+    ///
+    /// # Arguments
+    /// * `bounds` - Extent of the group
+    /// * `unit_half_size` - Half size of the visible area at a scale of 1
+    pub(crate) fn fit_scale(&self, bounds: &GroupBounds, unit_half_size: Vec2) -> f32 {
+        let usable = unit_half_size * (1.0 - self.margin);
+        (bounds.half_extents.truncate() / usable.max(Vec2::splat(f32::EPSILON))).max_element()
+    }
+}
@@ -0,0 +1,940 @@
+use bevy::{
+    input::{
+        InputSystem,
+        mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll, MouseScrollUnit},
+    },
+    platform::collections::HashMap,
+    prelude::*,
+    window::{CursorGrabMode, PrimaryWindow},
+};
+
+use super::{
+    CameraAnchor, CameraBuffer, CameraController, CameraController2d, CameraPlayer,
+    CameraTimeSource, DeltaBuffer2d, EditorNavigation, FlightController,
+    navigation::feed_editor_navigation, time::CameraTime,
+};
+
+/// Plugin feeding mouse motion and scrolling into camera buffers, so simple games
+/// need no input systems of their own. Settings can be changed at runtime through
+/// the [`MouseLook`] and [`TouchGestures`] resources. Controllers with a [`GamepadLook`]
+/// are also driven by their gamepad's right stick, controllers with an [`EdgeScroll`]
+/// pan while the cursor rests at the edge of the window, and controllers with an
+/// [`EditorNavigation`] are navigated with mouse drags instead of mouse look.
+#[derive(Default)]
+pub struct CameraInputPlugin {
+    /// Initial mouse look settings
+    pub mouse_look: MouseLook,
+    /// Initial touch gesture settings
+    pub touch: TouchGestures,
+}
+
+impl Plugin for CameraInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.mouse_look.clone())
+            .insert_resource(self.touch.clone())
+            .add_systems(
+                PreUpdate,
+                (
+                    feed_mouse_look,
+                    feed_gamepad_look,
+                    feed_touch_gestures,
+                    feed_edge_scroll,
+                    feed_editor_navigation,
+                )
+                    .after(InputSystem),
+            );
+    }
+}
+
+/// A device that camera input can come from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// Keyboard and mouse
+    Mouse,
+    /// Touch screen
+    Touch,
+    /// A specific gamepad
+    Gamepad(Entity),
+    /// Any connected gamepad
+    AnyGamepad,
+    /// Input fed by game code, such as AI directors or UI widgets, identified by name
+    Custom(&'static str),
+}
+
+/// A component listing the devices a controller, fly or RTS camera, or standalone
+/// [`DeltaBuffer`](super::DeltaBuffer) consumes, so different controllers can be driven by
+/// different devices and world cameras can ignore input meant for UI.
+///
+/// Without this component input is routed by the entity's [`CameraPlayer`], and entities
+/// without either receive input from every device.
+#[derive(Component, Clone, Debug, Default)]
+pub struct InputSources {
+    /// Devices the entity consumes
+    sources: Vec<InputSource>,
+}
+
+impl InputSources {
+    /// Creates a new InputSources from a list of devices
+    ///
+    /// # Arguments
+    /// * `sources` - Devices the entity consumes
+    pub fn new(sources: impl IntoIterator<Item = InputSource>) -> Self {
+        Self {
+            sources: sources.into_iter().collect(),
+        }
+    }
+
+    /// Adds a device the entity consumes
+    ///
+    /// # Arguments
+    /// * `source` - Device to add
+    #[inline]
+    pub fn with(mut self, source: InputSource) -> Self {
+        self.sources.push(source);
+        self
+    }
+
+    /// Returns the devices the entity consumes
+    #[inline]
+    pub fn sources(&self) -> &[InputSource] {
+        &self.sources
+    }
+
+    /// Checks whether input from a device is consumed
+    ///
+    /// # Arguments
+    /// * `source` - Device the input came from
+    pub fn accepts(&self, source: InputSource) -> bool {
+        self.sources.iter().any(|accepted| {
+            *accepted == source
+                || matches!(
+                    (accepted, source),
+                    (InputSource::AnyGamepad, InputSource::Gamepad(_))
+                )
+        })
+    }
+
+    /// Checks whether input from a device should reach an entity, using its input sources
+    /// if present, then its player, and otherwise accepting every device
+    ///
+    /// # Arguments
+    /// * `sources` - Input sources of the entity
+    /// * `player` - Player the entity belongs to
+    /// * `source` - Device the input came from
+    pub fn routes(
+        sources: Option<&Self>,
+        player: Option<&CameraPlayer>,
+        source: InputSource,
+    ) -> bool {
+        if let Some(sources) = sources {
+            return sources.accepts(source);
+        }
+        let Some(player) = player else {
+            return true;
+        };
+        match source {
+            InputSource::Mouse | InputSource::Touch => player.accepts_mouse(),
+            InputSource::Gamepad(gamepad) => {
+                player.gamepad.is_none() || player.accepts_gamepad(gamepad)
+            }
+            InputSource::AnyGamepad | InputSource::Custom(_) => true,
+        }
+    }
+}
+
+/// Settings for feeding mouse input into camera buffers
+#[derive(Resource, Clone, Debug)]
+pub struct MouseLook {
+    /// Whether mouse input is fed into buffers
+    pub enabled: bool,
+    /// Horizontal and vertical input added per pixel of mouse motion,
+    /// before the controller's own sensitivity
+    pub sensitivity: Vec2,
+    /// Whether moving the mouse up looks down
+    pub invert_y: bool,
+    /// Whether input is only fed while the primary window's cursor is grabbed
+    pub require_cursor_grab: bool,
+    /// Zoom input added per line scrolled, where 0 disables zooming
+    pub zoom_sensitivity: f32,
+}
+
+impl Default for MouseLook {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            sensitivity: Vec2::splat(0.003),
+            invert_y: false,
+            require_cursor_grab: false,
+            zoom_sensitivity: 1.0,
+        }
+    }
+}
+
+impl MouseLook {
+    /// Sets the input added per pixel of mouse motion
+    ///
+    /// # Arguments
+    /// * `sensitivity` - Input per pixel for both axes
+    #[inline]
+    pub fn with_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.sensitivity = Vec2::splat(sensitivity);
+        self
+    }
+
+    /// Sets whether moving the mouse up looks down
+    ///
+    /// # Arguments
+    /// * `invert_y` - Whether vertical motion is inverted
+    #[inline]
+    pub fn with_invert_y(mut self, invert_y: bool) -> Self {
+        self.invert_y = invert_y;
+        self
+    }
+
+    /// Only feeds input while the primary window's cursor is locked or confined
+    #[inline]
+    pub fn with_cursor_grab(mut self) -> Self {
+        self.require_cursor_grab = true;
+        self
+    }
+
+    /// Sets the zoom input added per line scrolled
+    ///
+    /// # Arguments
+    /// * `sensitivity` - Zoom input per line, 0 disables zooming
+    #[inline]
+    pub fn with_zoom_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.zoom_sensitivity = sensitivity;
+        self
+    }
+
+    /// Converts raw mouse motion into buffer input
+    ///
+    /// # Arguments
+    /// * `motion` - Mouse motion in pixels
+    #[inline]
+    pub fn look_input(&self, motion: Vec2) -> Vec2 {
+        let sign = Vec2::new(-1.0, if self.invert_y { 1.0 } else { -1.0 });
+        motion * self.sensitivity * sign
+    }
+}
+
+/// Feeds mouse motion and scrolling into the buffers of 3D controllers and into
+/// standalone 2D delta buffers that are routed the mouse
+///
+/// # Arguments
+/// * `camera_buffers` - Query for controller and flight controller buffers, players and input sources
+/// * `delta_buffers` - Query for standalone delta buffers, players and input sources
+/// * `windows` - Query for the primary window, used for cursor grab gating
+/// * `mouse_look` - Resource with mouse look settings
+/// * `motion` - Mouse motion accumulated this frame
+/// * `scroll` - Mouse scrolling accumulated this frame
+#[allow(clippy::type_complexity)]
+pub(crate) fn feed_mouse_look(
+    mut camera_buffers: Query<
+        (
+            &mut CameraBuffer,
+            Option<&CameraPlayer>,
+            Option<&InputSources>,
+        ),
+        (
+            Or<(With<CameraController>, With<FlightController>)>,
+            Without<EditorNavigation>,
+        ),
+    >,
+    mut delta_buffers: Query<(
+        &mut DeltaBuffer2d,
+        Option<&CameraPlayer>,
+        Option<&InputSources>,
+    )>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mouse_look: Res<MouseLook>,
+    motion: Option<Res<AccumulatedMouseMotion>>,
+    scroll: Option<Res<AccumulatedMouseScroll>>,
+) {
+    if !mouse_look.enabled {
+        return;
+    }
+    if mouse_look.require_cursor_grab
+        && !windows
+            .single()
+            .is_ok_and(|window| window.cursor_options.grab_mode != CursorGrabMode::None)
+    {
+        return;
+    }
+
+    let look = mouse_look.look_input(motion.map_or(Vec2::ZERO, |motion| motion.delta));
+    let zoom = scroll.map_or(0.0, |scroll| match scroll.unit {
+        MouseScrollUnit::Line => scroll.delta.y,
+        MouseScrollUnit::Pixel => scroll.delta.y / 16.0,
+    }) * mouse_look.zoom_sensitivity;
+    if look == Vec2::ZERO && zoom == 0.0 {
+        return;
+    }
+
+    for (mut buffer, player, sources) in camera_buffers.iter_mut() {
+        if InputSources::routes(sources, player, InputSource::Mouse) {
+            buffer.update(look);
+            buffer.update_zoom(zoom);
+        }
+    }
+    for (mut buffer, player, sources) in delta_buffers.iter_mut() {
+        if InputSources::routes(sources, player, InputSource::Mouse) {
+            buffer.update(look);
+        }
+    }
+}
+
+/// Shape applied to stick deflection after the deadzone
+#[derive(Clone, Copy, Debug)]
+pub enum StickResponse {
+    /// Output matches deflection
+    Linear,
+    /// Deflection raised to a power, larger powers give finer control near the center
+    Exponential(f32),
+    /// Custom mapping from deflection in the range 0 to 1 to output in the range 0 to 1
+    Custom(fn(f32) -> f32),
+}
+
+impl StickResponse {
+    /// Maps deflection in the range 0 to 1 through the response curve
+    ///
+    /// # Arguments
+    /// * `deflection` - Stick deflection after the deadzone
+    #[inline]
+    pub fn apply(&self, deflection: f32) -> f32 {
+        match self {
+            Self::Linear => deflection,
+            Self::Exponential(power) => deflection.powf(*power),
+            Self::Custom(curve) => curve(deflection),
+        }
+    }
+}
+
+/// Speed boost applied while the stick is held at the edge, so large turns stay fast
+/// without making small adjustments twitchy
+#[derive(Clone, Copy, Debug)]
+pub struct StickAcceleration {
+    /// Multiplier reached once the boost has fully ramped up
+    pub multiplier: f32,
+    /// Seconds at full deflection until the multiplier is reached
+    pub ramp_time: f32,
+}
+
+/// A component driving a controller's buffer from a gamepad's right stick.
+///
+/// The gamepad is taken from the controller's [`CameraPlayer`] if it has one,
+/// otherwise every connected gamepad drives the camera. Requires [`CameraInputPlugin`].
+#[derive(Component, Clone, Debug)]
+pub struct GamepadLook {
+    /// Radial deadzone as a fraction of full deflection
+    pub deadzone: f32,
+    /// Response curve applied after the deadzone
+    pub response: StickResponse,
+    /// Horizontal and vertical input added per second at full deflection
+    pub sensitivity: Vec2,
+    /// Whether pushing the stick up looks down
+    pub invert_y: bool,
+    /// Optional speed boost while the stick is held at the edge
+    pub acceleration: Option<StickAcceleration>,
+    /// Progress of the acceleration ramp from 0 to 1
+    ramp: f32,
+}
+
+impl Default for GamepadLook {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GamepadLook {
+    /// Creates a new GamepadLook with default settings:
+    /// - Deadzone: 0.15
+    /// - Response: exponential with a power of 2
+    /// - Sensitivity: 3.0 per second on both axes
+    /// - No inversion or acceleration
+    pub fn new() -> Self {
+        Self {
+            deadzone: 0.15,
+            response: StickResponse::Exponential(2.0),
+            sensitivity: Vec2::splat(3.0),
+            invert_y: false,
+            acceleration: None,
+            ramp: 0.0,
+        }
+    }
+
+    /// Sets the radial deadzone
+    ///
+    /// # Arguments
+    /// * `deadzone` - Fraction of full deflection ignored around the center
+    #[inline]
+    pub fn with_deadzone(mut self, deadzone: f32) -> Self {
+        self.deadzone = deadzone;
+        self
+    }
+
+    /// Sets the response curve applied after the deadzone
+    ///
+    /// # Arguments
+    /// * `response` - Response curve
+    #[inline]
+    pub fn with_response(mut self, response: StickResponse) -> Self {
+        self.response = response;
+        self
+    }
+
+    /// Sets separate horizontal and vertical sensitivity
+    ///
+    /// # Arguments
+    /// * `sensitivity` - Input added per second at full deflection for each axis
+    #[inline]
+    pub fn with_sensitivity_xy(mut self, sensitivity: Vec2) -> Self {
+        self.sensitivity = sensitivity;
+        self
+    }
+
+    /// Sets whether pushing the stick up looks down
+    ///
+    /// # Arguments
+    /// * `invert_y` - Whether vertical input is inverted
+    #[inline]
+    pub fn with_invert_y(mut self, invert_y: bool) -> Self {
+        self.invert_y = invert_y;
+        self
+    }
+
+    /// Boosts speed while the stick is held at the edge
+    ///
+    /// # Arguments
+    /// * `multiplier` - Multiplier reached once fully ramped up
+    /// * `ramp_time` - Seconds at full deflection until the multiplier is reached
+    #[inline]
+    pub fn with_acceleration(mut self, multiplier: f32, ramp_time: f32) -> Self {
+        self.acceleration = Some(StickAcceleration {
+            multiplier,
+            ramp_time,
+        });
+        self
+    }
+
+    /// Applies the radial deadzone and response curve to a stick position,
+    /// keeping its direction
+    ///
+    /// # Arguments
+    /// * `stick` - Raw stick position
+    pub fn shape(&self, stick: Vec2) -> Vec2 {
+        let length = stick.length();
+        if length <= self.deadzone || self.deadzone >= 1.0 {
+            return Vec2::ZERO;
+        }
+        // rescale so output starts at 0 on the edge of the deadzone
+        let deflection = ((length - self.deadzone) / (1.0 - self.deadzone)).min(1.0);
+        stick / length * self.response.apply(deflection)
+    }
+
+    /// Converts a raw stick position into buffer input for this frame,
+    /// advancing the acceleration ramp
+    ///
+    /// # Arguments
+    /// * `stick` - Raw stick position
+    /// * `dt` - Time elapsed since last update in seconds
+    pub fn look_input(&mut self, stick: Vec2, dt: f32) -> Vec2 {
+        let shaped = self.shape(stick);
+
+        let mut boost = 1.0;
+        if let Some(acceleration) = self.acceleration {
+            // ramp up while held near the edge, reset otherwise
+            self.ramp = if shaped.length() >= 0.95 {
+                (self.ramp + dt / acceleration.ramp_time.max(f32::EPSILON)).min(1.0)
+            } else {
+                0.0
+            };
+            boost += (acceleration.multiplier - 1.0) * self.ramp;
+        }
+
+        let sign = Vec2::new(-1.0, if self.invert_y { -1.0 } else { 1.0 });
+        shaped * self.sensitivity * sign * boost * dt
+    }
+}
+
+/// Feeds the right stick of each controller's gamepad into its buffer
+///
+/// # Arguments
+/// * `camera_controllers` - Query for controller buffers, gamepad settings and input routing
+/// * `gamepads` - Query for connected gamepads
+/// * `time` - Clocks providing frame timing for each controller's time source
+#[allow(clippy::type_complexity)]
+pub(crate) fn feed_gamepad_look(
+    mut camera_controllers: Query<(
+        &mut CameraBuffer,
+        &mut GamepadLook,
+        Option<&CameraController>,
+        Option<&CameraController2d>,
+        Option<&CameraPlayer>,
+        Option<&InputSources>,
+    )>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    time: CameraTime,
+) {
+    for (mut buffer, mut look, controller, controller2d, player, sources) in
+        camera_controllers.iter_mut()
+    {
+        let dt = time.delta_secs(CameraTimeSource::of(controller, controller2d));
+        // use the strongest stick of the gamepads routed to the controller
+        let stick = gamepads
+            .iter()
+            .filter(|(entity, _)| {
+                InputSources::routes(sources, player, InputSource::Gamepad(*entity))
+            })
+            .map(|(_, gamepad)| gamepad.right_stick())
+            .max_by(|a, b| a.length_squared().total_cmp(&b.length_squared()))
+            .unwrap_or(Vec2::ZERO);
+
+        let input = look.look_input(stick, dt);
+        if input != Vec2::ZERO {
+            buffer.update(input);
+        }
+    }
+}
+
+/// A component panning a controller with the [`Plane`](CameraAnchor::Plane) anchor while the
+/// cursor rests near the edge of the focused window, for strategy style edge scrolling.
+///
+/// Pan input is fed into [`EdgeScroll::CHANNEL`] of the controller's buffer, so it goes
+/// through the controller's pan sensitivity and smoothing. Requires [`CameraInputPlugin`].
+#[derive(Component, Clone, Debug)]
+pub struct EdgeScroll {
+    /// Distance in logical pixels from the window edge that starts scrolling
+    pub margin: f32,
+    /// Pan input added per second while scrolling
+    pub speed: f32,
+    /// Seconds the cursor rests at the edge before scrolling starts
+    pub delay: f32,
+    /// Seconds the cursor has rested at the edge
+    held: f32,
+}
+
+impl Default for EdgeScroll {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EdgeScroll {
+    /// Name of the buffer channel edge scrolling is fed into, so it can be scaled
+    /// or disabled separately from other input
+    pub const CHANNEL: &'static str = "edge_scroll";
+
+    /// Creates a new EdgeScroll with default settings:
+    /// - Margin: 10 pixels
+    /// - Speed: 10.0 per second
+    /// - Delay: 0.1 seconds
+    pub fn new() -> Self {
+        Self {
+            margin: 10.0,
+            speed: 10.0,
+            delay: 0.1,
+            held: 0.0,
+        }
+    }
+
+    /// Sets the distance from the window edge that starts scrolling
+    ///
+    /// # Arguments
+    /// * `margin` - Distance in logical pixels
+    #[inline]
+    pub fn with_margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Sets the pan input added per second while scrolling
+    ///
+    /// # Arguments
+    /// * `speed` - Pan input per second
+    #[inline]
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Sets the time the cursor rests at the edge before scrolling starts, so crossing the
+    /// edge on the way to another window does not move the camera
+    ///
+    /// # Arguments
+    /// * `delay` - Delay in seconds
+    #[inline]
+    pub fn with_delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Returns the direction to scroll for a cursor position, with x to the right and y up,
+    /// or zero away from the edges
+    ///
+    /// # Arguments
+    /// * `cursor` - Logical cursor position, with y pointing down
+    /// * `size` - Logical size of the window
+    pub fn direction(&self, cursor: Vec2, size: Vec2) -> Vec2 {
+        let near = |position: f32, extent: f32| {
+            (position > extent - self.margin) as i32 as f32 - (position < self.margin) as i32 as f32
+        };
+        Vec2::new(near(cursor.x, size.x), -near(cursor.y, size.y)).normalize_or_zero()
+    }
+
+    /// Converts a scroll direction into buffer input for this frame, advancing the delay
+    ///
+    /// # Arguments
+    /// * `direction` - Direction to scroll, or zero away from the edges
+    /// * `dt` - Time elapsed since last update in seconds
+    pub fn pan_input(&mut self, direction: Vec2, dt: f32) -> Vec2 {
+        if direction == Vec2::ZERO {
+            self.held = 0.0;
+            return Vec2::ZERO;
+        }
+        self.held += dt;
+        if self.held < self.delay {
+            return Vec2::ZERO;
+        }
+        direction * self.speed * dt
+    }
+}
+
+/// Feeds edge scrolling into the buffers of controllers with the plane anchor that are
+/// routed the keyboard and mouse
+///
+/// # Arguments
+/// * `camera_controllers` - Query for controller buffers, edge scroll settings and input routing
+/// * `windows` - Query for the primary window, used for the cursor position and focus
+/// * `time` - Clocks providing frame timing for each controller's time source
+#[allow(clippy::type_complexity)]
+pub(crate) fn feed_edge_scroll(
+    mut camera_controllers: Query<(
+        &CameraController,
+        &mut CameraBuffer,
+        &mut EdgeScroll,
+        Option<&CameraPlayer>,
+        Option<&InputSources>,
+    )>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    time: CameraTime,
+) {
+    let window = windows.single().ok().filter(|window| window.focused);
+    let edge = window.and_then(|window| Some((window.cursor_position()?, window.size())));
+
+    for (controller, mut buffer, mut scroll, player, sources) in camera_controllers.iter_mut() {
+        let dt = time.delta_secs(controller.time_source);
+        let direction = match edge {
+            Some((cursor, size))
+                if matches!(controller.anchor, CameraAnchor::Plane { .. })
+                    && InputSources::routes(sources, player, InputSource::Mouse) =>
+            {
+                scroll.direction(cursor, size)
+            }
+            _ => Vec2::ZERO,
+        };
+        let input = scroll.pan_input(direction, dt);
+        if input != Vec2::ZERO {
+            buffer.update_channel(EdgeScroll::CHANNEL, input);
+        }
+    }
+}
+
+/// Settings for converting touch gestures into camera buffer input.
+///
+/// One finger orbits like mouse look, two fingers dragging together pan, and two fingers
+/// moving apart or together zoom. A two finger gesture is classified as a pan or a pinch
+/// once either motion passes the disambiguation threshold, and stays that way until
+/// the number of fingers changes.
+#[derive(Resource, Clone, Debug)]
+pub struct TouchGestures {
+    /// Whether touch input is fed into buffers
+    pub enabled: bool,
+    /// Look input added per pixel of one finger drag
+    pub orbit_sensitivity: f32,
+    /// Pan input added per pixel of two finger drag, fed into
+    /// [`CameraBuffer::update_pan`] so it translates instead of turning the camera
+    pub pan_sensitivity: f32,
+    /// Zoom input added per pixel the fingers move apart
+    pub pinch_sensitivity: f32,
+    /// Pixels of movement needed before a two finger gesture is classified
+    pub threshold: f32,
+}
+
+impl Default for TouchGestures {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            orbit_sensitivity: 0.005,
+            pan_sensitivity: 0.005,
+            pinch_sensitivity: 0.02,
+            threshold: 10.0,
+        }
+    }
+}
+
+impl TouchGestures {
+    /// Sets the look input added per pixel of one finger drag
+    ///
+    /// # Arguments
+    /// * `sensitivity` - Look input per pixel
+    #[inline]
+    pub fn with_orbit_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.orbit_sensitivity = sensitivity;
+        self
+    }
+
+    /// Sets the pan input added per pixel of two finger drag
+    ///
+    /// # Arguments
+    /// * `sensitivity` - Pan input per pixel
+    #[inline]
+    pub fn with_pan_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.pan_sensitivity = sensitivity;
+        self
+    }
+
+    /// Sets the zoom input added per pixel the fingers move apart
+    ///
+    /// # Arguments
+    /// * `sensitivity` - Zoom input per pixel
+    #[inline]
+    pub fn with_pinch_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.pinch_sensitivity = sensitivity;
+        self
+    }
+}
+
+/// Kind of two finger gesture once classified
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TwoFingerGesture {
+    Pan,
+    Pinch,
+}
+
+/// Touch tracking between frames
+#[derive(Default)]
+pub(crate) struct TouchState {
+    /// Positions of the touches during the last update
+    positions: HashMap<u64, Vec2>,
+    /// Number of touches during the last update
+    fingers: usize,
+    /// Classified two finger gesture
+    gesture: Option<TwoFingerGesture>,
+    /// Pan and pinch movement accumulated before classification
+    pending: (Vec2, f32),
+    /// Whether input is ignored until all fingers lift, after a multi finger gesture
+    suppressed: bool,
+}
+
+/// Feeds touch gestures into the buffers of 3D controllers that are routed touch input
+///
+/// # Arguments
+/// * `camera_buffers` - Query for controller buffers, players and input sources
+/// * `gestures` - Resource with touch gesture settings
+/// * `touches` - Touch input, if the input plugin is present
+/// * `state` - Touch tracking between frames
+#[allow(clippy::type_complexity)]
+pub(crate) fn feed_touch_gestures(
+    mut camera_buffers: Query<
+        (
+            &mut CameraBuffer,
+            Option<&CameraPlayer>,
+            Option<&InputSources>,
+        ),
+        With<CameraController>,
+    >,
+    gestures: Res<TouchGestures>,
+    touches: Option<Res<Touches>>,
+    mut state: Local<TouchState>,
+) {
+    let Some(touches) = touches.filter(|_| gestures.enabled) else {
+        return;
+    };
+
+    // movement of each touch since the last update, from positions tracked here
+    let active: Vec<(Vec2, Vec2)> = touches
+        .iter()
+        .map(|touch| {
+            let previous = state
+                .positions
+                .get(&touch.id())
+                .copied()
+                .unwrap_or(touch.position());
+            (previous, touch.position())
+        })
+        .collect();
+    state.positions = touches
+        .iter()
+        .map(|touch| (touch.id(), touch.position()))
+        .collect();
+
+    // a changing number of fingers starts a new gesture
+    let fingers = active.len();
+    if fingers != state.fingers {
+        state.suppressed = fingers != 0 && (state.suppressed || fingers < state.fingers);
+        state.fingers = fingers;
+        state.gesture = None;
+        state.pending = (Vec2::ZERO, 0.0);
+        return;
+    }
+
+    let (mut look, mut pan, mut zoom) = (Vec2::ZERO, Vec2::ZERO, 0.0);
+    match active.as_slice() {
+        [(previous, current)] if !state.suppressed => {
+            look = -(*current - *previous) * gestures.orbit_sensitivity;
+        }
+        [(previous_a, current_a), (previous_b, current_b)] => {
+            let drag = ((*current_a - *previous_a) + (*current_b - *previous_b)) * 0.5;
+            let spread = current_a.distance(*current_b) - previous_a.distance(*previous_b);
+
+            if state.gesture.is_none() {
+                state.pending.0 += drag;
+                state.pending.1 += spread;
+                let (drag, spread) = state.pending;
+                if spread.abs() >= gestures.threshold && spread.abs() >= drag.length() {
+                    state.gesture = Some(TwoFingerGesture::Pinch);
+                } else if drag.length() >= gestures.threshold {
+                    state.gesture = Some(TwoFingerGesture::Pan);
+                }
+            }
+            match state.gesture {
+                // screen y points down, so flip it to drag the view along with the fingers
+                Some(TwoFingerGesture::Pan) => {
+                    pan = Vec2::new(-drag.x, drag.y) * gestures.pan_sensitivity;
+                }
+                Some(TwoFingerGesture::Pinch) => zoom = spread * gestures.pinch_sensitivity,
+                None => (),
+            }
+        }
+        _ => (),
+    }
+    if look == Vec2::ZERO && pan == Vec2::ZERO && zoom == 0.0 {
+        return;
+    }
+
+    for (mut buffer, player, sources) in camera_buffers.iter_mut() {
+        if InputSources::routes(sources, player, InputSource::Touch) {
+            buffer.update(look);
+            buffer.update_pan(pan);
+            buffer.update_zoom(zoom);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn response_curves_keep_the_ends_fixed() {
+        for response in [
+            StickResponse::Linear,
+            StickResponse::Exponential(2.0),
+            StickResponse::Custom(|deflection| deflection * deflection * deflection),
+        ] {
+            assert_eq!(response.apply(0.0), 0.0, "{response:?}");
+            assert_eq!(response.apply(1.0), 1.0, "{response:?}");
+        }
+        assert_eq!(StickResponse::Linear.apply(0.5), 0.5);
+        assert_eq!(StickResponse::Exponential(2.0).apply(0.5), 0.25);
+    }
+
+    #[test]
+    fn deadzone_ignores_small_deflection() {
+        let look = GamepadLook::new().with_deadzone(0.2);
+        assert_eq!(look.shape(Vec2::ZERO), Vec2::ZERO);
+        assert_eq!(look.shape(Vec2::new(0.1, 0.1)), Vec2::ZERO);
+        assert!(look.shape(Vec2::new(0.3, 0.0)).x > 0.0);
+    }
+
+    #[test]
+    fn shaping_rescales_past_the_deadzone_and_keeps_direction() {
+        let look = GamepadLook::new()
+            .with_deadzone(0.2)
+            .with_response(StickResponse::Linear);
+
+        let full = look.shape(Vec2::new(0.6, 0.8));
+        assert!((full.length() - 1.0).abs() < 1e-6);
+        assert!((full.normalize() - Vec2::new(0.6, 0.8)).length() < 1e-6);
+
+        // halfway between the deadzone and the edge
+        let half = look.shape(Vec2::new(0.0, -0.6));
+        assert!((half - Vec2::new(0.0, -0.5)).length() < 1e-6);
+
+        // readings past the edge are capped
+        assert!((look.shape(Vec2::new(1.2, 0.0)).length() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn exponential_response_softens_partial_deflection() {
+        let linear = GamepadLook::new()
+            .with_deadzone(0.0)
+            .with_response(StickResponse::Linear);
+        let exponential = linear
+            .clone()
+            .with_response(StickResponse::Exponential(2.0));
+        let stick = Vec2::new(0.5, 0.0);
+        assert!(exponential.shape(stick).x < linear.shape(stick).x);
+    }
+
+    #[test]
+    fn look_input_scales_by_sensitivity_and_inverts_yaw() {
+        let mut look = GamepadLook::new()
+            .with_deadzone(0.0)
+            .with_response(StickResponse::Linear)
+            .with_sensitivity_xy(Vec2::new(2.0, 4.0))
+            .with_invert_y(true);
+        let input = look.look_input(Vec2::new(1.0, 0.0), 0.5);
+        assert!((input - Vec2::new(-1.0, 0.0)).length() < 1e-6);
+        let input = look.look_input(Vec2::new(0.0, 1.0), 0.5);
+        assert!((input - Vec2::new(0.0, -2.0)).length() < 1e-6);
+    }
+
+    #[test]
+    fn acceleration_ramps_up_at_the_edge_and_resets() {
+        let mut look = GamepadLook::new()
+            .with_deadzone(0.0)
+            .with_response(StickResponse::Linear)
+            .with_sensitivity_xy(Vec2::ONE)
+            .with_acceleration(3.0, 1.0);
+        let edge = Vec2::new(0.0, 1.0);
+
+        let first = look.look_input(edge, 0.5).y;
+        let ramped = look.look_input(edge, 0.5).y;
+        assert!((ramped - 1.5).abs() < 1e-6);
+        assert!(first < ramped);
+
+        // letting go of the edge resets the ramp
+        look.look_input(Vec2::new(0.0, 0.5), 0.5);
+        assert!(look.look_input(edge, 0.5).y < ramped);
+    }
+
+    #[test]
+    fn edge_scroll_direction_follows_the_nearest_edges() {
+        let scroll = EdgeScroll::new();
+        let size = Vec2::new(800.0, 600.0);
+
+        assert_eq!(scroll.direction(Vec2::new(400.0, 300.0), size), Vec2::ZERO);
+        assert_eq!(scroll.direction(Vec2::new(5.0, 300.0), size), Vec2::NEG_X);
+        assert_eq!(scroll.direction(Vec2::new(795.0, 300.0), size), Vec2::X);
+        // window coordinates point down, scrolling points up
+        assert_eq!(scroll.direction(Vec2::new(400.0, 5.0), size), Vec2::Y);
+        assert_eq!(scroll.direction(Vec2::new(400.0, 595.0), size), Vec2::NEG_Y);
+
+        let corner = scroll.direction(Vec2::new(795.0, 5.0), size);
+        assert!((corner - Vec2::new(1.0, 1.0).normalize()).length() < 1e-6);
+    }
+
+    #[test]
+    fn edge_scroll_waits_for_the_delay() {
+        let mut scroll = EdgeScroll::new().with_speed(10.0).with_delay(0.1);
+
+        assert_eq!(scroll.pan_input(Vec2::X, 0.05), Vec2::ZERO);
+        assert_eq!(scroll.pan_input(Vec2::X, 0.05), Vec2::X * 0.5);
+        // leaving the edge restarts the delay
+        assert_eq!(scroll.pan_input(Vec2::ZERO, 0.05), Vec2::ZERO);
+        assert_eq!(scroll.pan_input(Vec2::X, 0.05), Vec2::ZERO);
+    }
+}
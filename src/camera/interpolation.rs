@@ -0,0 +1,40 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use super::{CameraController, CameraView};
+
+/// Adds transform interpolation to the rigid bodies followed by controllers that ask for it,
+/// unless the body already has interpolation or opted out of easing
+///
+/// # Arguments
+/// * `commands` - Commands for inserting interpolation components
+/// * `camera_controllers` - Query for controllers and their entities
+/// * `bodies` - Query for rigid bodies without interpolation
+#[allow(clippy::type_complexity)]
+pub(crate) fn interpolate_followed_bodies(
+    mut commands: Commands,
+    camera_controllers: Query<(Entity, &CameraController)>,
+    bodies: Query<
+        (),
+        (
+            With<RigidBody>,
+            Without<TransformInterpolation>,
+            Without<NoTransformEasing>,
+        ),
+    >,
+) {
+    for (entity, controller) in camera_controllers.iter() {
+        if !controller.interpolate_bodies {
+            continue;
+        }
+        let target = match controller.view {
+            CameraView::Target(target) => Some(target),
+            CameraView::Free => None,
+        };
+        for body in core::iter::once(entity).chain(target) {
+            if bodies.contains(body) {
+                commands.entity(body).try_insert(TransformInterpolation);
+            }
+        }
+    }
+}
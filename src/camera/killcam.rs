@@ -0,0 +1,227 @@
+use bevy::prelude::*;
+
+use super::{
+    CameraAnchor, CameraBuffer, CameraController, CameraTimeSource, CameraView, WorldUnits,
+    target::{self, TargetTransform},
+    time::CameraTime,
+};
+
+/// Lifecycle events emitted by a [`Killcam`]
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KillcamEvent {
+    /// The killcam started following its projectile
+    Started(Entity),
+    /// The projectile ended its flight and the hold shot began
+    Impact(Entity),
+    /// The hold shot finished and the killcam is done
+    Finished(Entity),
+}
+
+/// Phase of a [`Killcam`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KillcamPhase {
+    /// Following the projectile in flight
+    Flight,
+    /// Holding the shot at the end of flight, with the remaining hold time
+    Hold(f32),
+    /// The killcam is done
+    Finished,
+}
+
+/// A preset for following fast projectiles with an orbit camera.
+/// The killcam entity moves with its projectile, keeps the camera behind the
+/// direction of travel with tight smoothing, and scales the orbit distance with speed.
+/// When the projectile despawns or [`Killcam::end_flight`] is called, the shot is held
+/// in place before finishing.
+#[derive(Component, Clone)]
+#[require(Transform)]
+pub struct Killcam {
+    /// Entity of the projectile being followed
+    pub projectile: Entity,
+    /// Orbit distance when the projectile is stationary
+    pub base_distance: f32,
+    /// Extra orbit distance per unit of projectile speed
+    pub distance_per_speed: f32,
+    /// Maximum orbit distance
+    pub max_distance: f32,
+    /// Rate at which the camera turns to follow the direction of travel
+    pub rotation_rate: f32,
+    /// Time in seconds to hold the shot after the flight ends
+    pub hold: f32,
+    /// Whether the hold is timed with real time, so slow motion doesn't lengthen it
+    pub unscaled_time: bool,
+    /// Current phase of the killcam
+    phase: KillcamPhase,
+    /// Projectile position from the previous frame
+    previous: Option<Vec3>,
+}
+
+impl Killcam {
+    /// Creates a new Killcam with default settings:
+    /// - Base distance: 2.0
+    /// - Distance per speed: 0.05, up to 6.0
+    /// - Tight rotation following
+    /// - Hold for 1.5 seconds in real time
+    ///
+    /// # Arguments
+    /// * `projectile` - Entity of the projectile to follow
+    pub fn new(projectile: Entity) -> Self {
+        Self {
+            projectile,
+            base_distance: 2.0,
+            distance_per_speed: 0.05,
+            max_distance: 6.0,
+            rotation_rate: 20.0,
+            hold: 1.5,
+            unscaled_time: true,
+            phase: KillcamPhase::Flight,
+            previous: None,
+        }
+    }
+
+    /// Creates the killcam together with an orbit controller tuned for fast projectiles
+    ///
+    /// # Arguments
+    /// * `camera` - Entity ID of the camera to control
+    /// * `projectile` - Entity of the projectile to follow
+    pub fn bundle(camera: Entity, projectile: Entity) -> (Self, CameraController) {
+        let killcam = Self::new(projectile);
+        let controller = CameraController::new(
+            camera,
+            CameraAnchor::Orbit {
+                distance: killcam.base_distance,
+            },
+            CameraView::Free,
+        )
+        .with_translation_smoothing(0.05);
+        (killcam, controller)
+    }
+
+    /// Sets the orbit distance scaling with projectile speed
+    ///
+    /// # Arguments
+    /// * `base_distance` - Orbit distance when stationary
+    /// * `distance_per_speed` - Extra distance per unit of speed
+    /// * `max_distance` - Maximum orbit distance
+    #[inline]
+    pub fn with_distance(
+        mut self,
+        base_distance: f32,
+        distance_per_speed: f32,
+        max_distance: f32,
+    ) -> Self {
+        self.base_distance = base_distance;
+        self.distance_per_speed = distance_per_speed;
+        self.max_distance = max_distance;
+        self
+    }
+
+    /// Sets the time to hold the shot after the flight ends
+    ///
+    /// # Arguments
+    /// * `hold` - Hold time in seconds
+    /// * `unscaled_time` - Whether the hold is timed with real time
+    #[inline]
+    pub fn with_hold(mut self, hold: f32, unscaled_time: bool) -> Self {
+        self.hold = hold;
+        self.unscaled_time = unscaled_time;
+        self
+    }
+
+    /// Returns the current phase of the killcam
+    #[inline]
+    pub fn phase(&self) -> KillcamPhase {
+        self.phase
+    }
+
+    /// Ends the flight and starts the hold shot, for projectiles that are not despawned on impact
+    pub fn end_flight(&mut self) {
+        if self.phase == KillcamPhase::Flight {
+            self.phase = KillcamPhase::Hold(self.hold);
+        }
+    }
+}
+
+/// Moves killcams with their projectiles and advances their lifecycle
+///
+/// # Arguments
+/// * `killcams` - Query for killcams and their controllers
+/// * `projectile_transforms` - Query for projectile transforms
+/// * `events` - Event writer for killcam lifecycle events
+/// * `units` - Resource describing the scale of the world
+/// * `time` - Clocks providing frame timing for each controller's time source
+pub(crate) fn update_killcams(
+    mut killcams: Query<(
+        Entity,
+        &mut Killcam,
+        &mut CameraController,
+        &mut CameraBuffer,
+        &mut Transform,
+    )>,
+    projectile_transforms: Query<TargetTransform, (Without<Killcam>, Without<Camera>)>,
+    mut events: EventWriter<KillcamEvent>,
+    units: Res<WorldUnits>,
+    time: CameraTime,
+) {
+    for (entity, mut killcam, mut controller, mut buffer, mut transform) in killcams.iter_mut() {
+        let dt = time.delta_secs(controller.time_source);
+        match killcam.phase {
+            KillcamPhase::Flight => {
+                let Ok(projectile_transform) =
+                    target::get_transform(&projectile_transforms, killcam.projectile)
+                else {
+                    killcam.end_flight();
+                    events.write(KillcamEvent::Impact(entity));
+                    continue;
+                };
+                let translation = projectile_transform.translation;
+
+                let Some(previous) = killcam.previous else {
+                    // snap to the projectile on the first frame
+                    transform.translation = translation;
+                    killcam.previous = Some(translation);
+                    events.write(KillcamEvent::Started(entity));
+                    continue;
+                };
+
+                let velocity = if dt > 0.0 {
+                    (translation - previous) / dt
+                } else {
+                    Vec3::ZERO
+                };
+                transform.translation = translation;
+                killcam.previous = Some(translation);
+
+                // keep the camera behind the direction of travel
+                if let Ok(direction) = Dir3::new(velocity) {
+                    let goal = Transform::IDENTITY
+                        .looking_to(direction, controller.yaw_axis)
+                        .rotation;
+                    let factor = 1.0 - (-killcam.rotation_rate * dt).exp();
+                    buffer.rotation = buffer.rotation.slerp(goal, factor);
+                }
+
+                // distances are in meters, so measure speed in meters too
+                let speed = units.to_meters(velocity.length());
+                let distance = (killcam.base_distance + speed * killcam.distance_per_speed)
+                    .min(killcam.max_distance);
+                controller.anchor = CameraAnchor::Orbit { distance };
+            }
+            KillcamPhase::Hold(remaining) => {
+                let dt = if killcam.unscaled_time {
+                    time.delta_secs(CameraTimeSource::Real)
+                } else {
+                    dt
+                };
+                let remaining = remaining - dt;
+                if remaining <= 0.0 {
+                    killcam.phase = KillcamPhase::Finished;
+                    events.write(KillcamEvent::Finished(entity));
+                } else {
+                    killcam.phase = KillcamPhase::Hold(remaining);
+                }
+            }
+            KillcamPhase::Finished => (),
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use bevy::prelude::*;
+
+use super::{
+    CameraAnchor, CameraBuffer, CameraController, CameraView, WorldUnits,
+    target::{self, SkippedEntities, TargetTransform},
+    time::CameraTime,
+};
+
+/// Marker for entities a [`LockOn`] can acquire
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct LockOnTarget;
+
+/// A pending lock on change, resolved during the next update
+#[derive(Clone, Copy, Debug)]
+enum LockOnRequest {
+    /// Lock onto the nearest target in range
+    Acquire,
+    /// Switch to the next target counterclockwise for positive or clockwise for negative values
+    Cycle(f32),
+    /// Drop the lock
+    Release,
+}
+
+/// A component that locks a controller onto a [`LockOnTarget`], in the style of action games.
+///
+/// While locked, the controller's view is held free and its yaw is turned so the camera looks
+/// past the controller entity toward the target, keeping both in frame. Orbit distances grow
+/// with the separation between them. The view and distance are restored when the lock drops,
+/// which also happens when the target is despawned or leaves the lock range.
+#[derive(Component, Clone)]
+pub struct LockOn {
+    /// Maximum distance from the controller entity to acquire targets, in meters
+    pub range: f32,
+    /// Multiple of the range at which an existing lock is dropped
+    pub break_range: f32,
+    /// Rate at which yaw turns toward the target with smooth interpolation
+    pub turn_rate: f32,
+    /// Orbit distance added per meter of separation between the controller entity and target
+    pub framing: f32,
+    /// Locked target
+    target: Option<Entity>,
+    /// Lock change waiting for the next update
+    request: Option<LockOnRequest>,
+    /// View and orbit distance held before the lock, restored when it drops
+    saved: Option<(CameraView, Option<f32>)>,
+}
+
+impl Default for LockOn {
+    fn default() -> Self {
+        Self::new(20.0)
+    }
+}
+
+impl LockOn {
+    /// Creates a new LockOn with default settings:
+    /// - Locks drop at 1.5 times the range
+    /// - Turn smoothing: 0.15
+    /// - Framing: 0.5 meters of distance per meter of separation
+    ///
+    /// # Arguments
+    /// * `range` - Maximum distance to acquire targets, in meters
+    pub fn new(range: f32) -> Self {
+        Self {
+            range,
+            break_range: 1.5,
+            turn_rate: 1.0 / 0.15,
+            framing: 0.5,
+            target: None,
+            request: None,
+            saved: None,
+        }
+    }
+
+    /// Sets smoothing factor for turning toward the target.
+    /// Larger values give smoother movement.
+    ///
+    /// # Arguments
+    /// * `smoothing` - Smoothing factor for turning
+    #[inline]
+    pub fn with_turn_smoothing(mut self, smoothing: f32) -> Self {
+        self.turn_rate = 1.0 / smoothing;
+        self
+    }
+
+    /// Sets how far orbiting cameras move out as the target gets further away
+    ///
+    /// # Arguments
+    /// * `framing` - Orbit distance added per meter of separation
+    #[inline]
+    pub fn with_framing(mut self, framing: f32) -> Self {
+        self.framing = framing;
+        self
+    }
+
+    /// Returns the locked target
+    #[inline]
+    pub fn target(&self) -> Option<Entity> {
+        self.target
+    }
+
+    /// Locks onto the nearest target in range during the next update
+    #[inline]
+    pub fn acquire(&mut self) {
+        self.request = Some(LockOnRequest::Acquire);
+    }
+
+    /// Switches to the next target to the left of the current one, as seen from the camera
+    #[inline]
+    pub fn cycle_left(&mut self) {
+        self.request = Some(LockOnRequest::Cycle(1.0));
+    }
+
+    /// Switches to the next target to the right of the current one, as seen from the camera
+    #[inline]
+    pub fn cycle_right(&mut self) {
+        self.request = Some(LockOnRequest::Cycle(-1.0));
+    }
+
+    /// Drops the lock during the next update
+    #[inline]
+    pub fn release(&mut self) {
+        self.request = Some(LockOnRequest::Release);
+    }
+}
+
+/// Resolves lock on requests and turns locked controllers toward their targets
+///
+/// # Arguments
+/// * `camera_controllers` - Query for controllers, buffers and lock ons
+/// * `targets` - Query for entities that can be locked onto
+/// * `target_transforms` - Query for controller and target transforms
+/// * `units` - Resource describing the scale of the world
+/// * `time` - Clocks providing frame timing for each controller's time source
+/// * `skipped` - Controllers skipped for missing entities, warned about once
+pub(crate) fn update_lock_on(
+    mut camera_controllers: Query<(
+        Entity,
+        &mut CameraController,
+        &mut CameraBuffer,
+        &mut LockOn,
+    )>,
+    targets: Query<Entity, With<LockOnTarget>>,
+    target_transforms: Query<TargetTransform, Without<Camera>>,
+    units: Res<WorldUnits>,
+    time: CameraTime,
+    mut skipped: Local<SkippedEntities>,
+) {
+    for (entity, mut controller, mut buffer, mut lock_on) in camera_controllers.iter_mut() {
+        let dt = time.delta_secs(controller.time_source);
+        let Some(controller_transform) =
+            skipped.check(entity, target::get_transform(&target_transforms, entity))
+        else {
+            continue;
+        };
+        let origin = controller_transform.translation;
+        let range = units.to_world(lock_on.range);
+        // candidates in range with their translations
+        let candidates = || {
+            targets.iter().filter_map(|candidate| {
+                let translation = target::get_transform(&target_transforms, candidate)
+                    .ok()?
+                    .translation;
+                let distance = translation.distance(origin);
+                (candidate != entity && distance <= range).then_some((candidate, translation))
+            })
+        };
+
+        match lock_on.request.take() {
+            Some(LockOnRequest::Acquire) => {
+                lock_on.target = candidates()
+                    .min_by(|(_, a), (_, b)| {
+                        a.distance_squared(origin)
+                            .total_cmp(&b.distance_squared(origin))
+                    })
+                    .map(|(candidate, _)| candidate);
+            }
+            Some(LockOnRequest::Cycle(direction)) => {
+                let yaw = |translation: Vec3| {
+                    controller.yaw_to_heading(buffer.rotation, translation - origin)
+                };
+                let current = lock_on
+                    .target
+                    .and_then(|target| target::get_transform(&target_transforms, target).ok())
+                    .and_then(|transform| yaw(transform.translation))
+                    .unwrap_or(0.0);
+                // the nearest target turning in the requested direction from the current one
+                let next = candidates()
+                    .filter(|(candidate, _)| Some(*candidate) != lock_on.target)
+                    .filter_map(|(candidate, translation)| {
+                        let turn = (yaw(translation)? - current) * direction;
+                        (turn > 0.0).then_some((candidate, turn))
+                    })
+                    .min_by(|(_, a), (_, b)| a.total_cmp(b));
+                if let Some((candidate, _)) = next {
+                    lock_on.target = Some(candidate);
+                }
+            }
+            Some(LockOnRequest::Release) => lock_on.target = None,
+            None => (),
+        }
+
+        // drop locks on despawned or distant targets
+        let locked = lock_on.target.and_then(|target| {
+            let translation = target::get_transform(&target_transforms, target)
+                .ok()?
+                .translation;
+            (translation.distance(origin) <= range * lock_on.break_range).then_some(translation)
+        });
+        let Some(target_translation) = locked else {
+            lock_on.target = None;
+            if let Some((view, distance)) = lock_on.saved.take() {
+                controller.view = view;
+                if let (CameraAnchor::Orbit { distance: current }, Some(distance)) =
+                    (&mut controller.anchor, distance)
+                {
+                    *current = distance;
+                }
+            }
+            continue;
+        };
+
+        // hold the view free while locked, remembering what to restore
+        if lock_on.saved.is_none() {
+            let distance = match controller.anchor {
+                CameraAnchor::Orbit { distance } => Some(distance),
+                _ => None,
+            };
+            lock_on.saved = Some((controller.view.clone(), distance));
+        }
+        controller.view = CameraView::Free;
+
+        // turn to look past the controller entity toward the target
+        let heading = target_translation - origin;
+        if let Some(yaw) = controller.yaw_to_heading(buffer.rotation, heading) {
+            let factor = if lock_on.turn_rate.is_finite() {
+                1.0 - (-lock_on.turn_rate * dt).exp()
+            } else {
+                1.0
+            };
+            buffer.rotation = Quat::from_axis_angle(controller.yaw_axis.as_vec3(), yaw * factor)
+                * buffer.rotation;
+        }
+
+        // move out as the target gets further away so both stay in frame
+        let separation = units.to_meters(heading.length());
+        if let (Some((_, Some(base))), CameraAnchor::Orbit { distance }) =
+            (&lock_on.saved, &mut controller.anchor)
+        {
+            *distance = base + separation * lock_on.framing;
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use bevy::{prelude::*, render::camera::ScalingMode};
+
+use super::{
+    WorldUnits,
+    target::{self, SkippedEntities, TargetTransform},
+};
+
+/// A minimap controller added directly to a secondary camera entity, looking straight down
+/// on a target from a fixed height through an orthographic projection.
+///
+/// The camera stays centered on the target, or only follows once the target leaves a dead
+/// zone around the center. The map points north along the world's negative Z axis, or turns
+/// with the target so its facing is always up. Render it to a texture by setting the camera's
+/// `target` to a `RenderTarget::Image`, or into a corner of the window with a `viewport` and
+/// a higher `order` than the main camera.
+#[derive(Component, Clone, Debug)]
+pub struct MinimapCameraController {
+    /// Entity the minimap is centered on
+    pub target: Entity,
+    /// Height in meters above the target the camera is placed at
+    pub height: f32,
+    /// Visible height of the map in meters at each zoom level, from nearest to furthest
+    pub zoom_levels: Vec<f32>,
+    /// Radius in meters the target may move from the center before the camera follows
+    pub dead_zone: f32,
+    /// Whether the map turns with the target so its facing is up
+    pub rotate_with_target: bool,
+    /// Index of the current zoom level
+    zoom_level: usize,
+}
+
+impl MinimapCameraController {
+    /// Creates a new MinimapCameraController with default settings:
+    /// - Height: 100.0 meters above the target
+    /// - Zoom levels: 25, 50 and 100 meters visible, starting at 50
+    /// - Dead zone: none, the target stays centered
+    /// - North up
+    ///
+    /// # Arguments
+    /// * `target` - Entity the minimap is centered on
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            height: 100.0,
+            zoom_levels: vec![25.0, 50.0, 100.0],
+            dead_zone: 0.0,
+            rotate_with_target: false,
+            zoom_level: 1,
+        }
+    }
+
+    /// Sets the height above the target
+    ///
+    /// # Arguments
+    /// * `height` - Height in meters
+    #[inline]
+    pub fn with_height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the zoom levels and starts at the first one
+    ///
+    /// # Arguments
+    /// * `zoom_levels` - Visible height of the map in meters at each level
+    #[inline]
+    pub fn with_zoom_levels(mut self, zoom_levels: impl Into<Vec<f32>>) -> Self {
+        self.zoom_levels = zoom_levels.into();
+        self.zoom_level = 0;
+        self
+    }
+
+    /// Sets the dead zone the target may move in before the camera follows
+    ///
+    /// # Arguments
+    /// * `dead_zone` - Radius in meters
+    #[inline]
+    pub fn with_dead_zone(mut self, dead_zone: f32) -> Self {
+        self.dead_zone = dead_zone;
+        self
+    }
+
+    /// Sets whether the map turns with the target so its facing is up
+    ///
+    /// # Arguments
+    /// * `rotate_with_target` - Whether the map turns with the target
+    #[inline]
+    pub fn with_rotation(mut self, rotate_with_target: bool) -> Self {
+        self.rotate_with_target = rotate_with_target;
+        self
+    }
+
+    /// Returns the index of the current zoom level
+    #[inline]
+    pub fn zoom_level(&self) -> usize {
+        self.zoom_level
+    }
+
+    /// Sets the current zoom level, clamped to the available levels
+    ///
+    /// # Arguments
+    /// * `zoom_level` - Index of the zoom level
+    #[inline]
+    pub fn set_zoom_level(&mut self, zoom_level: usize) {
+        self.zoom_level = zoom_level.min(self.zoom_levels.len().saturating_sub(1));
+    }
+
+    /// Moves to the next nearer zoom level, if any
+    #[inline]
+    pub fn zoom_in(&mut self) {
+        self.set_zoom_level(self.zoom_level.saturating_sub(1));
+    }
+
+    /// Moves to the next further zoom level, if any
+    #[inline]
+    pub fn zoom_out(&mut self) {
+        self.set_zoom_level(self.zoom_level + 1);
+    }
+
+    /// Returns the visible height of the map in meters at the current zoom level
+    #[inline]
+    pub fn visible_height(&self) -> Option<f32> {
+        self.zoom_levels.get(self.zoom_level).copied()
+    }
+}
+
+/// Places minimap cameras above their targets and sets their orthographic zoom
+///
+/// # Arguments
+/// * `cameras` - Query for minimap cameras, their transforms and projections
+/// * `target_transforms` - Query for target transforms
+/// * `units` - Resource describing the scale of the world
+/// * `skipped` - Minimap cameras skipped for missing targets, warned about once
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_minimap_cameras(
+    mut cameras: Query<
+        (
+            Entity,
+            &MinimapCameraController,
+            &mut Transform,
+            &mut Projection,
+        ),
+        With<Camera>,
+    >,
+    target_transforms: Query<TargetTransform, Without<Camera>>,
+    units: Res<WorldUnits>,
+    mut skipped: Local<SkippedEntities>,
+) {
+    for (entity, minimap, mut transform, mut projection) in cameras.iter_mut() {
+        let Some(target_transform) = skipped.check(
+            entity,
+            target::get_transform(&target_transforms, minimap.target),
+        ) else {
+            continue;
+        };
+
+        // follow across the ground once the target leaves the dead zone
+        let focus = target_transform.translation.xz();
+        let offset = focus - transform.translation.xz();
+        let center = focus - offset.clamp_length_max(units.to_world(minimap.dead_zone));
+
+        let up = if minimap.rotate_with_target {
+            target_transform
+                .forward()
+                .with_y(0.0)
+                .normalize_or(Vec3::NEG_Z)
+        } else {
+            Vec3::NEG_Z
+        };
+        let translation = Vec3::new(
+            center.x,
+            target_transform.translation.y + units.to_world(minimap.height),
+            center.y,
+        );
+        let goal = Transform::from_translation(translation).looking_to(Vec3::NEG_Y, up);
+        if transform.translation != goal.translation || transform.rotation != goal.rotation {
+            transform.translation = goal.translation;
+            transform.rotation = goal.rotation;
+        }
+
+        let Some(visible_height) = minimap.visible_height() else {
+            continue;
+        };
+        let scaling_mode = ScalingMode::FixedVertical {
+            viewport_height: units.to_world(visible_height),
+        };
+        match &mut *projection {
+            Projection::Orthographic(orthographic) => orthographic.scaling_mode = scaling_mode,
+            projection => {
+                *projection = Projection::Orthographic(OrthographicProjection {
+                    scaling_mode,
+                    ..OrthographicProjection::default_3d()
+                });
+            }
+        }
+    }
+}
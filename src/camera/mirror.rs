@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+
+use super::{
+    CameraController,
+    target::{CameraFilter, SkippedEntities},
+};
+
+/// A component for cameras that copy the solved pose of another controller's camera
+/// each frame, useful for rear-view mirrors and picture-in-picture views
+#[derive(Component, Clone)]
+pub struct MirrorCamera {
+    /// Entity with the camera controller whose camera pose is copied
+    pub source: Entity,
+    /// Transform applied on top of the source pose, in the source camera's local space
+    pub offset: Transform,
+}
+
+impl MirrorCamera {
+    /// Creates a new MirrorCamera copying the source pose without offset
+    ///
+    /// # Arguments
+    /// * `source` - Entity with the camera controller to mirror
+    pub fn new(source: Entity) -> Self {
+        Self {
+            source,
+            offset: Transform::IDENTITY,
+        }
+    }
+
+    /// Creates a new MirrorCamera that looks backwards from the source pose
+    ///
+    /// # Arguments
+    /// * `source` - Entity with the camera controller to mirror
+    pub fn rear_view(source: Entity) -> Self {
+        Self::new(source).with_offset(Transform::from_rotation(Quat::from_rotation_y(
+            std::f32::consts::PI,
+        )))
+    }
+
+    /// Sets the transform applied on top of the source pose
+    ///
+    /// # Arguments
+    /// * `offset` - Transform in the source camera's local space
+    #[inline]
+    pub fn with_offset(mut self, offset: Transform) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+/// Copies solved controller camera poses onto mirror cameras
+///
+/// # Arguments
+/// * `mirrors` - Query for mirror camera settings
+/// * `camera_controllers` - Query for controllers being mirrored
+/// * `camera_transforms` - Query for camera transforms to read and modify
+/// * `skipped` - Mirrors skipped for missing entities, warned about once
+pub(crate) fn update_mirrors(
+    mirrors: Query<(Entity, &MirrorCamera)>,
+    camera_controllers: Query<&CameraController>,
+    mut camera_transforms: Query<&mut Transform, CameraFilter>,
+    mut skipped: Local<SkippedEntities>,
+) {
+    for (entity, mirror) in mirrors.iter() {
+        let lookup = camera_controllers
+            .get(mirror.source)
+            .and_then(|controller| camera_transforms.get(controller.camera).copied())
+            .and_then(|source_transform| {
+                Ok((source_transform, camera_transforms.get_mut(entity)?))
+            });
+        let Some((source_transform, mut transform)) = skipped.check(entity, lookup) else {
+            continue;
+        };
+        *transform = source_transform * mirror.offset;
+    }
+}
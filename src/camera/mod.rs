@@ -1,38 +1,528 @@
+mod bounds;
+#[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+mod collision;
+#[cfg(feature = "avian2d")]
+mod collision2d;
 mod controller;
+mod controller2d;
+#[cfg(feature = "egui")]
+mod debug_ui;
+mod delta;
+mod diagnostics;
+mod director;
+mod drag_pan;
+mod events;
+mod feedback;
+mod fixed;
+mod flight;
+mod fly;
+mod frame;
+mod gizmos;
+mod group;
+mod input;
+#[cfg(feature = "avian3d")]
+mod interpolation;
+mod killcam;
+mod lockon;
+mod minimap;
+mod mirror;
+mod motion;
+mod navigation;
+mod noise;
+#[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+mod occlusion;
+mod offset;
+mod overrides;
+mod parallax;
+mod pause;
+#[cfg(feature = "avian3d")]
+mod pivot;
+mod presentation;
+#[cfg(feature = "ron")]
+mod profile;
+mod projection;
+mod rail;
+mod region;
+mod rts;
+mod sample;
+mod shake;
+mod shake2d;
+mod smoothing;
+mod snapshot;
+mod split;
+mod target;
+mod time;
+mod transition;
+mod units;
+mod up;
+mod virtual_camera;
+mod zone;
 
-pub use controller::{CameraAnchor, CameraBuffer, CameraController, CameraView};
+pub use bounds::{CameraBounds, CameraConfiner2d};
+#[cfg(feature = "rapier3d")]
+pub use collision::RapierQueryFilter;
+#[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+pub use collision::{CollisionFilter, CollisionMask, SpringArm, Whiskers, exclude_hierarchy};
+#[cfg(feature = "avian2d")]
+pub use collision2d::CameraCollision2d;
+pub use controller::{
+    BufferChannel, CameraAnchor, CameraBuffer, CameraController, CameraLean, CameraView, IdleOrbit,
+    OrbitSnap, Recenter, RotationMode, SecondaryCamera, SecondaryCameras, SnapTurn, SpeedScaling,
+    TargetLost,
+};
+pub use controller2d::{CameraController2d, CameraView2d, FollowWindow};
+#[cfg(feature = "egui")]
+pub use debug_ui::CameraDebugUiPlugin;
+pub use delta::{DeltaBuffer, DeltaBuffer1d, DeltaBuffer2d, DeltaBuffer3d};
+pub use diagnostics::{CameraDiagnosticsPlugin, CameraTimings, FrameTimings};
+pub use drag_pan::DragPan;
+pub use events::{
+    CameraAnchorChanged, CameraCollisionEnded, CameraCollisionStarted, CameraTargetLost,
+    CameraTargetTeleported, CameraTransitionFinished, CameraViewChanged,
+};
+pub use feedback::{CameraFeedback, CameraFeedbackKind};
+#[cfg(feature = "rumble")]
+pub use feedback::{FeedbackGamepad, RumbleSettings};
+pub use fixed::FixedCameraUpdate;
+pub use flight::{ChaseCamera, FlightBindings, FlightController};
+pub use fly::{FlyCameraBindings, FlyCameraController};
+pub use frame::FrameTarget;
+pub use gizmos::CameraGizmos;
+pub use group::{CameraTargetGroup, GroupTarget};
+pub use input::{
+    CameraInputPlugin, EdgeScroll, GamepadLook, InputSource, InputSources, MouseLook,
+    StickAcceleration, StickResponse, TouchGestures,
+};
+pub use killcam::{Killcam, KillcamEvent, KillcamPhase};
+pub use lockon::{LockOn, LockOnTarget};
+pub use minimap::MinimapCameraController;
+pub use mirror::MirrorCamera;
+pub use motion::CameraMotionState;
+pub use navigation::{
+    EditorNavigation, EditorNavigationBindings, NavigationAction, NavigationBinding,
+    NavigationModifier,
+};
+pub use noise::{CameraNoise, NoiseLayer};
+#[cfg(all(
+    feature = "occlusion_fade",
+    any(feature = "avian3d", feature = "rapier3d")
+))]
+pub use occlusion::OcclusionFade;
+#[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+pub use occlusion::{CameraOcclusionPlugin, OccludingCamera, OcclusionEvent};
+pub use offset::{OffsetAnimation, OffsetBlend, OffsetCurve, OffsetProfile};
+pub use overrides::{CameraOverride, OverrideGoal};
+pub use parallax::ParallaxLayer;
+pub use pause::{CameraControllerPaused, PausedInput};
+#[cfg(feature = "avian3d")]
+pub use pivot::PivotPicking;
+pub use presentation::{CameraFade, CameraFadeCovered, CameraFadeFinished, Letterbox};
+#[cfg(feature = "ron")]
+pub use profile::{CameraProfile, CameraProfileError, CameraProfileHandle, CameraProfileLoader};
+pub use projection::ProjectionMode;
+pub use rail::CameraRail;
+pub use region::{CameraRegion, RegionScale};
+pub use rts::{RtsCameraBindings, RtsCameraController};
+pub use sample::{CameraSample, SampleBuffer};
+pub use shake::CameraShake;
+pub use shake2d::{CameraShake2d, ShakeImpulse2d};
+pub use smoothing::Smoothing;
+pub use snapshot::CameraSnapshot;
+pub use split::{CameraPlayer, SplitScreen, SplitScreenLayout};
+pub use target::TargetTransform;
+pub use time::CameraTimeSource;
+pub use transition::CameraTransition;
+pub use units::WorldUnits;
+pub use up::CameraUp;
+pub use virtual_camera::{CameraBrain, VirtualBlend, VirtualCamera};
+pub use zone::{CameraZone, ZoneVolume};
 
-use bevy::prelude::*;
+#[cfg(feature = "avian3d")]
+use avian3d::prelude::*;
+use bevy::{
+    ecs::{
+        query::QueryFilter,
+        schedule::{InternedScheduleLabel, ScheduleLabel},
+        system::SystemParam,
+    },
+    platform::time::Instant,
+    prelude::*,
+};
+use controller::IsometricState;
+use core::f32::consts::{PI, TAU};
+use smoothing::SmoothingVelocity;
+use target::{CameraFilter, SkippedEntities, TargetFilter};
+use time::CameraTime;
+
+/// Filter for controllers solved every frame in the plugin's schedule
+type FrameUpdate = Without<FixedCameraUpdate>;
+/// Filter for controllers solved in `FixedPostUpdate`
+type FixedStep = With<FixedCameraUpdate>;
+
+/// System sets of the camera controller systems, allowing systems to be ordered around
+/// buffer consumption and camera updates, or paused with run conditions such as during menus.
+/// The sets run in both the schedule of the [`CameraPlugin`], `PostUpdate` by default,
+/// and `FixedPostUpdate`.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CameraControlSet {
+    /// Buffered input is consumed into each controller's rotation and zoom
+    ConsumeInput,
+    /// Camera transforms are solved from controller settings
+    UpdateCamera,
+}
 
 /// Camera Plugin for managing camera systems and physics plugins (when avian3d feature is enabled).
-#[derive(Default)]
-pub struct CameraPlugin;
+///
+/// With avian3d, controllers solved in `FixedPostUpdate` run after physics, and controllers
+/// solved every frame run in `PostUpdate` after avian's transform interpolation has eased
+/// bodies for the frame. Followed bodies without interpolation still move only on fixed
+/// steps, which `CameraController::with_body_interpolation` fixes.
+///
+/// Camera systems run in `PostUpdate` by default, before transforms are propagated. Projects
+/// running all gameplay in another schedule can move them there with
+/// [`CameraPlugin::in_schedule`], ordering their own systems around [`CameraControlSet`].
+/// [`FixedCameraUpdate`] controllers are always solved in `FixedPostUpdate`.
+pub struct CameraPlugin {
+    /// Schedule the camera systems run in
+    schedule: InternedScheduleLabel,
+}
+
+impl Default for CameraPlugin {
+    fn default() -> Self {
+        Self::in_schedule(PostUpdate)
+    }
+}
+
+impl CameraPlugin {
+    /// Creates a new CameraPlugin running the camera systems in the given schedule,
+    /// such as `FixedPostUpdate` or a custom gameplay schedule
+    ///
+    /// # Arguments
+    /// * `schedule` - Schedule the camera systems run in
+    pub fn in_schedule(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+        }
+    }
+}
 
 impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            PostUpdate,
+        app.register_type::<CameraController>()
+            .register_type::<CameraBuffer>()
+            .register_type::<CameraControllerPaused>()
+            .register_type::<DeltaBuffer1d>()
+            .register_type::<DeltaBuffer2d>()
+            .register_type::<DeltaBuffer3d>()
+            .init_resource::<WorldUnits>()
+            .add_event::<CameraFeedback>()
+            .add_event::<KillcamEvent>()
+            .add_event::<ShakeImpulse2d>();
+
+        // offset curves are assets, which require the asset plugin
+        if app.world().contains_resource::<AssetServer>() {
+            app.init_asset::<OffsetCurve>();
+
+            #[cfg(feature = "ron")]
+            app.init_asset::<CameraProfile>()
+                .init_asset_loader::<CameraProfileLoader>()
+                .add_systems(
+                    self.schedule,
+                    profile::apply_camera_profiles.before(CameraControlSet::ConsumeInput),
+                );
+        }
+
+        // fixed timestep cameras are solved after physics has moved their targets, while frame
+        // cameras run in PostUpdate, after interpolated bodies have been eased for the frame
+        #[cfg(feature = "avian3d")]
+        app.configure_sets(
+            FixedPostUpdate,
+            CameraControlSet::ConsumeInput.after(PhysicsSet::Sync),
+        )
+        .add_systems(
+            self.schedule,
+            (
+                interpolation::interpolate_followed_bodies.before(CameraControlSet::ConsumeInput),
+                pivot::pick_orbit_pivots.before(CameraControlSet::ConsumeInput),
+            ),
+        );
+
+        #[cfg(feature = "rumble")]
+        app.init_resource::<RumbleSettings>().add_systems(
+            self.schedule,
+            feedback::rumble_feedback.after(update_camera::<FrameUpdate>),
+        );
+
+        app.configure_sets(
+            self.schedule,
+            (
+                CameraControlSet::ConsumeInput,
+                CameraControlSet::UpdateCamera,
+            )
+                .chain()
+                .before(TransformSystem::TransformPropagate),
+        )
+        .configure_sets(
+            FixedPostUpdate,
+            (
+                CameraControlSet::ConsumeInput,
+                CameraControlSet::UpdateCamera,
+            )
+                .chain(),
+        )
+        .add_systems(
+            self.schedule,
+            (
+                shake::remove_shake.before(consume_buffers::<FrameUpdate>),
+                killcam::update_killcams.before(consume_buffers::<FrameUpdate>),
+                region::update_region_scales.before(consume_buffers::<FrameUpdate>),
+                zone::update_zones.before(consume_buffers::<FrameUpdate>),
+                offset::update_offset_blends
+                    .after(zone::update_zones)
+                    .before(consume_buffers::<FrameUpdate>),
+                snapshot::update_snapshots
+                    .after(zone::update_zones)
+                    .after(overrides::suppress_input)
+                    .before(consume_buffers::<FrameUpdate>),
+                overrides::suppress_input.before(consume_buffers::<FrameUpdate>),
+                director::direct_cameras
+                    .after(snapshot::update_snapshots)
+                    .before(consume_buffers::<FrameUpdate>),
+                up::align_up_axes.before(CameraControlSet::ConsumeInput),
+                consume_buffers::<FrameUpdate>
+                    .in_set(CameraControlSet::ConsumeInput)
+                    .before(update_camera::<FrameUpdate>),
+                apply_samples
+                    .after(consume_buffers::<FrameUpdate>)
+                    .before(update_camera::<FrameUpdate>),
+                lockon::update_lock_on
+                    .after(apply_samples)
+                    .before(update_camera::<FrameUpdate>),
+                update_camera::<FrameUpdate>
+                    .in_set(CameraControlSet::UpdateCamera)
+                    .before(TransformSystem::TransformPropagate),
+                // fixed timestep cameras are placed where frame cameras are solved
+                fixed::interpolate_fixed_poses
+                    .after(shake::remove_shake)
+                    .before(overrides::apply_overrides),
+                overrides::apply_overrides
+                    .after(update_camera::<FrameUpdate>)
+                    .before(TransformSystem::TransformPropagate),
+                shake::apply_shake
+                    .after(overrides::apply_overrides)
+                    .before(TransformSystem::TransformPropagate),
+                mirror::update_mirrors
+                    .after(shake::apply_shake)
+                    .before(TransformSystem::TransformPropagate),
+                // virtual cameras are complete once shake is applied to them
+                virtual_camera::update_brains
+                    .after(shake::apply_shake)
+                    .before(TransformSystem::TransformPropagate),
+                motion::update_motion_state
+                    .after(overrides::apply_overrides)
+                    .before(shake::apply_shake),
+            ),
+        )
+        // split-screen viewports and standalone 2D, fly, strategy and minimap cameras
+        .add_systems(
+            self.schedule,
+            (
+                pause::hold_paused_input
+                    .before(CameraControlSet::ConsumeInput)
+                    .before(flight::update_flight_controllers)
+                    .before(update_camera2d),
+                frame::frame_targets.before(CameraControlSet::ConsumeInput),
+                drag_pan::update_drag_pans
+                    .before(CameraControlSet::ConsumeInput)
+                    .before(update_camera2d),
+                update_camera2d.before(TransformSystem::TransformPropagate),
+                parallax::update_parallax_layers
+                    .after(update_camera2d)
+                    .before(TransformSystem::TransformPropagate),
+                // 2D shake wraps the follow logic and parallax, so neither smooths it away
+                shake2d::remove_shake_2d.before(update_camera2d),
+                shake2d::apply_shake_2d
+                    .after(parallax::update_parallax_layers)
+                    .before(TransformSystem::TransformPropagate),
+                fly::update_fly_cameras.before(TransformSystem::TransformPropagate),
+                flight::update_flight_controllers.before(CameraControlSet::ConsumeInput),
+                flight::update_chase_cameras
+                    .after(flight::update_flight_controllers)
+                    .before(TransformSystem::TransformPropagate),
+                minimap::update_minimap_cameras
+                    .after(CameraControlSet::UpdateCamera)
+                    .after(flight::update_flight_controllers)
+                    .before(TransformSystem::TransformPropagate),
+                rts::update_rts_cameras.before(TransformSystem::TransformPropagate),
+                split::update_split_screen.before(rts::update_rts_cameras),
+            ),
+        )
+        // noise sway wraps around shake, so both are removed in reverse order
+        .add_systems(
+            self.schedule,
+            (
+                noise::remove_noise.before(shake::remove_shake),
+                noise::apply_noise
+                    .after(shake::apply_shake)
+                    .before(mirror::update_mirrors)
+                    .before(virtual_camera::update_brains)
+                    .before(TransformSystem::TransformPropagate),
+            ),
+        )
+        // screen fades and letterbox bars drawn over camera views
+        .add_systems(
+            self.schedule,
             (
-                consume_buffers.before(update_camera),
-                update_camera.before(TransformSystem::TransformPropagate),
+                presentation::update_camera_fades,
+                presentation::update_letterboxes,
+                presentation::cleanup_overlays,
             ),
+        )
+        // rig gizmos show where cameras were solved, before overrides and shake
+        .add_systems(
+            self.schedule,
+            (
+                gizmos::draw_camera_gizmos
+                    .after(CameraControlSet::UpdateCamera)
+                    .before(overrides::apply_overrides),
+                gizmos::draw_camera_gizmos_2d.after(update_camera2d),
+            )
+                .run_if(resource_exists::<CameraGizmos>),
+        )
+        .add_systems(
+            FixedPostUpdate,
+            (
+                fixed::restore_fixed_poses,
+                consume_buffers::<FixedStep>.in_set(CameraControlSet::ConsumeInput),
+                update_camera::<FixedStep>.in_set(CameraControlSet::UpdateCamera),
+                fixed::record_fixed_poses,
+            )
+                .chain(),
         );
     }
 }
 
-fn consume_buffers(
-    mut camera_controllers: Query<(&CameraController, &mut CameraBuffer)>,
-    mut camera_transforms: Query<&mut Transform, With<Camera>>,
-    time: Res<Time>,
-) -> Result<(), BevyError> {
-    for (controller, mut buffer) in camera_controllers.iter_mut() {
-        let mut camera_transform = camera_transforms.get_mut(controller.camera)?;
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn consume_buffers<F: QueryFilter>(
+    mut camera_controllers: Query<
+        (
+            Entity,
+            &mut CameraController,
+            &mut CameraBuffer,
+            Has<FixedCameraUpdate>,
+        ),
+        (F, Without<CameraControllerPaused>),
+    >,
+    mut camera_transforms: Query<&mut Transform, CameraFilter>,
+    camera_parents: Query<&ChildOf, CameraFilter>,
+    controller_transforms: Query<TargetTransform, TargetFilter>,
+    units: Res<WorldUnits>,
+    mut feedback: EventWriter<CameraFeedback>,
+    mut timings: Option<ResMut<CameraTimings>>,
+    time: CameraTime,
+    mut skipped: Local<SkippedEntities>,
+) {
+    let start = timings.is_some().then(Instant::now);
+
+    for (entity, mut controller, mut buffer, fixed_step) in camera_controllers.iter_mut() {
+        // controllers sharing a camera with a higher priority one are skipped
+        if !controller.is_live() {
+            continue;
+        }
+        // paused controllers drop input so the camera does not jump when resumed
+        if !controller.enabled {
+            buffer.clear();
+            continue;
+        }
+        // parented cameras are solved in world space and written back relative to the parent,
+        // missing cameras are reported by update_camera
+        let Ok(camera_parent) = target::get_parent_transform(
+            &camera_parents,
+            &controller_transforms,
+            controller.camera,
+        ) else {
+            continue;
+        };
+        let Ok(mut camera_local) = camera_transforms.get_mut(controller.camera) else {
+            continue;
+        };
+        // a missing controller transform skips only this controller, warning once until it
+        // recovers
+        let Some(controller_transform) = skipped.check(
+            entity,
+            target::get_transform(&controller_transforms, entity),
+        ) else {
+            continue;
+        };
+        let mut camera_transform = target::to_world(*camera_local, camera_parent);
         // get time delta
-        let dt = time.delta_secs();
+        let dt = time.solve_delta_secs(controller.time_source, fixed_step);
+
+        // remove the kick and lean so they never affect pitch limits or anchor smoothing,
+        // a head tracked rotation is replaced by the headset every frame instead
+        if let Some(rotation) = buffer.kick_applied.take()
+            && !controller.head_tracked
+        {
+            camera_transform.rotation *= rotation.inverse();
+        }
+        if let Some((translation, rotation)) = buffer.lean_applied.take() {
+            camera_transform.translation -= translation;
+            if !controller.head_tracked {
+                camera_transform.rotation *= rotation.inverse();
+            }
+        }
+
+        // sum contributions from all writers before consuming
+        buffer.flush_channels();
+        buffer.update_idle_time(dt);
+        buffer.expire(dt);
+
+        // apply zoom to the orbit distance, other anchors discard zoom input
+        let zoom = controller.get_zoom_delta(&mut buffer, dt);
+        let mut limited = false;
+        if let CameraAnchor::Orbit { distance } | CameraAnchor::Isometric { distance, .. } =
+            controller.anchor
+            && zoom != 0.0
+        {
+            let mut zoomed = distance - zoom;
+            if let Some((min, max)) = controller.distance_limits {
+                let clamped = zoomed.clamp(min, max);
+                limited = clamped != zoomed;
+                // only report the limit when it is first reached
+                if limited && !buffer.zoom_limited {
+                    feedback.write(CameraFeedback::new(
+                        entity,
+                        CameraFeedbackKind::ZoomLimit,
+                        (clamped - zoomed).abs(),
+                    ));
+                }
+                if limited {
+                    // discard remaining zoom pushing against the limit
+                    buffer.take_zoom();
+                }
+                zoomed = clamped;
+            }
+            match &mut controller.anchor {
+                CameraAnchor::Orbit { distance } | CameraAnchor::Isometric { distance, .. } => {
+                    *distance = zoomed;
+                }
+                _ => (),
+            }
+        }
+        buffer.zoom_limited = limited;
+
+        // pan input translates, offsetting the orbited point across the view for the orbit
+        // anchor while it keeps following the controller entity
+        let pan = units.to_world(controller.get_pan_delta(&mut buffer, dt));
+        if let CameraAnchor::Orbit { .. } = controller.anchor
+            && pan != Vec2::ZERO
+        {
+            buffer.pan_offset += camera_transform.right() * pan.x + camera_transform.up() * pan.y;
+        }
 
         match controller.anchor {
             CameraAnchor::Yaw => {
-                let delta = controller.get_translation_delta(&mut buffer, dt);
+                let delta = units.to_world(controller.get_translation_delta(&mut buffer, dt)) + pan;
 
                 let displacement =
                     controller.yaw_axis * delta.y + camera_transform.local_x() * delta.x;
@@ -40,7 +530,7 @@ fn consume_buffers(
                 camera_transform.translation += displacement;
             }
             CameraAnchor::Plane { normal } => {
-                let delta = controller.get_translation_delta(&mut buffer, dt);
+                let delta = units.to_world(controller.get_translation_delta(&mut buffer, dt)) + pan;
                 let local_y = controller
                     .yaw_axis
                     .reject_from_normalized(normal.as_vec3())
@@ -50,100 +540,1177 @@ fn consume_buffers(
 
                 camera_transform.translation += displacement;
             }
+            CameraAnchor::Isometric { directions, .. } => {
+                let step_angle = TAU / directions.max(1) as f32;
+                let delta = controller.get_rotation_delta(&mut buffer, dt);
+                let turn_time = controller.isometric_turn_time;
+
+                // start from the heading nearest the current rotation
+                let yaw = controller
+                    .yaw_to_heading(Quat::IDENTITY, buffer.rotation * Vec3::NEG_Z)
+                    .unwrap_or(0.0);
+                let state = buffer.isometric.get_or_insert_with(|| {
+                    IsometricState::new((yaw / step_angle).round() as i32, step_angle)
+                });
+
+                // turn once input passes half a step, discarding it when input stops
+                if delta.x == 0.0 {
+                    state.accumulated = 0.0;
+                } else {
+                    state.accumulated += delta.x;
+                    let steps = (state.accumulated / step_angle).round();
+                    if steps != 0.0 {
+                        state.from = state.yaw(step_angle, turn_time);
+                        state.step += steps as i32;
+                        state.accumulated -= steps * step_angle;
+                        state.elapsed = 0.0;
+                    }
+                }
+            }
             _ => {
                 // get camera rotation delta
-                let delta = controller.get_rotation_delta(&mut buffer, dt);
+                let mut delta = match &controller.snap_turn {
+                    Some(snap_turn) => controller.get_snap_turn_delta(snap_turn, &mut buffer, dt),
+                    None => {
+                        let delta = controller.get_rotation_delta(&mut buffer, dt);
+                        // keep spinning with friction once input stops
+                        match controller.inertia {
+                            Some(friction) => buffer.spin(delta, friction, dt),
+                            None => delta,
+                        }
+                    }
+                };
+                let mut roll = controller.get_roll_delta(&mut buffer, dt);
+                // pitch and roll are owned by the headset when head tracked
+                if controller.head_tracked {
+                    delta.y = 0.0;
+                    roll = 0.0;
+                }
 
-                // apply yaw rotation around world axis
-                let yaw_rotation = Quat::from_axis_angle(controller.yaw_axis.as_vec3(), delta.x);
-                buffer.rotation = yaw_rotation * buffer.rotation;
+                match controller.rotation_mode {
+                    RotationMode::Turntable => {
+                        // apply yaw rotation around world axis
+                        let yaw_rotation =
+                            Quat::from_axis_angle(controller.yaw_axis.as_vec3(), delta.x);
+                        buffer.rotation = yaw_rotation * buffer.rotation;
 
-                // apply pitch rotation around local x axis
-                if controller.can_rotate_pitch(delta.y, camera_transform.rotation) {
-                    buffer.rotation *= Quat::from_rotation_x(delta.y);
+                        // apply pitch rotation around local x axis
+                        if controller.can_rotate_pitch(delta.y, camera_transform.rotation) {
+                            buffer.rotation *= Quat::from_rotation_x(delta.y);
+                        }
+                    }
+                    // roll the camera over a virtual sphere, turning around the local axis
+                    // perpendicular to the drag so no axis stays upright
+                    RotationMode::Arcball => {
+                        let angle = delta.length();
+                        if angle > 0.0 {
+                            let axis = Vec3::new(delta.y, delta.x, 0.0) / angle;
+                            buffer.rotation =
+                                (buffer.rotation * Quat::from_axis_angle(axis, angle)).normalize();
+                        }
+                    }
+                }
+
+                // apply roll rotation around local forward axis
+                if roll != 0.0 && controller.can_rotate_roll(roll, buffer.rotation) {
+                    buffer.rotation *= Quat::from_rotation_z(roll);
+                }
+
+                // pull orbit yaw toward the controller entity's facing
+                if matches!(controller.anchor, CameraAnchor::Orbit { .. })
+                    && controller.heading_follow > 0.0
+                    && let Some(yaw) = controller
+                        .yaw_to_heading(buffer.rotation, controller_transform.forward().as_vec3())
+                {
+                    // weight is the fraction of the error removed per 60th of a second
+                    let factor = 1.0 - (1.0 - controller.heading_follow).powf(dt * 60.0);
+                    buffer.rotation =
+                        Quat::from_axis_angle(controller.yaw_axis.as_vec3(), yaw * factor)
+                            * buffer.rotation;
+                }
+
+                // slowly orbit once rotation input has been idle long enough
+                if let (CameraAnchor::Orbit { .. }, Some(idle_orbit)) =
+                    (&controller.anchor, controller.idle_orbit)
+                    && buffer.idle_time >= idle_orbit.delay
+                {
+                    buffer.rotation =
+                        Quat::from_axis_angle(controller.yaw_axis.as_vec3(), idle_orbit.speed * dt)
+                            * buffer.rotation;
+                }
+
+                // drift back behind the controller entity once rotation input is idle
+                if let (CameraAnchor::Orbit { .. }, Some(recenter)) =
+                    (&controller.anchor, controller.recenter)
+                    && recenter.enabled
+                    && buffer.idle_time >= recenter.delay
+                    && let Some(yaw) = controller
+                        .yaw_to_heading(buffer.rotation, controller_transform.forward().as_vec3())
+                {
+                    let max_turn = recenter.speed * dt;
+                    buffer.rotation = Quat::from_axis_angle(
+                        controller.yaw_axis.as_vec3(),
+                        yaw.clamp(-max_turn, max_turn),
+                    ) * buffer.rotation;
+                }
+
+                // animate to the nearest fixed heading once yaw input is released
+                let snap_steps = controller.take_orbit_snap_steps();
+                match (&controller.anchor, controller.orbit_snap) {
+                    (CameraAnchor::Orbit { .. }, Some(orbit_snap)) if delta.x == 0.0 => {
+                        let step_angle = TAU / orbit_snap.directions.max(1) as f32;
+                        if let Some(yaw) =
+                            controller.yaw_to_heading(Quat::IDENTITY, buffer.rotation * Vec3::NEG_Z)
+                        {
+                            let state = buffer.orbit_snap.get_or_insert_with(|| IsometricState {
+                                elapsed: 0.0,
+                                from: yaw,
+                                ..IsometricState::new((yaw / step_angle).round() as i32, step_angle)
+                            });
+                            if snap_steps != 0 {
+                                state.from = state.yaw(step_angle, orbit_snap.turn_time);
+                                state.step += snap_steps;
+                                state.elapsed = 0.0;
+                            }
+                            state.elapsed += dt;
+                            let turn = (state.yaw(step_angle, orbit_snap.turn_time) - yaw + PI)
+                                .rem_euclid(TAU)
+                                - PI;
+                            buffer.rotation =
+                                Quat::from_axis_angle(controller.yaw_axis.as_vec3(), turn)
+                                    * buffer.rotation;
+                        }
+                    }
+                    // turning freely while yaw input is held
+                    _ => buffer.orbit_snap = None,
+                }
+
+                // keep yaw within the configured range
+                if controller.yaw_range.is_some() {
+                    let reference = match controller.yaw_reference {
+                        Some(reference) => reference.as_vec3(),
+                        None => controller_transform.forward().as_vec3(),
+                    };
+                    buffer.rotation = controller.clamp_yaw(buffer.rotation, reference);
                 }
             }
         }
+
+        let local = target::to_local(camera_transform, camera_parent);
+        if *camera_local != local {
+            *camera_local = local;
+        }
+    }
+
+    if let (Some(timings), Some(start)) = (timings.as_mut(), start) {
+        timings.frame.consume_buffers += start.elapsed();
     }
-    Ok(())
+}
+
+/// Overrides buffered rotation and controller position with interpolated remote samples
+///
+/// # Arguments
+/// * `camera_controllers` - Query for controllers, sample buffers, camera buffers and transforms
+/// * `time` - Clocks providing frame timing for each controller's time source
+fn apply_samples(
+    mut camera_controllers: Query<(
+        &CameraController,
+        &mut SampleBuffer,
+        &mut CameraBuffer,
+        &mut Transform,
+    )>,
+    time: CameraTime,
+) {
+    for (controller, mut samples, mut buffer, mut transform) in camera_controllers.iter_mut() {
+        let playback_time = time.elapsed_secs(controller.time_source) - samples.delay;
+
+        let Some(sample) = samples.sample(playback_time) else {
+            continue;
+        };
+        // samples replace raw deltas, so discard any accumulated input
+        buffer.reset();
+        buffer.rotation = sample.rotation;
+        if let Some(translation) = sample.translation {
+            transform.translation = translation;
+        }
+
+        samples.prune(playback_time);
+    }
+}
+
+/// Scene access shared by every controller solved in [`update_camera`]
+#[derive(SystemParam)]
+struct CameraSolver<'w, 's> {
+    /// Commands for triggering camera state change events
+    commands: Commands<'w, 's>,
+    /// Query for camera transforms to modify
+    camera_transforms: Query<'w, 's, &'static mut Transform, CameraFilter>,
+    /// Query for the parents of parented cameras
+    camera_parents: Query<'w, 's, &'static ChildOf, CameraFilter>,
+    /// Query for camera projections driven by field of view and projection blends
+    projections: Query<'w, 's, &'static mut Projection, CameraFilter>,
+    /// Query for rails cameras dolly along
+    rails: Query<'w, 's, &'static CameraRail>,
+    /// Query for target transforms for camera targetting
+    target_transforms: Query<'w, 's, TargetTransform, TargetFilter>,
+    /// Optional assets for curve driven offsets
+    offset_curves: Option<Res<'w, Assets<OffsetCurve>>>,
+    /// Resource describing the scale of the world
+    units: Res<'w, WorldUnits>,
+    /// Scene queries for collision aware cameras
+    #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+    collision_query: collision::CollisionQuery<'w, 's>,
+    /// Query for children excluded from camera collision
+    #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+    children: Query<'w, 's, &'static Children>,
+    /// Event writer for notable camera events
+    #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+    feedback: EventWriter<'w, CameraFeedback>,
+    /// Optional resource collecting timings for diagnostics
+    timings: Option<ResMut<'w, CameraTimings>>,
+    /// Clocks providing frame timing for each controller's time source
+    time: CameraTime<'w>,
 }
 
 /// Updates camera position and rotation each frame based on controller settings
 ///
 /// # Arguments
 /// * `camera_controllers` - Query for camera controller and buffer
-/// * `camera_transforms` - Query for camera transforms to modify
-/// * `target_transforms` - Query for target transforms for camera targetting
-/// * `time` - Resource providing frame timing information
-fn update_camera(
-    camera_controllers: Query<(Entity, &CameraController, &CameraBuffer)>,
-    mut camera_transforms: Query<&mut Transform, With<Camera>>,
-    target_transforms: Query<&Transform, Without<Camera>>,
-    time: Res<Time>,
-) -> Result<(), BevyError> {
-    for (entity, controller, buffer) in camera_controllers.iter() {
-        let mut camera_transform = camera_transforms.get_mut(controller.camera)?;
-        let controller_transform = target_transforms.get(entity)?;
+/// * `solver` - Scene access shared by every controller
+/// * `skipped` - Controllers skipped for missing entities, warned about once
+#[allow(clippy::type_complexity)]
+fn update_camera<F: QueryFilter>(
+    mut camera_controllers: Query<
+        (
+            Entity,
+            &mut CameraController,
+            &mut CameraBuffer,
+            Option<&OffsetAnimation>,
+            Option<&CameraBounds>,
+            Option<&CameraTargetGroup>,
+            Has<FixedCameraUpdate>,
+        ),
+        (F, Without<CameraControllerPaused>),
+    >,
+    mut solver: CameraSolver,
+    mut skipped: Local<SkippedEntities>,
+) {
+    let system_start = solver.timings.is_some().then(Instant::now);
 
+    for (entity, mut controller, mut buffer, animation, bounds, group, fixed_step) in
+        camera_controllers.iter_mut()
+    {
+        if !controller.is_live() || !controller.enabled {
+            continue;
+        }
+        let start = solver.timings.is_some().then(Instant::now);
         // get time delta
-        let dt = time.delta_secs();
+        let dt = solver
+            .time
+            .solve_delta_secs(controller.time_source, fixed_step);
 
-        match controller.anchor {
-            CameraAnchor::Point => {
-                let local_offset = controller_transform.rotation * controller.offset;
-                let target_translation = controller_transform.translation + local_offset;
-
-                let decay_rate = controller.get_translation_decay_rate();
-                // calculate target distance with smoothing if enabled
-
-                let target_distance = 0.0;
-                let distance = if decay_rate.is_finite() {
-                    // apply smoothed translation for perspective view
-                    let mut distance = camera_transform.translation.distance(target_translation);
-                    distance.smooth_nudge(&target_distance, decay_rate, dt);
-                    distance
-                } else {
-                    target_distance
-                };
+        // missing entities skip only this controller, warning once until it recovers
+        let result = solve_controller(
+            entity,
+            dt,
+            &mut controller,
+            &mut buffer,
+            animation,
+            bounds,
+            group,
+            &mut solver,
+        );
+        skipped.check(entity, result);
+
+        if let (Some(timings), Some(start)) = (solver.timings.as_mut(), start) {
+            timings.record_controller(entity, start.elapsed());
+        }
+    }
+
+    if let (Some(timings), Some(start)) = (solver.timings.as_mut(), system_start) {
+        timings.frame.update_camera += start.elapsed();
+    }
+}
+
+/// Solves the camera pose of a single controller, failing if an entity it needs is missing
+///
+/// # Arguments
+/// * `entity` - Controller entity
+/// * `dt` - Seconds since the controller was last solved
+/// * `controller` - Camera controller to solve
+/// * `buffer` - Input and state buffer of the controller
+/// * `animation` - Optional animation between offset profiles
+/// * `bounds` - Optional volume the camera is kept inside
+/// * `group` - Optional group of targets the camera frames
+/// * `solver` - Scene access shared by every controller
+#[allow(clippy::too_many_arguments)]
+fn solve_controller(
+    entity: Entity,
+    dt: f32,
+    controller: &mut CameraController,
+    buffer: &mut CameraBuffer,
+    animation: Option<&OffsetAnimation>,
+    bounds: Option<&CameraBounds>,
+    group: Option<&CameraTargetGroup>,
+    solver: &mut CameraSolver,
+) -> Result<(), BevyError> {
+    let CameraSolver {
+        commands,
+        camera_transforms,
+        camera_parents,
+        projections,
+        rails,
+        target_transforms,
+        offset_curves,
+        units,
+        #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+        collision_query,
+        #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+        children,
+        #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+        feedback,
+        #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+        timings,
+        ..
+    } = solver;
+
+    // report anchor and view changes made since the last update
+    if let Some(previous) = buffer.anchor.replace(controller.anchor.clone())
+        && core::mem::discriminant(&previous) != core::mem::discriminant(&controller.anchor)
+    {
+        commands.trigger_targets(
+            CameraAnchorChanged {
+                previous,
+                anchor: controller.anchor.clone(),
+            },
+            entity,
+        );
+    }
+    if let Some(previous) = buffer.view.replace(controller.view.clone())
+        && previous != controller.view
+    {
+        commands.trigger_targets(
+            CameraViewChanged {
+                previous,
+                view: controller.view.clone(),
+            },
+            entity,
+        );
+    }
+
+    controller.advance_shoulder_swap(dt);
+    let (offset, look_offset) =
+        offset::resolve_offsets(controller, animation, offset_curves.as_deref());
+    let (offset, look_offset) = (units.to_world(offset), units.to_world(look_offset));
+
+    // parented cameras are solved in world space and written back relative to the parent
+    let camera_parent =
+        target::get_parent_transform(camera_parents, target_transforms, controller.camera)?;
+    let mut camera_local = camera_transforms.get_mut(controller.camera)?;
+    let mut camera_transform = target::to_world(*camera_local, camera_parent);
+    // remove the projection dolly so it never feeds back into smoothing
+    let dolly = controller.projection_blend.take_applied();
+    if dolly != Vec3::ZERO {
+        camera_transform.translation -= dolly;
+    }
+    let previous_transform = camera_transform;
+    let controller_transform = target::get_transform(target_transforms, entity)?;
+
+    // a despawned view target holds the last rotation, which a free view starts from
+    let lost_target = match controller.view {
+        CameraView::Target(target) if !target_transforms.contains(target) => Some(target),
+        _ => None,
+    };
+    if let Some(target) = lost_target {
+        if buffer.lost_target != Some(target) {
+            buffer.lost_target = Some(target);
+            commands.trigger_targets(CameraTargetLost { target }, entity);
+        }
+        buffer.rotation = camera_transform.rotation;
+        if controller.target_lost == TargetLost::Free {
+            controller.view = CameraView::Free;
+            controller.retarget = None;
+        }
+    } else {
+        buffer.lost_target = None;
+    }
+    let view = match lost_target {
+        Some(_) => CameraView::Free,
+        None => controller.view.clone(),
+    };
+    let group_bounds = match group {
+        Some(group) => group.bounds(target_transforms, units)?,
+        None => None,
+    };
+
+    // snap over teleports rather than smoothing across the level
+    let jump = buffer.previous_translation.map_or(0.0, |previous| {
+        units.to_meters(controller_transform.translation.distance(previous))
+    });
+    let teleported = controller
+        .teleport_distance
+        .is_some_and(|distance| jump > distance);
+    if teleported {
+        commands.trigger_targets(CameraTargetTeleported { distance: jump }, entity);
+    }
+    let snap = controller.take_snap_request() || teleported;
+    if snap {
+        buffer.velocity = SmoothingVelocity::default();
+        buffer.rail_parameter = None;
+        #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+        {
+            buffer.spring_arm = None;
+        }
+        // the jump is not movement to widen the view for
+        buffer.previous_translation = None;
+    }
+    let follow_smoothing = if snap {
+        Smoothing::INSTANT
+    } else {
+        controller.translation_smoothing()[0]
+    };
 
-                // position camera at calculated distance behind target
-                camera_transform.translation =
-                    camera_transform.rotation * Vec3::ZERO.with_z(distance) + target_translation;
+    // widen the view as the controller entity moves faster
+    match controller.speed_scaling {
+        Some(scaling) if dt > 0.0 => {
+            let speed = buffer.previous_translation.map_or(0.0, |previous| {
+                units.to_meters(controller_transform.translation.distance(previous)) / dt
+            });
+            buffer.speed_factor = scaling.smooth(buffer.speed_factor, speed, dt);
+        }
+        Some(_) => (),
+        None => buffer.speed_factor = 0.0,
+    }
+    buffer.previous_translation = Some(controller_transform.translation);
+    let (speed_fov, speed_distance) = controller.speed_scaling.map_or((0.0, 0.0), |scaling| {
+        (
+            scaling.fov * buffer.speed_factor,
+            units.to_world(scaling.distance * buffer.speed_factor),
+        )
+    });
+
+    // drive the field of view with smoothing if enabled
+    if let Some(target_fov) = controller
+        .target_fov()
+        .map(|fov| (fov + speed_fov).clamp(0.01, PI - 0.01))
+    {
+        let mut projection = projections.get_mut(controller.camera)?;
+        if let Projection::Perspective(perspective) = projection.as_ref() {
+            let decay_rate = controller.get_fov_decay_rate();
+            let mut fov = perspective.fov;
+            if decay_rate.is_finite() {
+                fov.smooth_nudge(&target_fov, decay_rate, dt);
+            } else {
+                fov = target_fov;
+            }
+            if fov != perspective.fov
+                && let Projection::Perspective(perspective) = projection.as_mut()
+            {
+                perspective.fov = fov;
             }
-            CameraAnchor::Orbit {
-                distance: target_distance,
-            } => {
-                let local_offset = controller_transform.rotation * controller.offset;
-                let target_translation = controller_transform.translation + local_offset;
+        }
+    }
 
-                // calculate target distance with smoothing if enabled
-                let decay_rate = controller.get_translation_decay_rate();
+    match controller.anchor {
+        CameraAnchor::Point => {
+            let local_offset = controller_transform.rotation * offset;
+            let target_translation = controller_transform.translation + local_offset;
 
-                let distance = if decay_rate.is_finite() {
-                    // apply smoothed translation for perspective view
+            // calculate target distance with smoothing if enabled
+            let target_distance = 0.0;
+            let distance = follow_smoothing.step(
+                camera_transform.translation.distance(target_translation),
+                target_distance,
+                &mut buffer.velocity.follow,
+                dt,
+            );
 
-                    let mut distance = camera_transform.translation.distance(target_translation);
-                    distance.smooth_nudge(&target_distance, decay_rate, dt);
-                    distance
-                } else {
-                    target_distance
-                };
+            // position camera at calculated distance behind target
+            camera_transform.translation =
+                camera_transform.rotation * Vec3::ZERO.with_z(distance) + target_translation;
+        }
+        CameraAnchor::Orbit {
+            distance: target_distance,
+        } => {
+            let mut target_distance = units.to_world(target_distance) + speed_distance;
+            let local_offset = controller_transform.rotation * offset;
+            let mut target_translation = controller_transform.translation + local_offset;
 
-                // position camera at calculated distance behind target
-                camera_transform.translation =
-                    buffer.rotation * Vec3::ZERO.with_z(distance) + target_translation;
+            // orbit the group center, moving out until the whole group fits
+            if let (Some(group), Some(group_bounds)) = (group, &group_bounds) {
+                target_translation = group_bounds.center + local_offset;
+                if let Ok(Projection::Perspective(perspective)) = projections.get(controller.camera)
+                {
+                    target_distance =
+                        target_distance.max(group.fit_distance(group_bounds, perspective));
+                }
             }
-            _ => (),
+            // orbit a point picked in the world instead
+            if let Some(pivot) = controller.orbit_pivot.as_mut() {
+                target_translation = pivot.advance(target_translation, dt);
+            }
+            target_translation += buffer.pan_offset;
+
+            // calculate target distance with smoothing if enabled
+            let current_distance = camera_transform.translation.distance(target_translation);
+            // smooth the unobstructed distance so the spring arm does not restart it
+            #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+            let current_distance = match (controller.spring_arm, buffer.spring_arm) {
+                (Some(_), Some((unobstructed, _))) => unobstructed,
+                _ => current_distance,
+            };
+
+            let distance = follow_smoothing.step(
+                current_distance,
+                target_distance,
+                &mut buffer.velocity.follow,
+                dt,
+            );
+
+            // pull the camera in front of obstacles between it and the pivot
+            #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+            let distance = match controller.spring_arm {
+                Some(spring_arm) => {
+                    let start = timings.is_some().then(Instant::now);
+
+                    let filter = controller.collision_filter(entity, children);
+                    // turn away from obstacles beside the arm before they block it
+                    if let Some(whiskers) = &spring_arm.whiskers {
+                        let steer = collision::steer_whiskers(
+                            collision_query,
+                            &filter,
+                            target_translation,
+                            buffer.rotation,
+                            distance,
+                            whiskers,
+                            dt,
+                        );
+                        buffer.rotation =
+                            Quat::from_axis_angle(controller.yaw_axis.as_vec3(), steer.x)
+                                * buffer.rotation;
+                        if controller.can_rotate_pitch(steer.y, buffer.rotation) {
+                            buffer.rotation *= Quat::from_rotation_x(steer.y);
+                        }
+                    }
+                    let direction = Dir3::new(buffer.rotation * Vec3::Z).unwrap_or(Dir3::Z);
+                    let allowed = collision::cast_spring_arm(
+                        collision_query,
+                        &filter,
+                        target_translation,
+                        direction,
+                        distance,
+                        units.to_world(spring_arm.probe_radius),
+                    );
+
+                    if let (Some(timings), Some(start)) = (timings.as_mut(), start) {
+                        timings.record_collision(entity, start.elapsed());
+                    }
+                    // only report the clamp when it engages, as strong as the arm is pulled in
+                    if allowed < distance - f32::EPSILON && !buffer.colliding {
+                        feedback.write(CameraFeedback::new(
+                            entity,
+                            CameraFeedbackKind::CollisionClamp,
+                            1.0 - allowed / distance,
+                        ));
+                    }
+                    let length = buffer
+                        .spring_arm
+                        .map_or(allowed, |(_, length)| {
+                            spring_arm.smooth(length, allowed, dt)
+                        })
+                        .min(distance);
+                    buffer.spring_arm = Some((distance, length));
+                    length
+                }
+                None => distance,
+            };
+
+            // position camera at calculated distance behind target
+            camera_transform.translation =
+                buffer.rotation * Vec3::ZERO.with_z(distance) + target_translation;
+        }
+        CameraAnchor::Rail(rail_entity) => {
+            let rail = rails.get(rail_entity)?;
+            let rail_transform = target::get_transform(target_transforms, rail_entity)?;
+            let tracked = match view {
+                CameraView::Target(target) => {
+                    target::get_transform(target_transforms, target)?.translation
+                }
+                CameraView::Free => controller_transform.translation,
+            };
+
+            // find the nearest point in the rail's local space
+            let local_point = rail_transform
+                .compute_affine()
+                .inverse()
+                .transform_point3(tracked);
+            let target_parameter = rail.nearest_parameter(local_point);
+
+            // dolly along the rail with smoothing if enabled
+            let parameter = match buffer.rail_parameter {
+                Some(parameter) => follow_smoothing.step(
+                    parameter,
+                    target_parameter,
+                    &mut buffer.velocity.follow,
+                    dt,
+                ),
+                None => target_parameter,
+            };
+            buffer.rail_parameter = Some(parameter);
+
+            camera_transform.translation = rail_transform.transform_point(rail.position(parameter));
         }
-        match controller.view {
+        CameraAnchor::Isometric {
+            distance: target_distance,
+            pitch,
+            directions,
+        } => {
+            let target_distance = units.to_world(target_distance);
+            let local_offset = controller_transform.rotation * offset;
+            let target_translation = controller_transform.translation + local_offset;
+
+            // animate toward the snapped heading at a fixed pitch
+            let step_angle = TAU / directions.max(1) as f32;
+            let turn_time = controller.isometric_turn_time;
+            let yaw = match buffer.isometric.as_mut() {
+                Some(state) => {
+                    state.elapsed += dt;
+                    state.yaw(step_angle, turn_time)
+                }
+                None => 0.0,
+            };
+            buffer.rotation = Quat::from_rotation_arc(Vec3::Y, controller.yaw_axis.as_vec3())
+                * Quat::from_rotation_y(yaw)
+                * Quat::from_rotation_x(-pitch);
+
+            // calculate target distance with smoothing if enabled
+            let distance = follow_smoothing.step(
+                camera_transform.translation.distance(target_translation),
+                target_distance,
+                &mut buffer.velocity.follow,
+                dt,
+            );
+
+            camera_transform.translation =
+                buffer.rotation * Vec3::ZERO.with_z(distance) + target_translation;
+        }
+        _ => (),
+    }
+    if !matches!(controller.anchor, CameraAnchor::Rail(_)) {
+        buffer.rail_parameter = None;
+    }
+    if !matches!(controller.anchor, CameraAnchor::Isometric { .. }) {
+        buffer.isometric = None;
+    }
+    if !matches!(controller.anchor, CameraAnchor::Orbit { .. }) {
+        buffer.orbit_snap = None;
+        buffer.pan_offset = Vec3::ZERO;
+    }
+
+    // chase sudden jumps rather than following them in a single frame
+    if let Some(max_speed) = controller.max_speed
+        && !snap
+    {
+        let step = (camera_transform.translation - previous_transform.translation)
+            .clamp_length_max(units.to_world(max_speed) * dt);
+        camera_transform.translation = previous_transform.translation + step;
+    }
+
+    // keep the camera inside the level before resolving collisions
+    if let Some(bounds) = bounds {
+        let translation = bounds.clamp_translation(camera_transform.translation);
+        if translation != camera_transform.translation {
+            camera_transform.translation = translation;
+        }
+    }
+
+    // collision state is recomputed every update
+    let colliding = {
+        #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+        {
+            let mut colliding = false;
+            if let Some(min_height) = controller.min_ground_height.map(|h| units.to_world(h)) {
+                let start = timings.is_some().then(Instant::now);
+
+                let filter = controller.collision_filter(entity, children);
+                let push = collision::clamp_to_ground(
+                    collision_query,
+                    &filter,
+                    &mut camera_transform.translation,
+                    controller.yaw_axis,
+                    min_height,
+                );
+                // only report the clamp when it engages
+                if let Some(push) = push
+                    && !buffer.colliding
+                {
+                    feedback.write(CameraFeedback::new(
+                        entity,
+                        CameraFeedbackKind::CollisionClamp,
+                        push / min_height,
+                    ));
+                }
+                colliding |= push.is_some();
+
+                if let (Some(timings), Some(start)) = (timings.as_mut(), start) {
+                    timings.record_collision(entity, start.elapsed());
+                }
+            }
+            if let CameraAnchor::Orbit { .. } = controller.anchor
+                && let Some((unobstructed, length)) = buffer.spring_arm
+                && controller.spring_arm.is_some()
+            {
+                colliding |= length < unobstructed - f32::EPSILON;
+            } else {
+                buffer.spring_arm = None;
+            }
+            colliding
+        }
+        #[cfg(not(any(feature = "avian3d", feature = "rapier3d")))]
+        false
+    };
+    if colliding != buffer.colliding {
+        if colliding {
+            commands.trigger_targets(CameraCollisionStarted, entity);
+        } else {
+            commands.trigger_targets(CameraCollisionEnded, entity);
+        }
+    }
+    buffer.colliding = colliding;
+
+    if controller.head_tracked {
+        // the headset owns the rotation, body yaw turns its pose around the yaw axis
+        let yaw = controller
+            .yaw_to_heading(Quat::IDENTITY, buffer.rotation * Vec3::NEG_Z)
+            .unwrap_or(0.0);
+        camera_transform.rotation =
+            Quat::from_axis_angle(controller.yaw_axis.as_vec3(), yaw) * camera_transform.rotation;
+    } else {
+        match view {
             CameraView::Free => {
                 camera_transform.rotation = buffer.rotation;
             }
             CameraView::Target(target) => {
-                let target_transform = target_transforms.get(target)?;
-                camera_transform.look_at(target_transform.translation, controller.yaw_axis);
+                let target_transform = target::get_transform(target_transforms, target)?;
+                let look_point =
+                    target_transform.translation + target_transform.rotation * look_offset;
+                camera_transform.look_at(look_point, controller.yaw_axis);
+
+                // turn from the previous target while retargeting
+                if controller.retarget.is_some()
+                    && let Some(retarget) = controller.retarget.as_mut()
+                {
+                    let weight = retarget.advance(dt);
+                    let finished = retarget.is_finished();
+                    // targets removed mid blend end the blend
+                    match target::get_transform(target_transforms, retarget.previous) {
+                        Ok(previous_transform) => {
+                            let previous_point = previous_transform.translation
+                                + previous_transform.rotation * look_offset;
+                            let previous_rotation = camera_transform
+                                .looking_at(previous_point, controller.yaw_axis)
+                                .rotation;
+                            camera_transform.rotation =
+                                previous_rotation.slerp(camera_transform.rotation, weight);
+                            if finished {
+                                controller.retarget = None;
+                            }
+                        }
+                        Err(_) => controller.retarget = None,
+                    }
+                }
+
+                // keep any roll from the roll channel on top of the look rotation
+                let roll = controller.get_roll(buffer.rotation);
+                if roll != 0.0 {
+                    camera_transform.rotate_local_z(roll);
+                }
+            }
+        }
+    }
+
+    // turn toward the solved rotation rather than cutting to it
+    let look_decay_rate = controller.get_look_decay_rate();
+    if look_decay_rate.is_finite() && !controller.head_tracked && !snap {
+        let mut rotation = previous_transform.rotation;
+        rotation.smooth_nudge(&camera_transform.rotation, look_decay_rate, dt);
+        camera_transform.rotation = rotation;
+    }
+
+    // turn toward sudden changes of view at a limited speed
+    if let Some(max_angular_speed) = controller.max_angular_speed
+        && !controller.head_tracked
+        && !snap
+    {
+        camera_transform.rotation = previous_transform
+            .rotation
+            .rotate_towards(camera_transform.rotation, max_angular_speed * dt);
+    }
+
+    // blend away from the pose held before switching anchor or view
+    if controller.transition.is_some()
+        && let Some(transition) = controller.transition.as_mut()
+    {
+        camera_transform = transition.blend(previous_transform, camera_transform, dt);
+        if transition.is_finished() {
+            controller.transition = None;
+            commands.trigger_targets(CameraTransitionFinished, entity);
+        }
+    }
+
+    // lean on top of the solved camera, removed again before the next update
+    if let Some(lean) = controller.lean {
+        buffer.lean = lean.smooth(buffer.lean, controller.lean_target(), dt);
+        if buffer.lean != 0.0 {
+            let translation =
+                camera_transform.right() * units.to_world(lean.max_offset) * buffer.lean;
+            let rotation = Quat::from_rotation_z(-lean.max_roll * buffer.lean);
+            camera_transform.translation += translation;
+            camera_transform.rotation *= rotation;
+            buffer.lean_applied = Some((translation, rotation));
+        }
+    } else {
+        buffer.lean = 0.0;
+    }
+
+    // kick on top of the lean, recovering independently of input smoothing
+    let kick = controller.kick();
+    if kick != Vec2::ZERO {
+        let rotation = Quat::from_euler(EulerRot::YXZ, kick.x, kick.y, 0.0);
+        camera_transform.rotation *= rotation;
+        buffer.kick_applied = Some(rotation);
+        controller.recover_kick(dt);
+    }
+
+    // dolly toward or away from a flat projection around the focus point
+    if controller.projection_blend.is_active() {
+        let focus = match view {
+            CameraView::Target(target) => {
+                target::get_transform(target_transforms, target)?.translation
             }
+            CameraView::Free => controller_transform.translation,
+        };
+        let focus_distance = camera_transform
+            .translation
+            .distance(focus)
+            .max(units.to_world(0.1));
+        let mut projection = projections.get_mut(controller.camera)?;
+        controller.projection_blend.apply(
+            &mut projection,
+            &mut camera_transform,
+            focus_distance,
+            dt,
+        );
+    }
+
+    let local = target::to_local(camera_transform, camera_parent);
+    if *camera_local != local {
+        *camera_local = local;
+    }
+
+    // slaved cameras follow the solved pose in the same frame
+    for secondary in controller.secondary_cameras.iter() {
+        let parent =
+            target::get_parent_transform(camera_parents, target_transforms, secondary.camera)?;
+        let mut transform = camera_transforms.get_mut(secondary.camera)?;
+        *transform = target::to_local(camera_transform * secondary.offset, parent);
+    }
+
+    Ok(())
+}
+
+/// Scene access shared by every controller solved in [`update_camera2d`]
+#[derive(SystemParam)]
+struct CameraSolver2d<'w, 's> {
+    /// Commands for triggering camera events
+    commands: Commands<'w, 's>,
+    /// Query for camera transforms and projections to modify
+    cameras: Query<
+        'w,
+        's,
+        (
+            &'static mut Transform,
+            &'static mut Projection,
+            &'static Camera,
+        ),
+    >,
+    /// Query for target transforms for following
+    target_transforms: Query<'w, 's, TargetTransform, Without<Camera>>,
+    /// Resource describing the scale of the world
+    units: Res<'w, WorldUnits>,
+    /// Scene queries for cameras colliding with level geometry
+    #[cfg(feature = "avian2d")]
+    spatial_query: avian2d::prelude::SpatialQuery<'w, 's>,
+    /// Clocks providing frame timing for each controller's time source
+    time: CameraTime<'w>,
+}
+
+/// Updates 2D camera position, rotation and zoom each frame based on controller settings
+///
+/// # Arguments
+/// * `camera_controllers` - Query for 2D camera controller and buffer
+/// * `solver` - Scene access shared by every controller
+/// * `skipped` - Controllers skipped for missing or unsuitable entities, warned about once
+#[allow(clippy::type_complexity)]
+fn update_camera2d(
+    mut camera_controllers: Query<
+        (
+            Entity,
+            &mut CameraController2d,
+            &mut CameraBuffer,
+            Option<&CameraBounds>,
+            Option<&CameraConfiner2d>,
+            Option<&CameraTargetGroup>,
+        ),
+        Without<CameraControllerPaused>,
+    >,
+    mut solver: CameraSolver2d,
+    mut skipped: Local<SkippedEntities>,
+) {
+    for (entity, mut controller, mut buffer, bounds, confiner, group) in
+        camera_controllers.iter_mut()
+    {
+        // missing entities skip only this controller, warning once until it recovers
+        let result = solve_controller2d(
+            entity,
+            &mut controller,
+            &mut buffer,
+            bounds,
+            confiner,
+            group,
+            &mut solver,
+        );
+        skipped.check(entity, result);
+    }
+}
+
+/// Solves the camera of a single 2D controller, failing if an entity it needs is missing
+/// or the camera is not orthographic
+///
+/// # Arguments
+/// * `entity` - Controller entity
+/// * `controller` - 2D camera controller to solve
+/// * `buffer` - Input and state buffer of the controller
+/// * `bounds` - Optional area the camera is kept inside
+/// * `confiner` - Optional shape the visible area is kept inside
+/// * `group` - Optional group of targets the camera frames
+/// * `solver` - Scene access shared by every controller
+fn solve_controller2d(
+    entity: Entity,
+    controller: &mut CameraController2d,
+    buffer: &mut CameraBuffer,
+    bounds: Option<&CameraBounds>,
+    confiner: Option<&CameraConfiner2d>,
+    group: Option<&CameraTargetGroup>,
+    solver: &mut CameraSolver2d,
+) -> Result<(), BevyError> {
+    let CameraSolver2d {
+        commands,
+        cameras,
+        target_transforms,
+        units,
+        #[cfg(feature = "avian2d")]
+        spatial_query,
+        time,
+    } = solver;
+
+    let (mut camera_transform, mut projection, camera) = cameras.get_mut(controller.camera)?;
+    let Projection::Orthographic(orthographic) = projection.as_ref() else {
+        return Err(format!("camera {} is not orthographic", controller.camera).into());
+    };
+    let (area, previous_scale) = (orthographic.area, orthographic.scale.max(f32::EPSILON));
+
+    // get time delta
+    let dt = time.delta_secs(controller.time_source);
+
+    // snap over teleports of the followed target rather than smoothing across the level
+    let followed = match controller.view {
+        CameraView2d::Follow { target, .. } => {
+            Some(target::get_transform(target_transforms, target)?.translation)
         }
+        CameraView2d::Manual => None,
+    };
+    let jump = match (buffer.previous_translation, followed) {
+        (Some(previous), Some(followed)) => units.to_meters(followed.distance(previous)),
+        _ => 0.0,
+    };
+    buffer.previous_translation = followed;
+    let teleported = controller
+        .teleport_distance
+        .is_some_and(|distance| jump > distance);
+    if teleported {
+        commands.trigger_targets(CameraTargetTeleported { distance: jump }, entity);
+    }
+    let snap = controller.take_snap_request() || teleported;
+
+    // turn toward the target rotation along the shortest direction
+    buffer.expire(dt);
+    controller.consume_rotation(buffer);
+    let current_angle = camera_transform.rotation.to_euler(EulerRot::ZYX).0;
+    let difference = (controller.target_rotation() - current_angle + PI).rem_euclid(TAU) - PI;
+    let target_angle = current_angle + difference;
+    let decay_rate = controller.get_rotation_decay_rate();
+    let mut angle = current_angle;
+    if decay_rate.is_finite() && !snap {
+        angle.smooth_nudge(&target_angle, decay_rate, dt);
+    } else {
+        angle = target_angle;
+    }
+    if let Some(max_angular_speed) = controller.max_angular_speed
+        && !snap
+    {
+        let max_turn = max_angular_speed * dt;
+        angle = current_angle + (angle - current_angle).clamp(-max_turn, max_turn);
+    }
+    if angle != current_angle {
+        camera_transform.rotation = Quat::from_rotation_z(angle);
+    }
+    // follow and pan math happens along the rotated screen axes
+    let rotation = Rot2::radians(angle);
+
+    // zoom out until the whole group fits
+    let unit_half_size = area.half_size() / previous_scale;
+    let mut target_zoom = controller.zoom;
+    let group_bounds = match group {
+        Some(group) => group
+            .bounds(target_transforms, units)?
+            .map(|group_bounds| (group, group_bounds)),
+        None => None,
+    };
+    if let Some((group, group_bounds)) = &group_bounds {
+        target_zoom = target_zoom.max(group.fit_scale(group_bounds, unit_half_size));
+    }
+    target_zoom = controller.clamp_zoom(target_zoom);
+
+    // calculate zoom with smoothing if enabled
+    let decay_rate = controller.get_zoom_decay_rate();
+    let mut scale = orthographic.scale;
+    if decay_rate.is_finite() && !snap {
+        scale.smooth_nudge(&target_zoom, decay_rate, dt);
+    } else {
+        scale = target_zoom;
+    }
+    // the visible area is only refreshed by the projection later, so rescale it for the new zoom
+    let view_half_size = unit_half_size * scale;
+    if scale != orthographic.scale
+        && let Projection::Orthographic(orthographic) = projection.as_mut()
+    {
+        orthographic.scale = scale;
+    }
+
+    let mut center = camera_transform.translation.truncate();
+    // continue from the unsnapped center unless the camera was moved elsewhere
+    let snapped_center = center;
+    if let Some(unsnapped) = buffer.unsnapped_center
+        && controller.snap_translation(unsnapped, rotation) == center
+    {
+        center = unsnapped;
     }
+    // keep the world point under the zoom anchor stationary as the scale changes
+    if let Some(anchor) = controller.zoom_anchor
+        && let Some(viewport_size) = camera.logical_viewport_size()
+    {
+        let normalized = anchor / viewport_size;
+        // viewport y points down while the visible area's y points up
+        let unit_point = Vec2::new(
+            area.min.x + normalized.x * area.width(),
+            area.max.y - normalized.y * area.height(),
+        ) / previous_scale;
+        center += rotation * unit_point * (previous_scale - scale);
+        if scale == target_zoom {
+            controller.zoom_anchor = None;
+        }
+    }
+    // goals are solved in screen space, where x is right and y is up
+    let local_center = rotation.inverse() * center;
+    let offset = units.to_world(controller.offset);
+    let goal = match (&group_bounds, &controller.view) {
+        // groups are centered directly, replacing the view's target
+        (Some((_, group_bounds)), _) => {
+            Some(rotation.inverse() * group_bounds.center.truncate() + offset)
+        }
+        (None, CameraView2d::Follow { target, radius }) => {
+            let target_transform = target::get_transform(target_transforms, *target)?;
+            let focus = rotation.inverse() * target_transform.translation.truncate() + offset;
+            Some(controller.get_follow_goal(
+                local_center,
+                focus,
+                units.to_world(*radius),
+                view_half_size,
+            ))
+        }
+        (None, CameraView2d::Manual) => None,
+    };
+    let translation = match goal {
+        Some(goal) => {
+            // apply smoothing separately for horizontal and vertical movement
+            let decay_rate = controller.get_translation_decay_rate();
+            let mut translation = local_center;
+            for axis in 0..2 {
+                if decay_rate[axis].is_finite() && !snap {
+                    translation[axis].smooth_nudge(&goal[axis], decay_rate[axis], dt);
+                } else {
+                    translation[axis] = goal[axis];
+                }
+            }
+            rotation * translation
+        }
+        None => {
+            // pan speed follows zoom so panning feels the same at any scale
+            let delta = controller.get_translation_delta(buffer, dt);
+            center + rotation * units.to_world(delta) * scale
+        }
+    };
+    // chase sudden jumps rather than following them in a single frame
+    let translation = match controller.max_speed {
+        Some(max_speed) if !snap => {
+            center + (translation - center).clamp_length_max(units.to_world(max_speed) * dt)
+        }
+        _ => translation,
+    };
+    // stop in front of level geometry between the camera and what it follows
+    #[cfg(feature = "avian2d")]
+    let translation = match controller.collision {
+        Some(collision) => {
+            let (origin, excluded) = match (&group_bounds, &controller.view) {
+                (Some((_, group_bounds)), _) => (group_bounds.center.truncate(), None),
+                (None, CameraView2d::Follow { target, .. }) => {
+                    let target_transform = target::get_transform(target_transforms, *target)?;
+                    (target_transform.translation.truncate(), Some(*target))
+                }
+                // panning is stopped by walls between the previous and new center
+                (None, CameraView2d::Manual) => (center, None),
+            };
+            collision2d::cast_view(
+                spatial_query,
+                excluded,
+                origin,
+                translation,
+                units.to_world(collision.probe_radius),
+            )
+        }
+        None => translation,
+    };
+    // keep the whole visible area inside the level, not just the center,
+    // using the world space extents of the rotated view
+    let (sin, cos) = (rotation.sin.abs(), rotation.cos.abs());
+    let world_half_size = Vec2::new(
+        cos * view_half_size.x + sin * view_half_size.y,
+        sin * view_half_size.x + cos * view_half_size.y,
+    );
+    let translation = match bounds {
+        Some(bounds) => bounds.clamp_view(translation, world_half_size),
+        None => translation,
+    };
+    let translation = match confiner {
+        Some(confiner) => confiner.clamp_view(translation, world_half_size),
+        None => translation,
+    };
+    buffer.unsnapped_center = controller.pixel_snap.map(|_| translation);
+    let translation = controller.snap_translation(translation, rotation);
+    if translation != snapped_center {
+        camera_transform.translation = translation.extend(camera_transform.translation.z);
+    }
+
     Ok(())
 }
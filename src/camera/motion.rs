@@ -0,0 +1,104 @@
+use bevy::prelude::*;
+
+use super::{
+    CameraBuffer, CameraController, WorldUnits,
+    target::{self, CameraFilter, SkippedEntities, TargetFilter, TargetTransform},
+    time::CameraTime,
+};
+
+/// A component exposing the derived motion of a controller's camera, updated each frame
+/// so animation and audio systems can react without recomputing it from transforms
+#[derive(Component, Clone, Debug)]
+pub struct CameraMotionState {
+    /// Angular speed of the camera in radians per second
+    pub turning_speed: f32,
+    /// Linear speed of the camera in units per second
+    pub speed: f32,
+    /// Distance between the camera and the controller entity
+    pub zoom: f32,
+    /// Whether the camera is below the settle thresholds
+    pub settled: bool,
+    /// Whether the camera is currently being pulled in by collision
+    pub colliding: bool,
+    /// Angular speed below which the camera counts as settled
+    pub settle_turning_speed: f32,
+    /// Linear speed in meters per second below which the camera counts as settled
+    pub settle_speed: f32,
+    /// Camera pose from the previous frame
+    previous: Option<(Vec3, Quat)>,
+}
+
+impl Default for CameraMotionState {
+    fn default() -> Self {
+        Self {
+            turning_speed: 0.0,
+            speed: 0.0,
+            zoom: 0.0,
+            settled: true,
+            colliding: false,
+            settle_turning_speed: 0.05,
+            settle_speed: 0.05,
+            previous: None,
+        }
+    }
+}
+
+impl CameraMotionState {
+    /// Returns whether the camera is moving or turning above the settle thresholds
+    #[inline]
+    pub fn is_moving(&self) -> bool {
+        !self.settled
+    }
+}
+
+/// Derives motion state from the solved camera transforms
+///
+/// # Arguments
+/// * `camera_controllers` - Query for controllers and their motion state
+/// * `camera_transforms` - Query for solved camera transforms
+/// * `controller_transforms` - Query for controller entity transforms
+/// * `units` - Resource describing the scale of the world
+/// * `time` - Clocks providing frame timing for each controller's time source
+/// * `skipped` - Controllers skipped for missing entities, warned about once
+pub(crate) fn update_motion_state(
+    mut camera_controllers: Query<(
+        Entity,
+        &CameraController,
+        &CameraBuffer,
+        &mut CameraMotionState,
+    )>,
+    camera_transforms: Query<&Transform, CameraFilter>,
+    controller_transforms: Query<TargetTransform, TargetFilter>,
+    units: Res<WorldUnits>,
+    time: CameraTime,
+    mut skipped: Local<SkippedEntities>,
+) {
+    for (entity, controller, buffer, mut state) in camera_controllers.iter_mut() {
+        let dt = time.delta_secs(controller.time_source);
+        let lookup = camera_transforms
+            .get(controller.camera)
+            .map_err(BevyError::from)
+            .and_then(|camera_transform| {
+                Ok((
+                    camera_transform,
+                    target::get_transform(&controller_transforms, entity)?,
+                ))
+            });
+        let Some((camera_transform, controller_transform)) = skipped.check(entity, lookup) else {
+            continue;
+        };
+
+        let (translation, rotation) = (camera_transform.translation, camera_transform.rotation);
+        if let Some((previous_translation, previous_rotation)) = state.previous
+            && dt > 0.0
+        {
+            state.speed = translation.distance(previous_translation) / dt;
+            state.turning_speed = rotation.angle_between(previous_rotation) / dt;
+        }
+        state.zoom = translation.distance(controller_transform.translation);
+        state.colliding = buffer.colliding;
+        state.settled = state.speed < units.to_world(state.settle_speed)
+            && state.turning_speed < state.settle_turning_speed;
+        state.previous = Some((translation, rotation));
+    }
+}
@@ -0,0 +1,347 @@
+use bevy::{
+    input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll, MouseScrollUnit},
+    prelude::*,
+    window::PrimaryWindow,
+};
+
+use super::{
+    CameraAnchor, CameraBuffer, CameraController, CameraControllerPaused, CameraPlayer,
+    InputSource, InputSources, WorldUnits,
+};
+
+/// Modifier key held together with a mouse button in a [`NavigationBinding`]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NavigationModifier {
+    /// No modifier is required
+    #[default]
+    None,
+    /// Either shift key
+    Shift,
+    /// Either control key
+    Control,
+    /// Either alt key
+    Alt,
+}
+
+impl NavigationModifier {
+    /// Checks whether the modifier is held, which is always the case without a modifier
+    ///
+    /// # Arguments
+    /// * `keys` - Keyboard input
+    pub fn pressed(&self, keys: &ButtonInput<KeyCode>) -> bool {
+        match self {
+            Self::None => true,
+            Self::Shift => keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+            Self::Control => keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]),
+            Self::Alt => keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]),
+        }
+    }
+}
+
+/// A mouse button dragged while a modifier is held
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NavigationBinding {
+    /// Button dragged
+    pub button: MouseButton,
+    /// Modifier held when the button is pressed
+    pub modifier: NavigationModifier,
+}
+
+impl NavigationBinding {
+    /// Creates a new NavigationBinding
+    ///
+    /// # Arguments
+    /// * `button` - Button dragged
+    /// * `modifier` - Modifier held when the button is pressed
+    pub fn new(button: MouseButton, modifier: NavigationModifier) -> Self {
+        Self { button, modifier }
+    }
+}
+
+/// Mouse drags used by [`EditorNavigation`], where unset actions are disabled
+#[derive(Clone, Debug)]
+pub struct EditorNavigationBindings {
+    /// Dragged to orbit
+    pub orbit: Option<NavigationBinding>,
+    /// Dragged to pan on the view plane
+    pub pan: Option<NavigationBinding>,
+    /// Dragged vertically to zoom
+    pub zoom: Option<NavigationBinding>,
+    /// Whether scrolling zooms
+    pub scroll_zoom: bool,
+}
+
+impl Default for EditorNavigationBindings {
+    fn default() -> Self {
+        Self::blender()
+    }
+}
+
+impl EditorNavigationBindings {
+    /// Middle mouse drag orbits, with shift pans and with control zooms, like Blender
+    pub fn blender() -> Self {
+        Self {
+            orbit: Some(NavigationBinding::new(
+                MouseButton::Middle,
+                NavigationModifier::None,
+            )),
+            pan: Some(NavigationBinding::new(
+                MouseButton::Middle,
+                NavigationModifier::Shift,
+            )),
+            zoom: Some(NavigationBinding::new(
+                MouseButton::Middle,
+                NavigationModifier::Control,
+            )),
+            scroll_zoom: true,
+        }
+    }
+
+    /// Alt with the left mouse button orbits, with the middle pans and with the right
+    /// zooms, like Maya
+    pub fn maya() -> Self {
+        Self {
+            orbit: Some(NavigationBinding::new(
+                MouseButton::Left,
+                NavigationModifier::Alt,
+            )),
+            pan: Some(NavigationBinding::new(
+                MouseButton::Middle,
+                NavigationModifier::Alt,
+            )),
+            zoom: Some(NavigationBinding::new(
+                MouseButton::Right,
+                NavigationModifier::Alt,
+            )),
+            scroll_zoom: true,
+        }
+    }
+
+    /// Returns the action started by pressing a button with the held modifiers, preferring
+    /// bindings that require a modifier over those that do not
+    ///
+    /// # Arguments
+    /// * `buttons` - Mouse button input
+    /// * `keys` - Keyboard input
+    fn started(
+        &self,
+        buttons: &ButtonInput<MouseButton>,
+        keys: &ButtonInput<KeyCode>,
+    ) -> Option<(NavigationAction, MouseButton)> {
+        [
+            (NavigationAction::Orbit, self.orbit),
+            (NavigationAction::Pan, self.pan),
+            (NavigationAction::Zoom, self.zoom),
+        ]
+        .into_iter()
+        .filter_map(|(action, binding)| Some((action, binding?)))
+        .filter(|(_, binding)| {
+            buttons.just_pressed(binding.button) && binding.modifier.pressed(keys)
+        })
+        .max_by_key(|(_, binding)| binding.modifier != NavigationModifier::None)
+        .map(|(action, binding)| (action, binding.button))
+    }
+}
+
+/// Action performed by an [`EditorNavigation`] drag
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NavigationAction {
+    /// Rotates around the pivot
+    Orbit,
+    /// Moves the pivot across the view plane
+    Pan,
+    /// Moves toward or away from the pivot
+    Zoom,
+}
+
+/// A component on a controller entity with the [`Orbit`](CameraAnchor::Orbit) anchor giving
+/// it the mouse navigation of modelling and animation tools, by default Blender's: middle
+/// mouse drag orbits, shift and middle mouse drag pans, and control and middle mouse drag
+/// or scrolling zooms.
+///
+/// The action is chosen when the button is pressed and kept until it is released. Panning
+/// moves the orbit pivot with [`CameraController::set_orbit_pivot`] so the point under the
+/// cursor stays under it, while orbiting and zooming go through the controller's buffer,
+/// sensitivity and smoothing. Controllers with this component are not fed by
+/// [`MouseLook`](super::MouseLook), so the mouse moves freely until a drag starts. Requires
+/// [`CameraInputPlugin`](super::CameraInputPlugin).
+#[derive(Component, Clone, Debug)]
+pub struct EditorNavigation {
+    /// Look input added per pixel of orbit drag
+    pub orbit_sensitivity: f32,
+    /// Zoom input added per pixel of zoom drag, positive values zoom in when dragging up
+    pub zoom_sensitivity: f32,
+    /// Zoom input added per line scrolled
+    pub scroll_sensitivity: f32,
+    /// Mouse drags used for each action
+    pub bindings: EditorNavigationBindings,
+    /// Action being dragged and the button held for it
+    active: Option<(NavigationAction, MouseButton)>,
+}
+
+impl Default for EditorNavigation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EditorNavigation {
+    /// Creates a new EditorNavigation with default settings:
+    /// - Blender bindings
+    /// - Orbit sensitivity: 0.005 per pixel
+    /// - Zoom sensitivity: 0.02 per pixel
+    /// - Scroll sensitivity: 1.0 per line
+    pub fn new() -> Self {
+        Self {
+            orbit_sensitivity: 0.005,
+            zoom_sensitivity: 0.02,
+            scroll_sensitivity: 1.0,
+            bindings: EditorNavigationBindings::default(),
+            active: None,
+        }
+    }
+
+    /// Sets the look input added per pixel of orbit drag
+    ///
+    /// # Arguments
+    /// * `sensitivity` - Look input per pixel
+    #[inline]
+    pub fn with_orbit_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.orbit_sensitivity = sensitivity;
+        self
+    }
+
+    /// Sets the zoom input added per pixel of zoom drag and per line scrolled
+    ///
+    /// # Arguments
+    /// * `drag` - Zoom input per pixel dragged up
+    /// * `scroll` - Zoom input per line scrolled
+    #[inline]
+    pub fn with_zoom_sensitivity(mut self, drag: f32, scroll: f32) -> Self {
+        self.zoom_sensitivity = drag;
+        self.scroll_sensitivity = scroll;
+        self
+    }
+
+    /// Sets the mouse drags used for each action
+    ///
+    /// # Arguments
+    /// * `bindings` - Mouse drag bindings
+    #[inline]
+    pub fn with_bindings(mut self, bindings: EditorNavigationBindings) -> Self {
+        self.bindings = bindings;
+        self
+    }
+
+    /// Returns the action being dragged, if any
+    #[inline]
+    pub fn active(&self) -> Option<NavigationAction> {
+        self.active.map(|(action, _)| action)
+    }
+}
+
+/// Feeds editor navigation drags and scrolling into controllers with the orbit anchor that
+/// are routed the keyboard and mouse
+///
+/// # Arguments
+/// * `camera_controllers` - Query for controllers, their buffers, navigation and input routing
+/// * `cameras` - Query for cameras and their global transforms
+/// * `windows` - Query for the primary window, used for the cursor position
+/// * `buttons` - Mouse button input, if the input plugin is present
+/// * `keys` - Keyboard input, if the input plugin is present
+/// * `motion` - Mouse motion accumulated this frame
+/// * `scroll` - Mouse scrolling accumulated this frame
+/// * `units` - Resource describing the scale of the world
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(crate) fn feed_editor_navigation(
+    mut camera_controllers: Query<
+        (
+            &mut CameraController,
+            &mut CameraBuffer,
+            &mut EditorNavigation,
+            Option<&CameraPlayer>,
+            Option<&InputSources>,
+        ),
+        Without<CameraControllerPaused>,
+    >,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    buttons: Option<Res<ButtonInput<MouseButton>>>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    motion: Option<Res<AccumulatedMouseMotion>>,
+    scroll: Option<Res<AccumulatedMouseScroll>>,
+    units: Res<WorldUnits>,
+) {
+    let (Some(buttons), Some(keys)) = (buttons, keys) else {
+        return;
+    };
+    let cursor = windows.single().ok().and_then(Window::cursor_position);
+    let motion = motion.map_or(Vec2::ZERO, |motion| motion.delta);
+    let scroll = scroll.map_or(0.0, |scroll| match scroll.unit {
+        MouseScrollUnit::Line => scroll.delta.y,
+        MouseScrollUnit::Pixel => scroll.delta.y / 16.0,
+    });
+
+    for (mut controller, mut buffer, mut navigation, player, sources) in
+        camera_controllers.iter_mut()
+    {
+        let CameraAnchor::Orbit { distance } = controller.anchor else {
+            navigation.active = None;
+            continue;
+        };
+        if !controller.enabled || !InputSources::routes(sources, player, InputSource::Mouse) {
+            navigation.active = None;
+            continue;
+        }
+
+        // the action is chosen on press, so releasing the modifier mid drag keeps it
+        if navigation
+            .active
+            .is_some_and(|(_, button)| !buttons.pressed(button))
+        {
+            navigation.active = None;
+        }
+        // drags only start over the window
+        if navigation.active.is_none() && cursor.is_some() {
+            navigation.active = navigation.bindings.started(&buttons, &keys);
+        }
+
+        if navigation.bindings.scroll_zoom && cursor.is_some() {
+            buffer.update_zoom(scroll * navigation.scroll_sensitivity);
+        }
+        if motion == Vec2::ZERO {
+            continue;
+        }
+        match navigation.active() {
+            Some(NavigationAction::Orbit) => {
+                buffer.update(-motion * navigation.orbit_sensitivity);
+            }
+            Some(NavigationAction::Zoom) => {
+                buffer.update_zoom(-motion.y * navigation.zoom_sensitivity);
+            }
+            Some(NavigationAction::Pan) => {
+                let (Some(cursor), Ok((camera, camera_transform))) =
+                    (cursor, cameras.get(controller.camera))
+                else {
+                    continue;
+                };
+                // slide the pivot across the plane through it facing the camera
+                let pivot = controller.orbit_pivot().unwrap_or_else(|| {
+                    camera_transform.translation()
+                        + camera_transform.forward() * units.to_world(distance)
+                });
+                let plane = InfinitePlane3d::new(camera_transform.forward());
+                let world_point = |cursor: Vec2| {
+                    let ray = camera.viewport_to_world(camera_transform, cursor).ok()?;
+                    let distance = ray.intersect_plane(pivot, plane)?;
+                    Some(ray.get_point(distance))
+                };
+                if let (Some(from), Some(to)) = (world_point(cursor - motion), world_point(cursor))
+                {
+                    controller.set_orbit_pivot(pivot + from - to, 0.0);
+                }
+            }
+            None => (),
+        }
+    }
+}
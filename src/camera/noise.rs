@@ -0,0 +1,239 @@
+use bevy::prelude::*;
+
+use super::{
+    CameraController, WorldUnits,
+    target::{CameraFilter, SkippedEntities},
+    time::CameraTime,
+};
+
+/// Hashes an integer lattice point into a value in the range -1 to 1
+#[inline]
+fn hash(seed: u32, i: i32) -> f32 {
+    let mut x = (i as u32).wrapping_mul(0x27d4_eb2d) ^ seed.wrapping_mul(0x1656_67b1);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x85eb_ca6b);
+    x ^= x >> 13;
+    x = x.wrapping_mul(0xc2b2_ae35);
+    x ^= x >> 16;
+    (x as f32 / u32::MAX as f32) * 2.0 - 1.0
+}
+
+/// Smooth 1D value noise in the range -1 to 1
+///
+/// # Arguments
+/// * `seed` - Seed selecting an independent noise channel
+/// * `t` - Position along the noise, one lattice cell per unit
+pub(crate) fn value_noise(seed: u32, t: f32) -> f32 {
+    let cell = t.floor();
+    let f = t - cell;
+    let a = hash(seed, cell as i32);
+    let b = hash(seed, cell as i32 + 1);
+    // smoothstep between lattice points
+    let s = f * f * (3.0 - 2.0 * f);
+    a + (b - a) * s
+}
+
+/// A single layer of [`CameraNoise`], swaying the camera at one frequency
+#[derive(Clone, Copy, Debug)]
+pub struct NoiseLayer {
+    /// Speed of the sway, in noise cells per second
+    pub frequency: f32,
+    /// Maximum translation offset in meters along each local axis
+    pub translation: Vec3,
+    /// Maximum rotation in radians for yaw, pitch and roll
+    pub rotation: Vec3,
+}
+
+impl NoiseLayer {
+    /// Creates a new NoiseLayer
+    ///
+    /// # Arguments
+    /// * `frequency` - Speed of the sway, in noise cells per second
+    /// * `translation` - Maximum translation offset in meters along each local axis
+    /// * `rotation` - Maximum rotation in radians for yaw, pitch and roll
+    pub fn new(frequency: f32, translation: Vec3, rotation: Vec3) -> Self {
+        Self {
+            frequency,
+            translation,
+            rotation,
+        }
+    }
+}
+
+/// A component that continuously sways a controller's camera with layered noise, such as
+/// a handheld camera, idle breathing or the rumble of a vehicle.
+///
+/// Unlike [`CameraShake`](super::CameraShake) the sway never decays. It is applied after
+/// the camera is solved and removed again before the next update, so it never feeds back
+/// into the controller's smoothing.
+#[derive(Component, Clone, Debug)]
+pub struct CameraNoise {
+    /// Layers summed into the sway
+    pub layers: Vec<NoiseLayer>,
+    /// Multiplier applied to every layer, such as to fade the sway in and out
+    pub gain: f32,
+    /// Time elapsed along the noise
+    time: f32,
+    /// Offset applied to the camera during the last update
+    applied: Option<(Vec3, Quat)>,
+}
+
+impl CameraNoise {
+    /// Creates a new CameraNoise from layers
+    ///
+    /// # Arguments
+    /// * `layers` - Layers summed into the sway
+    pub fn new(layers: impl Into<Vec<NoiseLayer>>) -> Self {
+        Self {
+            layers: layers.into(),
+            gain: 1.0,
+            time: 0.0,
+            applied: None,
+        }
+    }
+
+    /// Creates a new CameraNoise preset imitating a handheld camera, with a slow drift
+    /// and a faster jitter
+    pub fn handheld() -> Self {
+        Self::new([
+            NoiseLayer::new(
+                0.4,
+                Vec3::splat(0.01),
+                Vec3::new(1.0, 0.8, 0.5).map(f32::to_radians),
+            ),
+            NoiseLayer::new(
+                2.0,
+                Vec3::splat(0.003),
+                Vec3::new(0.3, 0.3, 0.2).map(f32::to_radians),
+            ),
+        ])
+    }
+
+    /// Creates a new CameraNoise preset imitating idle breathing, gently bobbing the
+    /// camera up and down
+    pub fn breathing() -> Self {
+        Self::new([NoiseLayer::new(
+            0.25,
+            Vec3::new(0.0, 0.015, 0.0),
+            Vec3::new(0.1, 0.4, 0.0).map(f32::to_radians),
+        )])
+    }
+
+    /// Creates a new CameraNoise preset imitating the rumble of a vehicle's engine
+    pub fn vehicle_rumble() -> Self {
+        Self::new([
+            NoiseLayer::new(
+                12.0,
+                Vec3::splat(0.004),
+                Vec3::new(0.15, 0.15, 0.1).map(f32::to_radians),
+            ),
+            NoiseLayer::new(
+                30.0,
+                Vec3::splat(0.002),
+                Vec3::new(0.05, 0.05, 0.05).map(f32::to_radians),
+            ),
+        ])
+    }
+
+    /// Adds a layer to the sway
+    ///
+    /// # Arguments
+    /// * `layer` - Layer to add
+    #[inline]
+    pub fn with_layer(mut self, layer: NoiseLayer) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    /// Sets the multiplier applied to every layer
+    ///
+    /// # Arguments
+    /// * `gain` - Multiplier of the sway
+    #[inline]
+    pub fn with_gain(mut self, gain: f32) -> Self {
+        self.gain = gain;
+        self
+    }
+
+    /// Samples the summed translation and yaw, pitch and roll angles of every layer
+    ///
+    /// # Arguments
+    /// * `time` - Time along the noise in seconds
+    fn sample(&self, time: f32) -> (Vec3, Vec3) {
+        let mut translation = Vec3::ZERO;
+        let mut angles = Vec3::ZERO;
+        for (index, layer) in self.layers.iter().enumerate() {
+            // every layer uses its own noise channels, apart from the ones used by shake
+            let seed = 16 + index as u32 * 6;
+            let t = time * layer.frequency;
+            let channels = |offset: u32| {
+                Vec3::new(
+                    value_noise(seed + offset, t),
+                    value_noise(seed + offset + 1, t),
+                    value_noise(seed + offset + 2, t),
+                )
+            };
+            translation += channels(0) * layer.translation;
+            angles += channels(3) * layer.rotation;
+        }
+        (translation * self.gain, angles * self.gain)
+    }
+}
+
+/// Removes the sway applied during the previous frame from controller cameras
+///
+/// # Arguments
+/// * `camera_controllers` - Query for controllers with noise
+/// * `camera_transforms` - Query for camera transforms to restore
+pub(crate) fn remove_noise(
+    mut camera_controllers: Query<(&CameraController, &mut CameraNoise)>,
+    mut camera_transforms: Query<&mut Transform, CameraFilter>,
+) {
+    for (controller, mut noise) in camera_controllers.iter_mut() {
+        let Some((translation, rotation)) = noise.applied.take() else {
+            continue;
+        };
+        if let Ok(mut camera_transform) = camera_transforms.get_mut(controller.camera) {
+            camera_transform.translation -= translation;
+            camera_transform.rotation *= rotation.inverse();
+        }
+    }
+}
+
+/// Applies sway on top of the solved controller cameras
+///
+/// # Arguments
+/// * `camera_controllers` - Query for controllers with noise
+/// * `camera_transforms` - Query for camera transforms to modify
+/// * `units` - Resource describing the scale of the world
+/// * `time` - Clocks providing frame timing for each controller's time source
+/// * `skipped` - Controllers skipped for missing entities, warned about once
+pub(crate) fn apply_noise(
+    mut camera_controllers: Query<(Entity, &CameraController, &mut CameraNoise)>,
+    mut camera_transforms: Query<&mut Transform, CameraFilter>,
+    units: Res<WorldUnits>,
+    time: CameraTime,
+    mut skipped: Local<SkippedEntities>,
+) {
+    for (entity, controller, mut noise) in camera_controllers.iter_mut() {
+        noise.time += time.delta_secs(controller.time_source);
+        if noise.gain == 0.0 || noise.layers.is_empty() {
+            continue;
+        }
+
+        let (translation, angles) = noise.sample(noise.time);
+        let translation = units.to_world(translation);
+        let rotation = Quat::from_euler(EulerRot::YXZ, angles.x, angles.y, angles.z);
+
+        let Some(mut camera_transform) =
+            skipped.check(entity, camera_transforms.get_mut(controller.camera))
+        else {
+            continue;
+        };
+        // translation is along the camera's local axes
+        let translation = camera_transform.rotation * translation;
+        camera_transform.translation += translation;
+        camera_transform.rotation *= rotation;
+        noise.applied = Some((translation, rotation));
+    }
+}
@@ -0,0 +1,271 @@
+use bevy::{
+    platform::{
+        collections::{HashMap, HashSet},
+        time::Instant,
+    },
+    prelude::*,
+};
+
+use super::{
+    CameraController, CameraTimings, CameraView,
+    collision::{self, CollisionQuery},
+    shake,
+    target::{self, CameraFilter, SkippedEntities, TargetFilter, TargetTransform},
+};
+
+#[cfg(feature = "occlusion_fade")]
+pub use fade::OcclusionFade;
+
+/// Maximum number of occluders detected between a camera and its target
+const MAX_OCCLUDERS: u32 = 16;
+
+/// Marker inserted on collider entities blocking the view between a controller's camera
+/// and its target, so games can fade or dither them. Removed once nothing is blocked.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct OccludingCamera;
+
+/// Event sent when an entity starts or stops blocking the view of a controller's camera
+#[derive(Event, Clone, Copy, Debug)]
+pub enum OcclusionEvent {
+    /// The occluder started blocking the view
+    Started {
+        controller: Entity,
+        occluder: Entity,
+    },
+    /// The occluder stopped blocking the view
+    Ended {
+        controller: Entity,
+        occluder: Entity,
+    },
+}
+
+/// Plugin detecting geometry between controller cameras and their targets each frame.
+///
+/// The view target is used for [`CameraView::Target`] controllers, otherwise the
+/// controller entity itself. Both are excluded from the ray cast.
+#[derive(Default)]
+pub struct CameraOcclusionPlugin;
+
+impl Plugin for CameraOcclusionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OcclusionEvent>().add_systems(
+            PostUpdate,
+            detect_occlusion
+                .after(shake::apply_shake)
+                .before(TransformSystem::TransformPropagate),
+        );
+
+        #[cfg(feature = "occlusion_fade")]
+        app.init_resource::<fade::OcclusionFade>()
+            .add_systems(PostUpdate, fade::fade_occluders.after(detect_occlusion));
+    }
+}
+
+/// Casts a ray from each controller's camera to its target, marking blocking entities
+/// and reporting changes with [`OcclusionEvent`]
+///
+/// # Arguments
+/// * `commands` - Commands for inserting and removing occluder markers
+/// * `camera_controllers` - Query for camera controllers
+/// * `camera_transforms` - Query for camera transforms to cast from
+/// * `target_transforms` - Query for target transforms to cast to
+/// * `markers` - Query for entities currently marked as occluders
+/// * `collision_query` - Scene queries used to find occluders
+/// * `occluded` - Occluders found per controller during the last update
+/// * `events` - Event writer for occlusion changes
+/// * `timings` - Optional resource collecting timings for diagnostics
+/// * `skipped` - Controllers skipped for missing entities, warned about once
+#[allow(clippy::too_many_arguments)]
+fn detect_occlusion(
+    mut commands: Commands,
+    camera_controllers: Query<(Entity, &CameraController)>,
+    camera_transforms: Query<&Transform, CameraFilter>,
+    target_transforms: Query<TargetTransform, TargetFilter>,
+    markers: Query<Entity, With<OccludingCamera>>,
+    collision_query: CollisionQuery,
+    mut occluded: Local<HashMap<Entity, HashSet<Entity>>>,
+    mut events: EventWriter<OcclusionEvent>,
+    mut timings: Option<ResMut<CameraTimings>>,
+    mut skipped: Local<SkippedEntities>,
+) {
+    let mut current = HashMap::<Entity, HashSet<Entity>>::default();
+
+    for (entity, controller) in camera_controllers.iter() {
+        let target = match controller.view {
+            CameraView::Target(target) => target,
+            CameraView::Free => entity,
+        };
+        let lookup = camera_transforms
+            .get(controller.camera)
+            .map_err(BevyError::from)
+            .and_then(|camera_transform| {
+                Ok((
+                    camera_transform,
+                    target::get_transform(&target_transforms, target)?.translation,
+                ))
+            });
+        let Some((camera_transform, target_translation)) = skipped.check(entity, lookup) else {
+            continue;
+        };
+
+        let offset = target_translation - camera_transform.translation;
+        let start = timings.is_some().then(Instant::now);
+        let occluders = match Dir3::new(offset) {
+            Ok(direction) => {
+                let filter = controller
+                    .view
+                    .collision_filter(entity, controller.collision_mask);
+                collision::ray_hits(
+                    &collision_query,
+                    &filter,
+                    camera_transform.translation,
+                    direction,
+                    offset.length(),
+                    MAX_OCCLUDERS,
+                )
+                .into_iter()
+                .collect()
+            }
+            Err(_) => HashSet::default(),
+        };
+        if let (Some(timings), Some(start)) = (timings.as_mut(), start) {
+            timings.record_collision(entity, start.elapsed());
+        }
+        current.insert(entity, occluders);
+    }
+
+    // report changes, including controllers that were removed
+    let empty = HashSet::default();
+    for (&controller, occluders) in current.iter() {
+        let previous = occluded.get(&controller).unwrap_or(&empty);
+        for &occluder in occluders.difference(previous) {
+            events.write(OcclusionEvent::Started {
+                controller,
+                occluder,
+            });
+        }
+    }
+    for (&controller, previous) in occluded.iter() {
+        let occluders = current.get(&controller).unwrap_or(&empty);
+        for &occluder in previous.difference(occluders) {
+            events.write(OcclusionEvent::Ended {
+                controller,
+                occluder,
+            });
+        }
+    }
+
+    // an entity stays marked while it blocks any controller
+    let blocking: HashSet<Entity> = current.values().flatten().copied().collect();
+    for marked in markers.iter().filter(|marked| !blocking.contains(marked)) {
+        commands.entity(marked).try_remove::<OccludingCamera>();
+    }
+    for &occluder in blocking
+        .iter()
+        .filter(|&&occluder| !markers.contains(occluder))
+    {
+        commands.entity(occluder).try_insert(OccludingCamera);
+    }
+
+    *occluded = current;
+}
+
+#[cfg(feature = "occlusion_fade")]
+mod fade {
+    use bevy::prelude::*;
+
+    use super::OccludingCamera;
+
+    /// Resource configuring the built-in fade of occluding `StandardMaterial` meshes
+    #[derive(Resource, Clone, Copy, Debug)]
+    pub struct OcclusionFade {
+        /// Alpha of occluders while they block the view
+        pub alpha: f32,
+        /// Smoothing factor of the fade, larger values give slower fades
+        pub smoothing: f32,
+    }
+
+    impl Default for OcclusionFade {
+        fn default() -> Self {
+            Self {
+                alpha: 0.25,
+                smoothing: 0.1,
+            }
+        }
+    }
+
+    /// Unique material swapped in while an occluder is faded
+    #[derive(Component)]
+    pub(crate) struct FadedMaterial {
+        /// Material to restore once the fade has recovered
+        original: Handle<StandardMaterial>,
+        /// Alpha of the original material
+        alpha: f32,
+    }
+
+    /// Fades the material of marked occluders and restores it once they are clear.
+    /// Faded meshes get their own copy of the material so shared materials are untouched.
+    ///
+    /// # Arguments
+    /// * `commands` - Commands for tracking faded materials
+    /// * `settings` - Resource configuring the fade
+    /// * `materials` - Material assets to fade
+    /// * `occluders` - Query for occluder meshes and their fade state
+    /// * `time` - Resource providing frame timing information
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn fade_occluders(
+        mut commands: Commands,
+        settings: Res<OcclusionFade>,
+        mut materials: ResMut<Assets<StandardMaterial>>,
+        mut occluders: Query<
+            (
+                Entity,
+                &mut MeshMaterial3d<StandardMaterial>,
+                Option<&FadedMaterial>,
+                Has<OccludingCamera>,
+            ),
+            Or<(With<OccludingCamera>, With<FadedMaterial>)>,
+        >,
+        time: Res<Time>,
+    ) {
+        let dt = time.delta_secs();
+
+        for (entity, mut material, faded, occluding) in occluders.iter_mut() {
+            let Some(faded) = faded else {
+                // swap in a unique copy before fading
+                let Some(original) = materials.get(&material.0) else {
+                    continue;
+                };
+                let alpha = original.base_color.alpha();
+                let copy = StandardMaterial {
+                    alpha_mode: AlphaMode::Blend,
+                    ..original.clone()
+                };
+                commands.entity(entity).insert(FadedMaterial {
+                    original: material.0.clone(),
+                    alpha,
+                });
+                material.0 = materials.add(copy);
+                continue;
+            };
+
+            let target = if occluding {
+                settings.alpha.min(faded.alpha)
+            } else {
+                faded.alpha
+            };
+            let Some(copy) = materials.get_mut(&material.0) else {
+                continue;
+            };
+            let mut alpha = copy.base_color.alpha();
+            alpha.smooth_nudge(&target, 1.0 / settings.smoothing, dt);
+            copy.base_color.set_alpha(alpha);
+
+            // restore the shared material once fully recovered
+            if !occluding && (alpha - target).abs() < 0.01 {
+                material.0 = faded.original.clone();
+                commands.entity(entity).remove::<FadedMaterial>();
+            }
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use bevy::prelude::*;
+
+use super::{CameraAnchor, CameraController, time::CameraTime};
+
+/// An asset holding a curve of offsets, allowing camera offsets to be authored as curves
+#[derive(Asset, TypePath)]
+pub struct OffsetCurve(Box<dyn Curve<Vec3> + Send + Sync>);
+
+impl OffsetCurve {
+    /// Creates a new OffsetCurve asset from any curve of offsets
+    ///
+    /// # Arguments
+    /// * `curve` - Curve to sample offsets from
+    pub fn new(curve: impl Curve<Vec3> + Send + Sync + 'static) -> Self {
+        Self(Box::new(curve))
+    }
+
+    /// Samples the curve at a normalized parameter mapped onto the curve's domain
+    ///
+    /// # Arguments
+    /// * `parameter` - Position along the curve in the range 0 to 1
+    pub fn sample_normalized(&self, parameter: f32) -> Vec3 {
+        let domain = self.0.domain();
+        let t = if domain.is_bounded() {
+            domain.start() + parameter.clamp(0.0, 1.0) * domain.length()
+        } else {
+            parameter
+        };
+        self.0.sample_clamped(t)
+    }
+}
+
+/// A component that drives a controller's offsets from [`OffsetCurve`] assets,
+/// sampled each frame at a normalized parameter set by the game (e.g. aim charge or speed)
+#[derive(Component, Clone, Default)]
+pub struct OffsetAnimation {
+    /// Curve replacing the controller's offset
+    pub offset: Option<Handle<OffsetCurve>>,
+    /// Curve replacing the controller's look offset
+    pub look_offset: Option<Handle<OffsetCurve>>,
+    /// Position along the curves in the range 0 to 1
+    pub parameter: f32,
+}
+
+impl OffsetAnimation {
+    /// Creates a new OffsetAnimation driving the controller's offset
+    ///
+    /// # Arguments
+    /// * `offset` - Curve replacing the controller's offset
+    pub fn new(offset: Handle<OffsetCurve>) -> Self {
+        Self {
+            offset: Some(offset),
+            ..default()
+        }
+    }
+
+    /// Sets the curve driving the controller's look offset
+    ///
+    /// # Arguments
+    /// * `look_offset` - Curve replacing the controller's look offset
+    #[inline]
+    pub fn with_look_offset(mut self, look_offset: Handle<OffsetCurve>) -> Self {
+        self.look_offset = Some(look_offset);
+        self
+    }
+
+    /// Sets the normalized parameter the curves are sampled at
+    ///
+    /// # Arguments
+    /// * `parameter` - Position along the curves in the range 0 to 1
+    #[inline]
+    pub fn set_parameter(&mut self, parameter: f32) {
+        self.parameter = parameter.clamp(0.0, 1.0);
+    }
+}
+
+/// Resolves the offset and look offset for a controller this frame, falling back
+/// to the controller's fields when no curve is set or the curve is not loaded
+pub(crate) fn resolve_offsets(
+    controller: &CameraController,
+    animation: Option<&OffsetAnimation>,
+    curves: Option<&Assets<OffsetCurve>>,
+) -> (Vec3, Vec3) {
+    let (Some(animation), Some(curves)) = (animation, curves) else {
+        return (controller.offset, controller.look_offset);
+    };
+    let sample = |handle: &Option<Handle<OffsetCurve>>| {
+        handle
+            .as_ref()
+            .and_then(|handle| curves.get(handle))
+            .map(|curve| curve.sample_normalized(animation.parameter))
+    };
+    (
+        sample(&animation.offset).unwrap_or(controller.offset),
+        sample(&animation.look_offset).unwrap_or(controller.look_offset),
+    )
+}
+
+/// A named set of framing values an [`OffsetBlend`] can blend a controller toward
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OffsetProfile {
+    /// Offset of the camera from the controller entity in meters
+    pub offset: Vec3,
+    /// Optional orbit distance in meters, leaving the distance unchanged if unset
+    pub distance: Option<f32>,
+    /// Optional field of view in radians, leaving the field of view unchanged if unset
+    pub fov: Option<f32>,
+}
+
+impl OffsetProfile {
+    /// Creates a new OffsetProfile changing only the offset
+    ///
+    /// # Arguments
+    /// * `offset` - Offset of the camera from the controller entity in meters
+    pub fn new(offset: Vec3) -> Self {
+        Self {
+            offset,
+            ..default()
+        }
+    }
+
+    /// Sets the orbit distance of the profile
+    ///
+    /// # Arguments
+    /// * `distance` - Orbit distance in meters
+    #[inline]
+    pub fn with_distance(mut self, distance: f32) -> Self {
+        self.distance = Some(distance);
+        self
+    }
+
+    /// Sets the field of view of the profile
+    ///
+    /// # Arguments
+    /// * `fov` - Field of view in radians
+    #[inline]
+    pub fn with_fov(mut self, fov: f32) -> Self {
+        self.fov = Some(fov);
+        self
+    }
+}
+
+/// A component that smoothly blends a controller's offset, orbit distance and field of view
+/// between named [`OffsetProfile`]s, such as hip fire and aim down sights framings.
+///
+/// The controller's values are only written while a blend is running, so zoom and other
+/// changes made afterwards are kept until the next profile switch.
+#[derive(Component, Clone)]
+pub struct OffsetBlend {
+    /// Profiles by name
+    profiles: Vec<(&'static str, OffsetProfile)>,
+    /// Name of the profile being blended toward or held
+    active: Option<&'static str>,
+    /// Duration of a blend in seconds
+    pub duration: f32,
+    /// Easing applied to blends
+    pub ease: EaseFunction,
+    /// Controller values captured when the current blend started
+    from: Option<OffsetProfile>,
+    /// Time elapsed since the current blend started
+    elapsed: f32,
+}
+
+impl OffsetBlend {
+    /// Creates a new OffsetBlend without profiles, blending with smooth step easing
+    ///
+    /// # Arguments
+    /// * `duration` - Duration of a blend in seconds
+    pub fn new(duration: f32) -> Self {
+        Self {
+            profiles: Vec::new(),
+            active: None,
+            duration,
+            ease: EaseFunction::SmoothStep,
+            from: None,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Adds a named profile, replacing any profile with the same name
+    ///
+    /// # Arguments
+    /// * `name` - Name identifying the profile
+    /// * `profile` - Framing values of the profile
+    #[inline]
+    pub fn with_profile(mut self, name: &'static str, profile: OffsetProfile) -> Self {
+        self.profiles.retain(|(existing, _)| *existing != name);
+        self.profiles.push((name, profile));
+        self
+    }
+
+    /// Sets the easing applied to blends
+    ///
+    /// # Arguments
+    /// * `ease` - Easing function
+    #[inline]
+    pub fn with_ease(mut self, ease: EaseFunction) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    /// Starts blending toward a named profile from the controller's current values,
+    /// doing nothing if the profile is unknown or already active
+    ///
+    /// # Arguments
+    /// * `name` - Name of the profile to blend toward
+    pub fn set_profile(&mut self, name: &'static str) {
+        if self.active == Some(name) || self.profile(name).is_none() {
+            return;
+        }
+        self.active = Some(name);
+        self.from = None;
+        self.elapsed = 0.0;
+    }
+
+    /// Returns the name of the profile being blended toward or held
+    #[inline]
+    pub fn active_profile(&self) -> Option<&'static str> {
+        self.active
+    }
+
+    /// Returns a profile by name
+    ///
+    /// # Arguments
+    /// * `name` - Name of the profile
+    pub fn profile(&self, name: &str) -> Option<&OffsetProfile> {
+        self.profiles
+            .iter()
+            .find(|(existing, _)| *existing == name)
+            .map(|(_, profile)| profile)
+    }
+
+    /// Checks whether a blend is running
+    #[inline]
+    pub fn is_blending(&self) -> bool {
+        self.active.is_some() && self.elapsed < self.duration
+    }
+}
+
+/// Blends controller offsets, orbit distances and fields of view toward their active profiles
+///
+/// # Arguments
+/// * `camera_controllers` - Query for controllers with offset blends
+/// * `time` - Clocks providing frame timing for each controller's time source
+pub(crate) fn update_offset_blends(
+    mut camera_controllers: Query<(&mut CameraController, &mut OffsetBlend)>,
+    time: CameraTime,
+) {
+    for (mut controller, mut blend) in camera_controllers.iter_mut() {
+        if !blend.is_blending() {
+            continue;
+        }
+        let Some(target) = blend.active.and_then(|name| blend.profile(name)).copied() else {
+            continue;
+        };
+        // capture where the blend starts on its first update
+        let distance = match controller.anchor {
+            CameraAnchor::Orbit { distance } | CameraAnchor::Isometric { distance, .. } => {
+                Some(distance)
+            }
+            _ => None,
+        };
+        let from = *blend.from.get_or_insert(OffsetProfile {
+            offset: controller.offset,
+            distance,
+            fov: controller.fov,
+        });
+
+        blend.elapsed += time.delta_secs(controller.time_source);
+        let t = if blend.duration > 0.0 {
+            blend.elapsed / blend.duration
+        } else {
+            1.0
+        };
+        let weight = blend.ease.sample_clamped(t);
+
+        controller.offset = from.offset.lerp(target.offset, weight);
+        if let (Some(from_distance), Some(to_distance)) = (from.distance, target.distance) {
+            let blended = from_distance.lerp(to_distance, weight);
+            if let CameraAnchor::Orbit { distance } | CameraAnchor::Isometric { distance, .. } =
+                &mut controller.anchor
+            {
+                *distance = blended;
+            }
+        }
+        if let Some(to_fov) = target.fov {
+            controller.fov = Some(from.fov.map_or(to_fov, |fov| fov.lerp(to_fov, weight)));
+        }
+    }
+}
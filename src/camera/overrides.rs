@@ -0,0 +1,162 @@
+use bevy::prelude::*;
+
+use super::{
+    CameraBuffer, CameraController,
+    target::{self, CameraFilter, SkippedEntities, TargetFilter, TargetTransform},
+    time::CameraTime,
+};
+
+/// What a [`CameraOverride`] points the camera at
+#[derive(Clone, Copy)]
+pub enum OverrideGoal {
+    /// Moves the camera to a fixed world space transform
+    Transform(Transform),
+    /// Keeps the camera in place while looking at an entity
+    Target(Entity),
+}
+
+/// A component that temporarily takes control of a controller's camera,
+/// blending in, holding for a duration, and blending back out before removing itself.
+/// Buffered input is discarded while the override is active, so the controller
+/// resumes from the view it had before the override.
+#[derive(Component, Clone)]
+pub struct CameraOverride {
+    /// Transform or target to point the camera at
+    pub transform_or_target: OverrideGoal,
+    /// Time in seconds to hold the override after blending in
+    pub duration: f32,
+    /// Time in seconds to blend from the controller to the override
+    pub blend_in: f32,
+    /// Time in seconds to blend from the override back to the controller
+    pub blend_out: f32,
+    /// Time elapsed since the override started
+    elapsed: f32,
+}
+
+impl CameraOverride {
+    /// Creates a new CameraOverride without blending
+    ///
+    /// # Arguments
+    /// * `transform_or_target` - Transform or target to point the camera at
+    /// * `duration` - Time in seconds to hold the override
+    pub fn new(transform_or_target: OverrideGoal, duration: f32) -> Self {
+        Self {
+            transform_or_target,
+            duration,
+            blend_in: 0.0,
+            blend_out: 0.0,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Creates a new CameraOverride looking at an entity
+    ///
+    /// # Arguments
+    /// * `target` - Entity to look at
+    /// * `duration` - Time in seconds to hold the override
+    pub fn look_at(target: Entity, duration: f32) -> Self {
+        Self::new(OverrideGoal::Target(target), duration)
+    }
+
+    /// Sets the blend in and blend out times
+    ///
+    /// # Arguments
+    /// * `blend_in` - Time in seconds to blend to the override
+    /// * `blend_out` - Time in seconds to blend back to the controller
+    #[inline]
+    pub fn with_blend(mut self, blend_in: f32, blend_out: f32) -> Self {
+        self.blend_in = blend_in;
+        self.blend_out = blend_out;
+        self
+    }
+
+    /// Returns the total time the override is active for
+    #[inline]
+    pub fn total_duration(&self) -> f32 {
+        self.blend_in + self.duration + self.blend_out
+    }
+
+    /// Returns whether the override has finished
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.total_duration()
+    }
+
+    /// Returns the current weight of the override, from 0 (controller) to 1 (override)
+    pub fn weight(&self) -> f32 {
+        let hold_end = self.blend_in + self.duration;
+        let weight = if self.elapsed < self.blend_in {
+            self.elapsed / self.blend_in
+        } else if self.elapsed <= hold_end {
+            1.0
+        } else if self.blend_out > 0.0 {
+            1.0 - (self.elapsed - hold_end) / self.blend_out
+        } else {
+            0.0
+        };
+        let weight = weight.clamp(0.0, 1.0);
+        // ease in and out of the blend
+        weight * weight * (3.0 - 2.0 * weight)
+    }
+}
+
+/// Discards input buffered for controllers with an active override
+///
+/// # Arguments
+/// * `camera_controllers` - Query for buffers of overridden controllers
+pub(crate) fn suppress_input(
+    mut camera_controllers: Query<&mut CameraBuffer, With<CameraOverride>>,
+) {
+    for mut buffer in camera_controllers.iter_mut() {
+        buffer.clear();
+    }
+}
+
+/// Blends overridden cameras between their solved pose and the override goal,
+/// removing overrides once they have finished
+///
+/// # Arguments
+/// * `commands` - Commands for removing finished overrides
+/// * `camera_controllers` - Query for controllers with an override
+/// * `camera_transforms` - Query for solved camera transforms to modify
+/// * `target_transforms` - Query for target transforms for override targets
+/// * `time` - Clocks providing frame timing for each controller's time source
+/// * `skipped` - Controllers skipped for missing entities, warned about once
+pub(crate) fn apply_overrides(
+    mut commands: Commands,
+    mut camera_controllers: Query<(Entity, &CameraController, &mut CameraOverride)>,
+    mut camera_transforms: Query<&mut Transform, CameraFilter>,
+    target_transforms: Query<TargetTransform, TargetFilter>,
+    time: CameraTime,
+    mut skipped: Local<SkippedEntities>,
+) {
+    for (entity, controller, mut camera_override) in camera_controllers.iter_mut() {
+        camera_override.elapsed += time.delta_secs(controller.time_source);
+        if camera_override.is_finished() {
+            commands.entity(entity).remove::<CameraOverride>();
+            continue;
+        }
+
+        let lookup = camera_transforms
+            .get_mut(controller.camera)
+            .map_err(BevyError::from)
+            .and_then(|camera_transform| {
+                let goal = match camera_override.transform_or_target {
+                    OverrideGoal::Transform(transform) => transform,
+                    OverrideGoal::Target(target) => {
+                        let target_transform = target::get_transform(&target_transforms, target)?;
+                        camera_transform
+                            .looking_at(target_transform.translation, controller.yaw_axis)
+                    }
+                };
+                Ok((camera_transform, goal))
+            });
+        let Some((mut camera_transform, goal)) = skipped.check(entity, lookup) else {
+            continue;
+        };
+
+        let weight = camera_override.weight();
+        camera_transform.translation = camera_transform.translation.lerp(goal.translation, weight);
+        camera_transform.rotation = camera_transform.rotation.slerp(goal.rotation, weight);
+    }
+}
@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+
+use super::{CameraController2d, target::SkippedEntities};
+
+/// A background or foreground layer scrolling at a fraction of a 2D camera's movement.
+///
+/// The layer's authored translation is where it sits while the camera is at the origin.
+/// A factor of 1 moves the layer with the world, 0 keeps it fixed on screen, and values
+/// between give the depth of distant backgrounds. Values above 1 suit close foregrounds.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Transform)]
+pub struct ParallaxLayer {
+    /// Horizontal and vertical fraction of the camera's movement the layer scrolls by
+    pub factor: Vec2,
+    /// Camera the layer scrolls against, or the camera of the only 2D controller if unset
+    pub camera: Option<Entity>,
+    /// Authored translation of the layer, captured on the first update
+    origin: Option<Vec2>,
+}
+
+impl ParallaxLayer {
+    /// Creates a new ParallaxLayer scrolling against the camera of the only 2D controller
+    ///
+    /// # Arguments
+    /// * `factor` - Horizontal and vertical scroll speed relative to the world
+    pub fn new(factor: Vec2) -> Self {
+        Self {
+            factor,
+            camera: None,
+            origin: None,
+        }
+    }
+
+    /// Sets the camera the layer scrolls against
+    ///
+    /// # Arguments
+    /// * `camera` - Entity ID of the camera
+    #[inline]
+    pub fn with_camera(mut self, camera: Entity) -> Self {
+        self.camera = Some(camera);
+        self
+    }
+}
+
+/// Offsets parallax layers against the solved translation of their camera
+///
+/// # Arguments
+/// * `layers` - Query for parallax layers and their transforms
+/// * `camera_controllers` - Query for 2D controllers, used to find the default camera
+/// * `cameras` - Query for camera transforms
+/// * `skipped` - Layers skipped for missing cameras, warned about once
+pub(crate) fn update_parallax_layers(
+    mut layers: Query<(Entity, &mut ParallaxLayer, &mut Transform), Without<Camera>>,
+    camera_controllers: Query<&CameraController2d>,
+    cameras: Query<&Transform, With<Camera>>,
+    mut skipped: Local<SkippedEntities>,
+) {
+    if layers.is_empty() {
+        return;
+    }
+    let default_camera = camera_controllers
+        .single()
+        .ok()
+        .map(|controller| controller.camera);
+
+    for (entity, mut layer, mut transform) in layers.iter_mut() {
+        let Some(camera) = layer.camera.or(default_camera) else {
+            continue;
+        };
+        let Some(camera_transform) = skipped.check(entity, cameras.get(camera)) else {
+            continue;
+        };
+        let camera_translation = camera_transform.translation.truncate();
+        let origin = *layer.origin.get_or_insert(transform.translation.truncate());
+
+        // the layer falls behind the camera by the part of its movement it does not follow
+        let translation = origin + camera_translation * (Vec2::ONE - layer.factor);
+        if translation != transform.translation.truncate() {
+            transform.translation = translation.extend(transform.translation.z);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+
+use super::{
+    CameraBuffer, CameraController, CameraController2d, CameraTimeSource, time::CameraTime,
+};
+
+/// What happens to input buffered for a paused controller
+#[derive(Default, Clone, Copy, Debug, PartialEq, Reflect)]
+pub enum PausedInput {
+    /// Input keeps accumulating and is applied at once when resumed
+    Keep,
+    /// Input fades out at the given rate, so only recent input is applied when resumed
+    Decay(f32),
+    /// Input is dropped so the camera does not jump when resumed
+    #[default]
+    Discard,
+}
+
+/// A component pausing the controller on the same entity, such as during menus, cutscenes
+/// or death screens. The camera is left where it is without changing any controller
+/// settings, and removing the component resumes it.
+///
+/// Respected by [`CameraController`](super::CameraController),
+/// [`CameraController2d`](super::CameraController2d),
+/// [`FlightController`](super::FlightController) and, on camera entities,
+/// [`FlyCameraController`](super::FlyCameraController) and
+/// [`RtsCameraController`](super::RtsCameraController).
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct CameraControllerPaused {
+    /// What happens to input buffered while paused
+    pub input: PausedInput,
+}
+
+impl CameraControllerPaused {
+    /// Creates a new CameraControllerPaused keeping buffered input, which is applied at once
+    /// when resumed
+    pub fn keep_input() -> Self {
+        Self {
+            input: PausedInput::Keep,
+        }
+    }
+
+    /// Creates a new CameraControllerPaused fading buffered input out
+    ///
+    /// # Arguments
+    /// * `rate` - Rate at which input decays, larger values fade sooner
+    pub fn decay_input(rate: f32) -> Self {
+        Self {
+            input: PausedInput::Decay(rate),
+        }
+    }
+
+    /// Creates a new CameraControllerPaused discarding buffered input
+    pub fn discard_input() -> Self {
+        Self {
+            input: PausedInput::Discard,
+        }
+    }
+}
+
+/// Keeps, fades or discards input buffered for paused controllers, still dropping input
+/// older than each buffer's max age
+///
+/// # Arguments
+/// * `buffers` - Query for paused controllers and their buffers
+/// * `time` - Clocks providing frame timing for each controller's time source
+#[allow(clippy::type_complexity)]
+pub(crate) fn hold_paused_input(
+    mut buffers: Query<(
+        &CameraControllerPaused,
+        &mut CameraBuffer,
+        Option<&CameraController>,
+        Option<&CameraController2d>,
+    )>,
+    time: CameraTime,
+) {
+    for (paused, mut buffer, controller, controller2d) in buffers.iter_mut() {
+        let dt = time.delta_secs(CameraTimeSource::of(controller, controller2d));
+        buffer.expire(dt);
+        match paused.input {
+            PausedInput::Keep => (),
+            PausedInput::Decay(rate) => buffer.fade(rate, dt),
+            PausedInput::Discard => buffer.clear(),
+        }
+    }
+}
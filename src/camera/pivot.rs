@@ -0,0 +1,148 @@
+use avian3d::prelude::*;
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use super::{
+    CameraAnchor, CameraController, CameraControllerPaused, CameraPlayer, InputSource,
+    InputSources, WorldUnits,
+};
+
+/// A component on a controller entity with the [`Orbit`](CameraAnchor::Orbit) anchor that
+/// re-centers the orbit on whatever is clicked, like orbiting around a clicked object in
+/// modelling tools and editors.
+///
+/// A ray is cast under the cursor against the controller's collision layers, and the pivot
+/// moves to the hit point with [`CameraController::set_orbit_pivot`]. The orbit distance is
+/// set to the camera's distance from the hit, so the camera slides to center the clicked
+/// point without zooming.
+#[derive(Component, Clone, Debug)]
+pub struct PivotPicking {
+    /// Clicked to pick a new pivot
+    pub button: MouseButton,
+    /// Furthest distance in meters a pivot can be picked at
+    pub max_distance: f32,
+    /// Duration in seconds of the blend to the new pivot
+    pub blend: f32,
+}
+
+impl Default for PivotPicking {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PivotPicking {
+    /// Creates a new PivotPicking with default settings:
+    /// - Middle mouse button to pick
+    /// - Max distance: 1000.0 meters
+    /// - Blend: 0.3 seconds
+    pub fn new() -> Self {
+        Self {
+            button: MouseButton::Middle,
+            max_distance: 1000.0,
+            blend: 0.3,
+        }
+    }
+
+    /// Sets the button clicked to pick a new pivot
+    ///
+    /// # Arguments
+    /// * `button` - Mouse button
+    #[inline]
+    pub fn with_button(mut self, button: MouseButton) -> Self {
+        self.button = button;
+        self
+    }
+
+    /// Sets the furthest distance a pivot can be picked at
+    ///
+    /// # Arguments
+    /// * `max_distance` - Distance in meters
+    #[inline]
+    pub fn with_max_distance(mut self, max_distance: f32) -> Self {
+        self.max_distance = max_distance;
+        self
+    }
+
+    /// Sets the duration of the blend to a new pivot
+    ///
+    /// # Arguments
+    /// * `blend` - Duration in seconds
+    #[inline]
+    pub fn with_blend(mut self, blend: f32) -> Self {
+        self.blend = blend;
+        self
+    }
+}
+
+/// Casts rays under the cursor for controllers with pivot picking, moving their orbit
+/// pivot to the clicked point
+///
+/// # Arguments
+/// * `camera_controllers` - Query for controllers with pivot picking and their input routing
+/// * `cameras` - Query for cameras and their global transforms
+/// * `children` - Query for children excluded from the ray cast
+/// * `windows` - Query for the primary window, used for the cursor position
+/// * `buttons` - Mouse button input, if the input plugin is present
+/// * `spatial_query` - Spatial query for the ray cast
+/// * `units` - Resource describing the scale of the world
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(crate) fn pick_orbit_pivots(
+    mut camera_controllers: Query<
+        (
+            Entity,
+            &mut CameraController,
+            &PivotPicking,
+            Option<&CameraPlayer>,
+            Option<&InputSources>,
+        ),
+        Without<CameraControllerPaused>,
+    >,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    children: Query<&Children>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    buttons: Option<Res<ButtonInput<MouseButton>>>,
+    spatial_query: SpatialQuery,
+    units: Res<WorldUnits>,
+) {
+    let (Some(buttons), Some(cursor)) = (
+        buttons,
+        windows.single().ok().and_then(Window::cursor_position),
+    ) else {
+        return;
+    };
+
+    for (entity, mut controller, picking, player, sources) in camera_controllers.iter_mut() {
+        if !buttons.just_pressed(picking.button)
+            || !controller.enabled
+            || !matches!(controller.anchor, CameraAnchor::Orbit { .. })
+            || !InputSources::routes(sources, player, InputSource::Mouse)
+        {
+            continue;
+        }
+        let Ok((camera, camera_transform)) = cameras.get(controller.camera) else {
+            continue;
+        };
+        let Ok(ray) = camera.viewport_to_world(camera_transform, cursor) else {
+            continue;
+        };
+        let filter = controller.collision_filter(entity, &children);
+        let Some(hit) = spatial_query.cast_ray(
+            ray.origin,
+            ray.direction,
+            units.to_world(picking.max_distance),
+            true,
+            &filter,
+        ) else {
+            continue;
+        };
+
+        // keep the camera at its current distance from the picked point
+        let point = ray.get_point(hit.distance);
+        let mut distance = units.to_meters(camera_transform.translation().distance(point));
+        if let Some((min, max)) = controller.distance_limits {
+            distance = distance.clamp(min, max);
+        }
+        controller.anchor = CameraAnchor::Orbit { distance };
+        controller.set_orbit_pivot(point, picking.blend);
+    }
+}
@@ -0,0 +1,385 @@
+use bevy::prelude::*;
+
+/// Z index of letterbox bars, drawn over regular UI
+const LETTERBOX_Z_INDEX: i32 = i32::MAX - 2;
+
+/// Z index of fade overlays, drawn over letterbox bars
+const FADE_Z_INDEX: i32 = i32::MAX - 1;
+
+/// A component on a camera entity fading its view to or from a solid color.
+///
+/// A fade covers the screen, optionally holds it covered and then uncovers it again.
+/// [`CameraFadeCovered`] is triggered on the camera once the screen is fully covered, which
+/// is the moment to cut between cameras, teleport targets or snap a
+/// [`CameraController`](super::CameraController) without the jump being visible.
+/// [`CameraFadeFinished`] is triggered once the screen is uncovered, after which the
+/// component is removed. Fades that only cover the screen stay until removed or replaced.
+#[derive(Component, Clone, Debug)]
+pub struct CameraFade {
+    /// Color the view fades to
+    pub color: Color,
+    /// Easing applied to the fade in both directions
+    pub ease: EaseFunction,
+    /// Seconds spent covering the screen
+    fade_out: f32,
+    /// Seconds the screen is held fully covered
+    hold: f32,
+    /// Seconds spent uncovering the screen, or `None` to stay covered
+    fade_in: Option<f32>,
+    /// Seconds since the fade started
+    elapsed: f32,
+    /// Whether [`CameraFadeCovered`] was triggered
+    covered: bool,
+    /// Overlay node drawing the fade
+    overlay: Option<Entity>,
+}
+
+impl CameraFade {
+    /// Creates a new CameraFade covering the screen, then holding and uncovering it, such as
+    /// to mask a cut
+    ///
+    /// # Arguments
+    /// * `color` - Color the view fades to
+    /// * `fade_out` - Seconds spent covering the screen
+    /// * `hold` - Seconds the screen is held fully covered
+    /// * `fade_in` - Seconds spent uncovering the screen
+    pub fn cut(color: Color, fade_out: f32, hold: f32, fade_in: f32) -> Self {
+        Self {
+            color,
+            ease: EaseFunction::Linear,
+            fade_out: fade_out.max(0.0),
+            hold: hold.max(0.0),
+            fade_in: Some(fade_in.max(0.0)),
+            elapsed: 0.0,
+            covered: false,
+            overlay: None,
+        }
+    }
+
+    /// Creates a new CameraFade covering the screen and keeping it covered
+    ///
+    /// # Arguments
+    /// * `color` - Color the view fades to
+    /// * `duration` - Seconds spent covering the screen
+    pub fn fade_out(color: Color, duration: f32) -> Self {
+        Self {
+            fade_in: None,
+            ..Self::cut(color, duration, 0.0, 0.0)
+        }
+    }
+
+    /// Creates a new CameraFade starting fully covered and uncovering the screen
+    ///
+    /// # Arguments
+    /// * `color` - Color the view fades from
+    /// * `duration` - Seconds spent uncovering the screen
+    pub fn fade_in(color: Color, duration: f32) -> Self {
+        Self::cut(color, 0.0, 0.0, duration)
+    }
+
+    /// Sets the easing applied to the fade
+    ///
+    /// # Arguments
+    /// * `ease` - Easing function
+    #[inline]
+    pub fn with_ease(mut self, ease: EaseFunction) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    /// Returns how much of the screen is covered, from 0 to 1
+    pub fn alpha(&self) -> f32 {
+        let curve = EasingCurve::new(0.0, 1.0, self.ease);
+        let progress = |elapsed: f32, duration: f32| {
+            if duration > 0.0 {
+                curve.sample_clamped(elapsed / duration)
+            } else {
+                1.0
+            }
+        };
+
+        let uncovering = self.elapsed - self.fade_out - self.hold;
+        if self.elapsed < self.fade_out {
+            progress(self.elapsed, self.fade_out)
+        } else if let Some(fade_in) = self.fade_in
+            && uncovering >= 0.0
+        {
+            1.0 - progress(uncovering, fade_in)
+        } else {
+            1.0
+        }
+    }
+
+    /// Checks whether the screen is fully covered
+    #[inline]
+    pub fn is_covered(&self) -> bool {
+        self.covered && self.alpha() >= 1.0
+    }
+
+    /// Checks whether the screen has been uncovered again
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.fade_in
+            .is_some_and(|fade_in| self.elapsed >= self.fade_out + self.hold + fade_in)
+    }
+}
+
+/// Triggered on a camera entity when its [`CameraFade`] fully covers the screen
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CameraFadeCovered;
+
+/// Triggered on a camera entity when its [`CameraFade`] has uncovered the screen again
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CameraFadeFinished;
+
+/// A component on a camera entity sliding cinematic bars over the top and bottom of its view
+#[derive(Component, Clone, Debug)]
+pub struct Letterbox {
+    /// Fraction of the view height covered by each bar when shown
+    pub size: f32,
+    /// Color of the bars
+    pub color: Color,
+    /// Seconds the bars take to slide in or out
+    pub duration: f32,
+    /// Whether the bars are shown
+    pub visible: bool,
+    /// How far the bars have slid in, from 0 to 1
+    amount: f32,
+    /// Bar nodes at the top and bottom of the view
+    bars: Option<[Entity; 2]>,
+}
+
+impl Default for Letterbox {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Letterbox {
+    /// Creates a new shown Letterbox with default settings:
+    /// - Size: 0.12 of the view height per bar
+    /// - Color: black
+    /// - Duration: 0.5 seconds
+    pub fn new() -> Self {
+        Self {
+            size: 0.12,
+            color: Color::BLACK,
+            duration: 0.5,
+            visible: true,
+            amount: 0.0,
+            bars: None,
+        }
+    }
+
+    /// Sets the fraction of the view height covered by each bar
+    ///
+    /// # Arguments
+    /// * `size` - Fraction of the view height
+    #[inline]
+    pub fn with_size(mut self, size: f32) -> Self {
+        self.size = size.clamp(0.0, 0.5);
+        self
+    }
+
+    /// Sets the color of the bars
+    ///
+    /// # Arguments
+    /// * `color` - Color of the bars
+    #[inline]
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Sets the time the bars take to slide in or out
+    ///
+    /// # Arguments
+    /// * `duration` - Duration in seconds
+    #[inline]
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Slides the bars in
+    #[inline]
+    pub fn show(&mut self) {
+        self.visible = true;
+    }
+
+    /// Slides the bars out
+    #[inline]
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    /// Returns how far the bars have slid in, from 0 to 1
+    #[inline]
+    pub fn amount(&self) -> f32 {
+        self.amount
+    }
+}
+
+/// Marks UI nodes drawn over a camera's view for fades and letterboxing
+#[derive(Component)]
+pub(crate) struct PresentationOverlay {
+    /// Camera the node is drawn over
+    camera: Entity,
+}
+
+/// Spawns a UI node covering part of a camera's view
+///
+/// # Arguments
+/// * `commands` - Commands for spawning the node
+/// * `camera` - Camera the node is drawn over
+/// * `node` - Layout of the node
+/// * `z_index` - Global z index of the node
+fn spawn_overlay(commands: &mut Commands, camera: Entity, node: Node, z_index: i32) -> Entity {
+    commands
+        .spawn((
+            node,
+            BackgroundColor(Color::NONE),
+            GlobalZIndex(z_index),
+            UiTargetCamera(camera),
+            Pickable::IGNORE,
+            PresentationOverlay { camera },
+        ))
+        .id()
+}
+
+/// Advances camera fades, drawing their overlays and triggering events as they cover and
+/// uncover the screen
+///
+/// # Arguments
+/// * `commands` - Commands for spawning overlays and triggering events
+/// * `fades` - Query for camera fades
+/// * `colors` - Query for overlay colors
+/// * `time` - Resource providing real frame timing, so fades run while the game is paused
+pub(crate) fn update_camera_fades(
+    mut commands: Commands,
+    mut fades: Query<(Entity, &mut CameraFade)>,
+    mut colors: Query<&mut BackgroundColor, With<PresentationOverlay>>,
+    time: Res<Time<Real>>,
+) {
+    for (entity, mut fade) in fades.iter_mut() {
+        let overlay = match fade.overlay {
+            Some(overlay) => overlay,
+            None => {
+                let node = Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                };
+                let overlay = spawn_overlay(&mut commands, entity, node, FADE_Z_INDEX);
+                fade.overlay = Some(overlay);
+                overlay
+            }
+        };
+
+        fade.elapsed += time.delta_secs();
+        let alpha = fade.alpha();
+
+        if !fade.covered && fade.elapsed >= fade.fade_out {
+            fade.covered = true;
+            commands.trigger_targets(CameraFadeCovered, entity);
+        }
+        if fade.is_finished() {
+            commands.entity(overlay).despawn();
+            commands.entity(entity).remove::<CameraFade>();
+            commands.trigger_targets(CameraFadeFinished, entity);
+            continue;
+        }
+
+        let color = fade.color.with_alpha(fade.color.alpha() * alpha);
+        match colors.get_mut(overlay) {
+            Ok(mut background) => background.0 = color,
+            // the overlay is spawned this frame
+            Err(_) => {
+                commands.entity(overlay).insert(BackgroundColor(color));
+            }
+        }
+    }
+}
+
+/// Slides letterbox bars in and out
+///
+/// # Arguments
+/// * `commands` - Commands for spawning bars
+/// * `letterboxes` - Query for letterboxes
+/// * `nodes` - Query for bar layouts and colors
+/// * `time` - Resource providing real frame timing, so bars move while the game is paused
+pub(crate) fn update_letterboxes(
+    mut commands: Commands,
+    mut letterboxes: Query<(Entity, &mut Letterbox)>,
+    mut nodes: Query<(&mut Node, &mut BackgroundColor), With<PresentationOverlay>>,
+    time: Res<Time<Real>>,
+) {
+    for (entity, mut letterbox) in letterboxes.iter_mut() {
+        let bars = match letterbox.bars {
+            Some(bars) => bars,
+            None => {
+                let bar = |top: bool| Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(0.0),
+                    top: if top { Val::Px(0.0) } else { Val::Auto },
+                    bottom: if top { Val::Auto } else { Val::Px(0.0) },
+                    ..default()
+                };
+                let bars = [
+                    spawn_overlay(&mut commands, entity, bar(true), LETTERBOX_Z_INDEX),
+                    spawn_overlay(&mut commands, entity, bar(false), LETTERBOX_Z_INDEX),
+                ];
+                letterbox.bars = Some(bars);
+                bars
+            }
+        };
+
+        let goal = if letterbox.visible { 1.0 } else { 0.0 };
+        let amount = if letterbox.duration > 0.0 {
+            let step = time.delta_secs() / letterbox.duration;
+            letterbox.amount + (goal - letterbox.amount).clamp(-step, step)
+        } else {
+            goal
+        };
+        if letterbox.amount != amount {
+            letterbox.amount = amount;
+        }
+
+        let height = Val::Percent(letterbox.size * amount * 100.0);
+        for bar in bars {
+            if let Ok((mut node, mut background)) = nodes.get_mut(bar) {
+                node.height = height;
+                background.0 = letterbox.color;
+            }
+        }
+    }
+}
+
+/// Despawns fade overlays and letterbox bars whose camera no longer has the component
+/// drawing them
+///
+/// # Arguments
+/// * `commands` - Commands for despawning overlays
+/// * `overlays` - Query for overlay nodes
+/// * `fades` - Query for camera fades
+/// * `letterboxes` - Query for letterboxes
+pub(crate) fn cleanup_overlays(
+    mut commands: Commands,
+    overlays: Query<(Entity, &PresentationOverlay)>,
+    fades: Query<&CameraFade>,
+    letterboxes: Query<&Letterbox>,
+) {
+    for (entity, overlay) in overlays.iter() {
+        let in_use = fades
+            .get(overlay.camera)
+            .is_ok_and(|fade| fade.overlay == Some(entity))
+            || letterboxes
+                .get(overlay.camera)
+                .is_ok_and(|letterbox| letterbox.bars.is_some_and(|bars| bars.contains(&entity)));
+        if !in_use {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    platform::collections::HashSet,
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{CameraAnchor, CameraController, Smoothing};
+
+/// An asset capturing the tuning of a [`CameraController`], loaded from `.camera.ron` files.
+///
+/// Every field is optional, so a profile only overrides the settings it lists. Attach it to a
+/// controller with [`CameraProfileHandle`], and the profile is applied again whenever the file
+/// changes while Bevy's `file_watcher` feature is enabled, so camera feel can be iterated
+/// without recompiling.
+///
+/// ```ron
+/// (
+///     sensitivity: Some((0.8, 0.6)),
+///     rotation_smoothing: Some((Exponential(decay: 20.0), Exponential(decay: 20.0))),
+///     offset: Some((0.5, 1.6, 0.0)),
+///     pitch_limits: Some((-1.2, 0.6)),
+///     anchor: Some(Orbit(distance: 4.0)),
+/// )
+/// ```
+#[derive(Asset, TypePath, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CameraProfile {
+    /// Sensitivity of horizontal and vertical input
+    pub sensitivity: Option<Vec2>,
+    /// Horizontal and vertical smoothing of translation
+    pub translation_smoothing: Option<[Smoothing; 2]>,
+    /// Yaw and pitch smoothing of rotation
+    pub rotation_smoothing: Option<[Smoothing; 2]>,
+    /// Sensitivity of the zoom channel
+    pub zoom_sensitivity: Option<f32>,
+    /// Smoothing of zoom
+    pub zoom_smoothing: Option<Smoothing>,
+    /// Offset from the controller entity in meters, in the controller's local space
+    pub offset: Option<Vec3>,
+    /// Offset of the look point from the view target, in the target's local space
+    pub look_offset: Option<Vec3>,
+    /// Minimum and maximum orbit distance reachable by zooming
+    pub distance_limits: Option<(f32, f32)>,
+    /// Base vertical field of view in radians
+    pub fov: Option<f32>,
+    /// Minimum and maximum pitch angle in radians, negative values look down
+    pub pitch_limits: Option<(f32, f32)>,
+    /// Sensitivity of the roll channel
+    pub roll_sensitivity: Option<f32>,
+    /// Minimum and maximum roll angle in radians
+    pub roll_limits: Option<(f32, f32)>,
+    /// Minimum and maximum yaw angle in radians relative to the yaw reference
+    pub yaw_range: Option<(f32, f32)>,
+    /// Anchor and its parameters, such as the orbit distance. Rail anchors refer to
+    /// entities and cannot be stored in a profile.
+    pub anchor: Option<CameraAnchor>,
+}
+
+impl CameraProfile {
+    /// Captures the tuning of a controller, such as to save a profile tuned at runtime
+    ///
+    /// # Arguments
+    /// * `controller` - Controller to capture
+    pub fn from_controller(controller: &CameraController) -> Self {
+        Self {
+            sensitivity: Some(controller.sensitivity),
+            translation_smoothing: Some(controller.translation_smoothing),
+            rotation_smoothing: Some(controller.rotation_smoothing),
+            zoom_sensitivity: Some(controller.zoom_sensitivity),
+            zoom_smoothing: Some(controller.zoom_smoothing),
+            offset: Some(controller.offset),
+            look_offset: Some(controller.look_offset),
+            distance_limits: controller.distance_limits,
+            fov: controller.fov,
+            pitch_limits: controller.pitch_limits,
+            roll_sensitivity: Some(controller.roll_sensitivity),
+            roll_limits: controller.roll_limits,
+            yaw_range: controller.yaw_range,
+            anchor: match controller.anchor {
+                CameraAnchor::Rail(_) => None,
+                ref anchor => Some(anchor.clone()),
+            },
+        }
+    }
+
+    /// Writes the settings listed in this profile to a controller
+    ///
+    /// # Arguments
+    /// * `controller` - Controller to tune
+    pub fn apply(&self, controller: &mut CameraController) {
+        if let Some(sensitivity) = self.sensitivity {
+            controller.sensitivity = sensitivity;
+        }
+        if let Some(smoothing) = self.translation_smoothing {
+            controller.translation_smoothing = smoothing;
+        }
+        if let Some(smoothing) = self.rotation_smoothing {
+            controller.rotation_smoothing = smoothing;
+        }
+        if let Some(sensitivity) = self.zoom_sensitivity {
+            controller.zoom_sensitivity = sensitivity;
+        }
+        if let Some(smoothing) = self.zoom_smoothing {
+            controller.zoom_smoothing = smoothing;
+        }
+        if let Some(offset) = self.offset {
+            controller.offset = offset;
+        }
+        if let Some(look_offset) = self.look_offset {
+            controller.look_offset = look_offset;
+        }
+        if let Some((min, max)) = self.distance_limits {
+            controller.distance_limits = Some((min.min(max), min.max(max)));
+        }
+        if let Some(fov) = self.fov {
+            controller.fov = Some(fov);
+        }
+        if let Some((min, max)) = self.pitch_limits {
+            let limit = std::f32::consts::FRAC_PI_2 - 0.001;
+            let (min, max) = (min.min(max), min.max(max));
+            controller.pitch_limits = Some((min.clamp(-limit, limit), max.clamp(-limit, limit)));
+        }
+        if let Some(sensitivity) = self.roll_sensitivity {
+            controller.roll_sensitivity = sensitivity;
+        }
+        if let Some((min, max)) = self.roll_limits {
+            controller.roll_limits = Some((min.min(max), min.max(max)));
+        }
+        if let Some((min, max)) = self.yaw_range {
+            controller.yaw_range = Some((min.min(max), min.max(max)));
+        }
+        if let Some(anchor) = &self.anchor
+            && !matches!(anchor, CameraAnchor::Rail(_))
+        {
+            controller.anchor = anchor.clone();
+        }
+    }
+}
+
+/// A component applying a [`CameraProfile`] to the [`CameraController`] on the same entity,
+/// when the handle is set and whenever the profile asset is loaded or modified
+#[derive(Component, Clone, Debug, Default, Deref, DerefMut)]
+pub struct CameraProfileHandle(pub Handle<CameraProfile>);
+
+/// Errors encountered while loading a [`CameraProfile`]
+#[derive(Debug)]
+pub enum CameraProfileError {
+    /// The profile file could not be read
+    Io(std::io::Error),
+    /// The profile file is not valid RON
+    Ron(ron::error::SpannedError),
+}
+
+impl core::fmt::Display for CameraProfileError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "could not read camera profile: {error}"),
+            Self::Ron(error) => write!(f, "could not parse camera profile: {error}"),
+        }
+    }
+}
+
+impl core::error::Error for CameraProfileError {}
+
+impl From<std::io::Error> for CameraProfileError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<ron::error::SpannedError> for CameraProfileError {
+    fn from(error: ron::error::SpannedError) -> Self {
+        Self::Ron(error)
+    }
+}
+
+/// Loads [`CameraProfile`] assets from `.camera.ron` files
+#[derive(Default)]
+pub struct CameraProfileLoader;
+
+impl AssetLoader for CameraProfileLoader {
+    type Asset = CameraProfile;
+    type Settings = ();
+    type Error = CameraProfileError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<CameraProfile, CameraProfileError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["camera.ron"]
+    }
+}
+
+/// Applies profiles to controllers when their handle changes or the profile is reloaded
+///
+/// # Arguments
+/// * `events` - Asset events of camera profiles
+/// * `profiles` - Loaded camera profiles
+/// * `controllers` - Query for controllers with a profile handle
+pub(crate) fn apply_camera_profiles(
+    mut events: EventReader<AssetEvent<CameraProfile>>,
+    profiles: Res<Assets<CameraProfile>>,
+    mut controllers: Query<(Ref<CameraProfileHandle>, &mut CameraController)>,
+) {
+    let updated: HashSet<_> = events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+
+    for (handle, mut controller) in controllers.iter_mut() {
+        if !handle.is_changed() && !updated.contains(&handle.id()) {
+            continue;
+        }
+        if let Some(profile) = profiles.get(&handle.0) {
+            profile.apply(&mut controller);
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use bevy::{prelude::*, render::camera::ScalingMode};
+
+/// Narrowest field of view reached before a perspective camera swaps to orthographic
+const MIN_FOV: f32 = 0.02;
+
+/// Projection a [`CameraController`](super::CameraController) blends toward with
+/// [`CameraController::blend_projection`](super::CameraController::blend_projection)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProjectionMode {
+    #[default]
+    Perspective,
+    Orthographic,
+}
+
+/// Progress of a controller's camera between perspective and orthographic projections.
+///
+/// The blend is a dolly zoom, narrowing the field of view while backing the camera away
+/// so the plane through the focus point keeps its size, then swapping to an orthographic
+/// projection of the same size once the view is nearly flat.
+#[derive(Clone, Default)]
+pub(crate) struct ProjectionBlend {
+    /// Projection being blended toward
+    pub mode: ProjectionMode,
+    /// Duration of a full blend in seconds
+    duration: f32,
+    /// Linear progress from perspective at 0 to orthographic at 1
+    progress: f32,
+    /// Perspective projection blended from and returned to, stored while not perspective
+    perspective: Option<PerspectiveProjection>,
+    /// Translation pushed onto the camera by the dolly during the last update
+    applied: Vec3,
+}
+
+impl ProjectionBlend {
+    /// Starts blending toward a projection, continuing from the current progress
+    ///
+    /// # Arguments
+    /// * `mode` - Projection to blend toward
+    /// * `duration` - Duration of a full blend in seconds
+    pub fn start(&mut self, mode: ProjectionMode, duration: f32) {
+        self.mode = mode;
+        self.duration = duration;
+    }
+
+    /// Checks whether the camera is blending or resting away from the perspective projection
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.mode == ProjectionMode::Orthographic || self.progress > 0.0
+    }
+
+    /// Takes the dolly translation applied during the last update, so it can be
+    /// removed before the camera is solved again
+    #[inline]
+    pub fn take_applied(&mut self) -> Vec3 {
+        core::mem::take(&mut self.applied)
+    }
+
+    /// Advances the blend and applies it to the camera's projection and transform
+    ///
+    /// # Arguments
+    /// * `projection` - Projection of the controlled camera
+    /// * `camera_transform` - Solved transform of the controlled camera
+    /// * `focus_distance` - Distance from the camera to the plane that keeps its size
+    /// * `dt` - Time elapsed since last update in seconds
+    pub fn apply(
+        &mut self,
+        projection: &mut Projection,
+        camera_transform: &mut Transform,
+        focus_distance: f32,
+        dt: f32,
+    ) {
+        let target = match self.mode {
+            ProjectionMode::Perspective => 0.0,
+            ProjectionMode::Orthographic => 1.0,
+        };
+        let step = if self.duration > 0.0 {
+            dt / self.duration
+        } else {
+            1.0
+        };
+        self.progress += (target - self.progress).clamp(-step, step);
+
+        // store the perspective being left, or fall back to the default for orthographic cameras
+        let perspective = self
+            .perspective
+            .get_or_insert_with(|| match projection {
+                Projection::Perspective(perspective) => perspective.clone(),
+                _ => PerspectiveProjection::default(),
+            })
+            .clone();
+        let half_height = focus_distance * (perspective.fov * 0.5).tan();
+
+        let flatness = EaseFunction::SmoothStep.sample_clamped(self.progress);
+        if flatness >= 1.0 {
+            // fully flat, sized so the focus plane matches the perspective view
+            let viewport_height = half_height * 2.0;
+            let scaling_mode = ScalingMode::FixedVertical { viewport_height };
+            match projection {
+                Projection::Orthographic(orthographic) => {
+                    if !matches!(orthographic.scaling_mode,
+                        ScalingMode::FixedVertical { viewport_height: height } if height == viewport_height)
+                    {
+                        orthographic.scaling_mode = scaling_mode;
+                    }
+                }
+                _ => {
+                    *projection = Projection::Orthographic(OrthographicProjection {
+                        scaling_mode,
+                        far: perspective.far,
+                        ..OrthographicProjection::default_3d()
+                    });
+                }
+            }
+        } else if flatness <= 0.0 {
+            // back to the authored perspective projection
+            *projection = Projection::Perspective(perspective);
+            self.perspective = None;
+        } else {
+            // back away as the field of view narrows so the focus plane keeps its size
+            let fov = perspective.fov * (MIN_FOV / perspective.fov).powf(flatness);
+            let dolly = half_height / (fov * 0.5).tan() - focus_distance;
+            self.applied = camera_transform.back() * dolly;
+            camera_transform.translation += self.applied;
+            *projection = Projection::Perspective(PerspectiveProjection {
+                fov,
+                far: perspective.far + dolly,
+                ..perspective
+            });
+        }
+    }
+}
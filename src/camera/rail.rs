@@ -0,0 +1,90 @@
+use bevy::prelude::*;
+
+/// A spline path, in the rail entity's local space, that cameras using
+/// [`CameraAnchor::Rail`](super::CameraAnchor::Rail) are constrained to
+#[derive(Component, Clone)]
+#[require(Transform)]
+pub struct CameraRail {
+    /// Curve the camera travels along
+    curve: CubicCurve<Vec3>,
+    /// Number of straight pieces each curve segment is split into when finding the nearest point
+    subdivisions: usize,
+    /// Positions along the curve used to find the nearest point
+    samples: Vec<Vec3>,
+}
+
+impl CameraRail {
+    /// Creates a new CameraRail, splitting each curve segment into 16 pieces
+    /// when finding the nearest point
+    ///
+    /// # Arguments
+    /// * `curve` - Curve in the rail entity's local space
+    pub fn new(curve: CubicCurve<Vec3>) -> Self {
+        Self {
+            curve,
+            subdivisions: 0,
+            samples: Vec::new(),
+        }
+        .with_subdivisions(16)
+    }
+
+    /// Sets the resolution used for finding the nearest point
+    ///
+    /// # Arguments
+    /// * `subdivisions` - Number of straight pieces per curve segment
+    pub fn with_subdivisions(mut self, subdivisions: usize) -> Self {
+        self.subdivisions = subdivisions.max(1);
+        self.samples = self
+            .curve
+            .iter_positions(self.curve.segments().len() * self.subdivisions)
+            .collect();
+        self
+    }
+
+    /// Returns the curve the camera travels along
+    #[inline]
+    pub fn curve(&self) -> &CubicCurve<Vec3> {
+        &self.curve
+    }
+
+    /// Returns the largest curve parameter, equal to the number of curve segments
+    #[inline]
+    pub fn length(&self) -> f32 {
+        self.curve.segments().len() as f32
+    }
+
+    /// Returns the position on the rail at a curve parameter, in local space
+    ///
+    /// # Arguments
+    /// * `parameter` - Curve parameter, clamped between 0 and the rail length
+    #[inline]
+    pub fn position(&self, parameter: f32) -> Vec3 {
+        self.curve.position(parameter.clamp(0.0, self.length()))
+    }
+
+    /// Finds the curve parameter of the point on the rail nearest to a local space point
+    ///
+    /// # Arguments
+    /// * `point` - Point in the rail entity's local space
+    pub fn nearest_parameter(&self, point: Vec3) -> f32 {
+        let step = 1.0 / self.subdivisions as f32;
+        let (index, fraction, _) = self.samples.windows(2).enumerate().fold(
+            (0, 0.0, f32::INFINITY),
+            |nearest, (index, piece)| {
+                let along = piece[1] - piece[0];
+                let fraction = if along.length_squared() > 0.0 {
+                    ((point - piece[0]).dot(along) / along.length_squared()).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                let distance = point.distance_squared(piece[0] + along * fraction);
+                if distance < nearest.2 {
+                    (index, fraction, distance)
+                } else {
+                    nearest
+                }
+            },
+        );
+        (index as f32 + fraction) * step
+    }
+}
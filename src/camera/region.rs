@@ -0,0 +1,126 @@
+use bevy::prelude::*;
+
+use super::{
+    CameraBuffer, CameraController,
+    target::{self, SkippedEntities, TargetFilter, TargetTransform},
+};
+
+/// Multipliers applied to a controller's sensitivity and smoothing
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegionScale {
+    /// Multiplier applied to sensitivity
+    pub sensitivity: f32,
+    /// Multiplier applied to smoothing, larger values give heavier movement
+    pub smoothing: f32,
+}
+
+impl Default for RegionScale {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl RegionScale {
+    /// Scale that leaves sensitivity and smoothing unchanged
+    pub const IDENTITY: Self = Self {
+        sensitivity: 1.0,
+        smoothing: 1.0,
+    };
+}
+
+impl core::ops::Mul for RegionScale {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            sensitivity: self.sensitivity * rhs.sensitivity,
+            smoothing: self.smoothing * rhs.smoothing,
+        }
+    }
+}
+
+/// A box shaped volume that scales the sensitivity and smoothing of controllers
+/// whose entity is inside it. Overlapping regions multiply their scales together.
+#[derive(Component, Clone)]
+#[require(Transform)]
+pub struct CameraRegion {
+    /// Half size of the volume in the region entity's local space
+    pub half_extents: Vec3,
+    /// Scale applied to controllers inside the volume
+    pub scale: RegionScale,
+}
+
+impl CameraRegion {
+    /// Creates a new CameraRegion without any scaling
+    ///
+    /// # Arguments
+    /// * `half_extents` - Half size of the volume in local space
+    pub fn new(half_extents: Vec3) -> Self {
+        Self {
+            half_extents,
+            scale: RegionScale::IDENTITY,
+        }
+    }
+
+    /// Sets the sensitivity multiplier applied inside the region
+    ///
+    /// # Arguments
+    /// * `sensitivity` - Multiplier applied to sensitivity
+    #[inline]
+    pub fn with_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.scale.sensitivity = sensitivity;
+        self
+    }
+
+    /// Sets the smoothing multiplier applied inside the region
+    ///
+    /// # Arguments
+    /// * `smoothing` - Multiplier applied to smoothing
+    #[inline]
+    pub fn with_smoothing(mut self, smoothing: f32) -> Self {
+        self.scale.smoothing = smoothing;
+        self
+    }
+
+    /// Checks whether a world space point is inside the region
+    ///
+    /// # Arguments
+    /// * `transform` - Transform of the region entity
+    /// * `point` - World space point to test
+    pub fn contains(&self, transform: &Transform, point: Vec3) -> bool {
+        let local = transform.compute_affine().inverse().transform_point3(point);
+        local.abs().cmple(self.half_extents).all()
+    }
+}
+
+/// Combines the scales of all regions containing each controller entity
+///
+/// # Arguments
+/// * `camera_controllers` - Query for controller buffers to store scales in
+/// * `controller_transforms` - Query for controller entity transforms
+/// * `regions` - Query for region volumes
+/// * `skipped` - Controllers skipped for missing entities, warned about once
+pub(crate) fn update_region_scales(
+    mut camera_controllers: Query<(Entity, &mut CameraBuffer), With<CameraController>>,
+    controller_transforms: Query<TargetTransform, TargetFilter>,
+    regions: Query<(&CameraRegion, &Transform)>,
+    mut skipped: Local<SkippedEntities>,
+) {
+    for (entity, mut buffer) in camera_controllers.iter_mut() {
+        let Some(controller_transform) = skipped.check(
+            entity,
+            target::get_transform(&controller_transforms, entity),
+        ) else {
+            continue;
+        };
+        let translation = controller_transform.translation;
+
+        buffer.region_scale = regions
+            .iter()
+            .filter(|(region, transform)| region.contains(transform, translation))
+            .fold(RegionScale::IDENTITY, |scale, (region, _)| {
+                scale * region.scale
+            });
+    }
+}
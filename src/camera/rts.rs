@@ -0,0 +1,376 @@
+use bevy::{
+    input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll, MouseScrollUnit},
+    prelude::*,
+    window::PrimaryWindow,
+};
+
+use super::{CameraControllerPaused, CameraPlayer, InputSource, InputSources, WorldUnits};
+
+/// Keys and buttons used by an [`RtsCameraController`]
+#[derive(Clone, Debug)]
+pub struct RtsCameraBindings {
+    pub forward: KeyCode,
+    pub back: KeyCode,
+    pub left: KeyCode,
+    pub right: KeyCode,
+    /// Rotates the view counterclockwise around the look point
+    pub rotate_left: KeyCode,
+    /// Rotates the view clockwise around the look point
+    pub rotate_right: KeyCode,
+    /// Held to drag the ground under the cursor
+    pub drag: Option<MouseButton>,
+    /// Held to rotate around the look point with the mouse
+    pub rotate: Option<MouseButton>,
+}
+
+impl Default for RtsCameraBindings {
+    fn default() -> Self {
+        Self {
+            forward: KeyCode::KeyW,
+            back: KeyCode::KeyS,
+            left: KeyCode::KeyA,
+            right: KeyCode::KeyD,
+            rotate_left: KeyCode::KeyQ,
+            rotate_right: KeyCode::KeyE,
+            drag: Some(MouseButton::Middle),
+            rotate: Some(MouseButton::Right),
+        }
+    }
+}
+
+/// A strategy and builder game camera, added directly to a camera entity.
+///
+/// The camera looks down at a point on a horizontal ground plane and combines keyboard
+/// panning, screen edge scrolling, dragging the ground with the mouse, rotating around the
+/// look point and zooming toward the ground under the cursor.
+#[derive(Component, Clone, Debug)]
+pub struct RtsCameraController {
+    /// Whether the camera responds to input
+    pub enabled: bool,
+    /// Height of the ground plane the camera looks at, in meters
+    pub ground_height: f32,
+    /// Panning speed as a fraction of the view distance per second
+    pub pan_speed: f32,
+    /// Width of the screen edge that scrolls the view, in logical pixels, or disabled if unset
+    pub edge_margin: Option<f32>,
+    /// Rotation speed in radians per second for the rotate keys
+    pub rotate_speed: f32,
+    /// Radians turned per pixel of mouse movement while rotating
+    pub sensitivity: f32,
+    /// Fraction of the distance removed per scroll line
+    pub zoom_speed: f32,
+    /// Minimum and maximum distance from the look point, in meters
+    pub distance_limits: (f32, f32),
+    /// Minimum and maximum pitch below the horizon, in radians
+    pub pitch_limits: (f32, f32),
+    /// Keys and buttons used for control
+    pub bindings: RtsCameraBindings,
+    /// Rate at which the view decays toward the goal with smooth interpolation
+    decay_rate: f32,
+    /// Goal state of the view
+    goal: RtsView,
+    /// Current state of the view
+    current: Option<RtsView>,
+}
+
+/// State of an RTS camera, in world units
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct RtsView {
+    focus: Vec3,
+    yaw: f32,
+    pitch: f32,
+    distance: f32,
+}
+
+impl RtsView {
+    /// Gets the camera transform looking at the focus point
+    fn transform(&self) -> Transform {
+        let rotation = Quat::from_euler(EulerRot::YXZ, self.yaw, -self.pitch, 0.0);
+        Transform::from_translation(self.focus + rotation * Vec3::Z * self.distance)
+            .with_rotation(rotation)
+    }
+}
+
+impl RtsCameraController {
+    /// Creates a new RtsCameraController with default settings:
+    /// - Ground plane at height 0
+    /// - Distance: 20 meters, limited to 5 to 100 meters
+    /// - Pitch: 55 degrees, limited to 20 to 85 degrees
+    /// - Pan speed: 1.0 view distance per second, scrolling within 10 pixels of the edge
+    /// - WASD to pan, Q and E to rotate, middle mouse to drag, right mouse to rotate
+    /// - No smoothing (instant movement)
+    ///
+    /// # Arguments
+    /// * `focus` - Point on the ground the camera initially looks at, in world units
+    pub fn new(focus: Vec3) -> Self {
+        Self {
+            enabled: true,
+            ground_height: 0.0,
+            pan_speed: 1.0,
+            edge_margin: Some(10.0),
+            rotate_speed: 1.5,
+            sensitivity: 0.005,
+            zoom_speed: 0.1,
+            distance_limits: (5.0, 100.0),
+            pitch_limits: (20f32.to_radians(), 85f32.to_radians()),
+            bindings: RtsCameraBindings::default(),
+            decay_rate: f32::INFINITY,
+            goal: RtsView {
+                focus,
+                yaw: 0.0,
+                pitch: 55f32.to_radians(),
+                distance: 20.0,
+            },
+            current: None,
+        }
+    }
+
+    /// Sets the initial distance from the look point
+    ///
+    /// # Arguments
+    /// * `distance` - Distance in meters
+    #[inline]
+    pub fn with_distance(mut self, distance: f32) -> Self {
+        self.goal.distance = distance;
+        self
+    }
+
+    /// Sets the initial yaw and pitch of the view
+    ///
+    /// # Arguments
+    /// * `yaw` - Rotation around the vertical axis in radians
+    /// * `pitch` - Angle below the horizon in radians
+    #[inline]
+    pub fn with_angles(mut self, yaw: f32, pitch: f32) -> Self {
+        self.goal.yaw = yaw;
+        self.goal.pitch = pitch;
+        self
+    }
+
+    /// Sets the height of the ground plane
+    ///
+    /// # Arguments
+    /// * `height` - Ground height in meters
+    #[inline]
+    pub fn with_ground_height(mut self, height: f32) -> Self {
+        self.ground_height = height;
+        self
+    }
+
+    /// Sets the width of the screen edge that scrolls the view
+    ///
+    /// # Arguments
+    /// * `margin` - Edge width in logical pixels, or `None` to disable edge scrolling
+    #[inline]
+    pub fn with_edge_margin(mut self, margin: Option<f32>) -> Self {
+        self.edge_margin = margin;
+        self
+    }
+
+    /// Sets the distance limits
+    ///
+    /// # Arguments
+    /// * `min` - Minimum distance in meters
+    /// * `max` - Maximum distance in meters
+    #[inline]
+    pub fn with_distance_limits(mut self, min: f32, max: f32) -> Self {
+        self.distance_limits = (min, max);
+        self
+    }
+
+    /// Sets the keys and buttons used for control
+    ///
+    /// # Arguments
+    /// * `bindings` - Key and button bindings
+    #[inline]
+    pub fn with_bindings(mut self, bindings: RtsCameraBindings) -> Self {
+        self.bindings = bindings;
+        self
+    }
+
+    /// Sets smoothing factor for the view.
+    /// Larger values give smoother movement.
+    ///
+    /// # Arguments
+    /// * `smoothing` - Smoothing factor for camera movement
+    #[inline]
+    pub fn with_smoothing(mut self, smoothing: f32) -> Self {
+        self.decay_rate = 1.0 / smoothing;
+        self
+    }
+
+    /// Returns the point on the ground the camera is moving to look at, in world units
+    #[inline]
+    pub fn focus(&self) -> Vec3 {
+        self.goal.focus
+    }
+
+    /// Moves the look point, keeping the current rotation and distance
+    ///
+    /// # Arguments
+    /// * `focus` - Point on the ground to look at, in world units
+    #[inline]
+    pub fn set_focus(&mut self, focus: Vec3) {
+        self.goal.focus = focus;
+    }
+}
+
+/// Intersects a ray with a horizontal ground plane
+///
+/// # Arguments
+/// * `ray` - Ray to intersect
+/// * `height` - Height of the ground plane
+fn ground_point(ray: Ray3d, height: f32) -> Option<Vec3> {
+    let distance = ray.intersect_plane(Vec3::Y * height, InfinitePlane3d::new(Vec3::Y))?;
+    Some(ray.get_point(distance))
+}
+
+/// Pans, rotates and zooms RTS cameras from keyboard and mouse input, leaving cameras
+/// that are not routed the keyboard and mouse to their smoothing
+///
+/// # Arguments
+/// * `rts_cameras` - Query for RTS cameras, their transforms, cameras and input routing
+/// * `windows` - Query for the primary window, used for the cursor position
+/// * `keys` - Keyboard input, if the input plugin is present
+/// * `buttons` - Mouse button input, if the input plugin is present
+/// * `motion` - Mouse motion accumulated this frame
+/// * `scroll` - Mouse scrolling accumulated this frame
+/// * `units` - Resource describing the scale of the world
+/// * `time` - Resource providing frame timing information
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(crate) fn update_rts_cameras(
+    mut rts_cameras: Query<
+        (
+            &mut RtsCameraController,
+            &mut Transform,
+            &Camera,
+            Option<&CameraPlayer>,
+            Option<&InputSources>,
+        ),
+        Without<CameraControllerPaused>,
+    >,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    buttons: Option<Res<ButtonInput<MouseButton>>>,
+    motion: Option<Res<AccumulatedMouseMotion>>,
+    scroll: Option<Res<AccumulatedMouseScroll>>,
+    units: Res<WorldUnits>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+    let window = windows.single().ok();
+    let cursor = window.and_then(Window::cursor_position);
+    let motion = motion.map_or(Vec2::ZERO, |motion| motion.delta);
+    let pressed = |button: Option<MouseButton>| {
+        button.is_some_and(|button| buttons.as_deref().is_some_and(|b| b.pressed(button)))
+    };
+
+    for (mut rts, mut transform, camera, player, sources) in rts_cameras.iter_mut() {
+        let ground_height = units.to_world(rts.ground_height);
+        let (min, max) = rts.distance_limits;
+        let (min, max) = (units.to_world(min), units.to_world(max));
+        let (min_pitch, max_pitch) = rts.pitch_limits;
+
+        // start from the authored view, converted into world units
+        let mut current = match rts.current {
+            Some(current) => current,
+            None => {
+                let mut view = rts.goal;
+                view.focus.y = ground_height;
+                view.distance = units.to_world(view.distance).clamp(min, max);
+                rts.goal = view;
+                view
+            }
+        };
+        let mut goal = rts.goal;
+
+        if rts.enabled && InputSources::routes(sources, player, InputSource::Mouse) {
+            let camera_transform = GlobalTransform::from(current.transform());
+            let cursor_ground = |cursor: Vec2| {
+                let ray = camera.viewport_to_world(&camera_transform, cursor).ok()?;
+                ground_point(ray, ground_height)
+            };
+            // planar axes facing along the view
+            let yaw = Quat::from_rotation_y(goal.yaw);
+            let (right, back) = (yaw * Vec3::X, yaw * Vec3::Z);
+            let mut pan = Vec2::ZERO;
+
+            if let Some(keys) = keys.as_deref() {
+                let bindings = &rts.bindings;
+                let axis = |positive: KeyCode, negative: KeyCode| {
+                    keys.pressed(positive) as i32 as f32 - keys.pressed(negative) as i32 as f32
+                };
+                pan += Vec2::new(
+                    axis(bindings.right, bindings.left),
+                    axis(bindings.back, bindings.forward),
+                );
+                goal.yaw +=
+                    axis(bindings.rotate_left, bindings.rotate_right) * rts.rotate_speed * dt;
+            }
+
+            // scroll when the cursor is near the edge of the window
+            if let (Some(margin), Some(window), Some(cursor)) = (rts.edge_margin, window, cursor) {
+                let size = window.size();
+                pan.x +=
+                    (cursor.x > size.x - margin) as i32 as f32 - (cursor.x < margin) as i32 as f32;
+                pan.y +=
+                    (cursor.y > size.y - margin) as i32 as f32 - (cursor.y < margin) as i32 as f32;
+            }
+
+            let pan = pan.clamp_length_max(1.0) * rts.pan_speed * goal.distance * dt;
+            goal.focus += right * pan.x + back * pan.y;
+
+            // drag the ground under the cursor along with it
+            if pressed(rts.bindings.drag)
+                && motion != Vec2::ZERO
+                && let Some(cursor) = cursor
+                && let (Some(from), Some(to)) =
+                    (cursor_ground(cursor - motion), cursor_ground(cursor))
+            {
+                goal.focus += from - to;
+            }
+
+            // rotate around the look point
+            if pressed(rts.bindings.rotate) {
+                goal.yaw -= motion.x * rts.sensitivity;
+                goal.pitch += motion.y * rts.sensitivity;
+            }
+            goal.pitch = goal.pitch.clamp(min_pitch, max_pitch);
+
+            // zoom toward the ground under the cursor, keeping that point in place
+            if let Some(scroll) = scroll.as_deref() {
+                let lines = match scroll.unit {
+                    MouseScrollUnit::Line => scroll.delta.y,
+                    MouseScrollUnit::Pixel => scroll.delta.y / 16.0,
+                };
+                if lines != 0.0 {
+                    let distance =
+                        (goal.distance * (1.0 - rts.zoom_speed).powf(lines)).clamp(min, max);
+                    let factor = distance / goal.distance;
+                    if let Some(point) = cursor.and_then(cursor_ground) {
+                        goal.focus = point + (goal.focus - point) * factor;
+                    }
+                    goal.distance = distance;
+                }
+            }
+        }
+        goal.focus.y = ground_height;
+        rts.goal = goal;
+
+        // move toward the goal view
+        if rts.decay_rate.is_finite() {
+            let rate = rts.decay_rate;
+            current.focus.smooth_nudge(&goal.focus, rate, dt);
+            current.yaw.smooth_nudge(&goal.yaw, rate, dt);
+            current.pitch.smooth_nudge(&goal.pitch, rate, dt);
+            current.distance.smooth_nudge(&goal.distance, rate, dt);
+        } else {
+            current = goal;
+        }
+        rts.current = Some(current);
+
+        let solved = current.transform();
+        transform.translation = solved.translation;
+        transform.rotation = solved.rotation;
+    }
+}
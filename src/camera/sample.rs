@@ -0,0 +1,201 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+/// A single timestamped pose sample, typically received over the network
+#[derive(Clone, Copy, Debug)]
+pub struct CameraSample {
+    /// Time the sample was taken, in seconds on the clock of the controller's
+    /// [`time_source`](super::CameraController::time_source)
+    pub time: f32,
+    /// Orientation of the view at the time of the sample
+    pub rotation: Quat,
+    /// Optional position of the controller entity at the time of the sample
+    pub translation: Option<Vec3>,
+}
+
+/// A buffer component that stores sparse timestamped samples and interpolates
+/// between them, used in place of raw deltas when spectating remote players.
+///
+/// Samples are played back with a fixed delay so that there is usually a sample
+/// on either side of the playback time. When samples stop arriving the last known
+/// motion is extrapolated for a limited amount of time.
+#[derive(Component, Clone)]
+#[require(super::CameraBuffer)]
+pub struct SampleBuffer {
+    /// Samples ordered by time
+    samples: VecDeque<CameraSample>,
+    /// Maximum number of samples kept in the buffer
+    capacity: usize,
+    /// Delay in seconds between the current time and the playback time
+    pub delay: f32,
+    /// Maximum time in seconds to extrapolate past the newest sample
+    pub max_extrapolation: f32,
+}
+
+impl Default for SampleBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SampleBuffer {
+    /// Creates a new SampleBuffer instance with default settings:
+    /// - Delay: 0.1 seconds (suits 10-20 Hz sample rates)
+    /// - Extrapolation: up to 0.25 seconds
+    /// - Capacity: 32 samples
+    pub fn new() -> Self {
+        Self {
+            samples: VecDeque::new(),
+            capacity: 32,
+            delay: 0.1,
+            max_extrapolation: 0.25,
+        }
+    }
+
+    /// Sets the playback delay in seconds
+    ///
+    /// # Arguments
+    /// * `delay` - Time behind the newest samples to play back at
+    #[inline]
+    pub fn with_delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets the maximum time to extrapolate past the newest sample
+    ///
+    /// # Arguments
+    /// * `max_extrapolation` - Extrapolation limit in seconds
+    #[inline]
+    pub fn with_max_extrapolation(mut self, max_extrapolation: f32) -> Self {
+        self.max_extrapolation = max_extrapolation;
+        self
+    }
+
+    /// Sets the maximum number of samples kept in the buffer
+    ///
+    /// # Arguments
+    /// * `capacity` - Number of samples to keep, at least 2
+    #[inline]
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(2);
+        self
+    }
+
+    /// Adds an orientation sample to the buffer
+    ///
+    /// # Arguments
+    /// * `time` - Time the sample was taken on the clock of the controller's
+    ///   [`time_source`](super::CameraController::time_source)
+    /// * `rotation` - Orientation of the view
+    #[inline]
+    pub fn push(&mut self, time: f32, rotation: Quat) {
+        self.insert(CameraSample {
+            time,
+            rotation,
+            translation: None,
+        });
+    }
+
+    /// Adds an orientation and position sample to the buffer
+    ///
+    /// # Arguments
+    /// * `time` - Time the sample was taken on the clock of the controller's
+    ///   [`time_source`](super::CameraController::time_source)
+    /// * `rotation` - Orientation of the view
+    /// * `translation` - Position of the controller entity
+    #[inline]
+    pub fn push_with_translation(&mut self, time: f32, rotation: Quat, translation: Vec3) {
+        self.insert(CameraSample {
+            time,
+            rotation,
+            translation: Some(translation),
+        });
+    }
+
+    /// Inserts a sample in time order, discarding the oldest sample when full
+    pub fn insert(&mut self, sample: CameraSample) {
+        // samples usually arrive in order, so search from the back
+        let index = self
+            .samples
+            .iter()
+            .rposition(|s| s.time <= sample.time)
+            .map_or(0, |i| i + 1);
+        self.samples.insert(index, sample);
+
+        while self.samples.len() > self.capacity {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Removes all samples from the buffer
+    #[inline]
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Returns the samples currently stored in the buffer
+    #[inline]
+    pub fn samples(&self) -> impl Iterator<Item = &CameraSample> {
+        self.samples.iter()
+    }
+
+    /// Interpolates the stored samples at the given playback time,
+    /// extrapolating past the newest sample up to the configured limit
+    ///
+    /// # Arguments
+    /// * `time` - Playback time on the clock of the controller's
+    ///   [`time_source`](super::CameraController::time_source)
+    pub fn sample(&self, time: f32) -> Option<CameraSample> {
+        let (first, last) = (self.samples.front()?, self.samples.back()?);
+
+        if time <= first.time {
+            return Some(*first);
+        }
+
+        let (from, to) = match self.samples.iter().position(|s| s.time > time) {
+            Some(i) => (self.samples[i - 1], self.samples[i]),
+            // past the newest sample, extrapolate from the last two samples
+            None if self.samples.len() >= 2 => (self.samples[self.samples.len() - 2], *last),
+            None => return Some(*last),
+        };
+
+        let span = to.time - from.time;
+        if span <= f32::EPSILON {
+            return Some(to);
+        }
+        // limit how far past the newest sample motion is continued
+        let time = time.min(last.time + self.max_extrapolation);
+        let s = (time - from.time) / span;
+
+        let rotation = if s <= 1.0 {
+            from.rotation.slerp(to.rotation, s)
+        } else {
+            let (axis, angle) = (to.rotation * from.rotation.inverse()).to_axis_angle();
+            Quat::from_axis_angle(axis, angle * s) * from.rotation
+        };
+        let translation = match (from.translation, to.translation) {
+            (Some(a), Some(b)) => Some(a + (b - a) * s),
+            (_, b) => b,
+        };
+
+        Some(CameraSample {
+            time,
+            rotation: rotation.normalize(),
+            translation,
+        })
+    }
+
+    /// Discards samples that are no longer needed for interpolation at the given time
+    ///
+    /// # Arguments
+    /// * `time` - Playback time on the clock of the controller's
+    ///   [`time_source`](super::CameraController::time_source)
+    pub fn prune(&mut self, time: f32) {
+        // keep one sample before the playback time to interpolate from
+        while self.samples.len() > 2 && self.samples[1].time <= time {
+            self.samples.pop_front();
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use bevy::prelude::*;
+
+use super::{
+    CameraController, CameraFeedback, CameraFeedbackKind, WorldUnits, noise,
+    target::{CameraFilter, SkippedEntities},
+    time::CameraTime,
+};
+
+/// A component that layers trauma based procedural shake on top of a controller's camera.
+///
+/// Trauma is added with [`CameraShake::add_trauma`] and decays over time, with the
+/// shake strength growing with the square of trauma. The shake is applied after the
+/// camera is solved and removed again before the next update, so it never feeds back
+/// into the controller's smoothing.
+#[derive(Component, Clone)]
+pub struct CameraShake {
+    /// Current trauma in the range 0 to 1
+    trauma: f32,
+    /// Trauma removed per second
+    pub decay: f32,
+    /// Maximum translation offset in meters along each local axis
+    pub max_translation: Vec3,
+    /// Maximum rotation in radians for yaw, pitch and roll
+    pub max_rotation: Vec3,
+    /// Frequency of the shake noise
+    pub frequency: f32,
+    /// Time elapsed along the noise
+    time: f32,
+    /// Trauma during the previous update, used to detect new trauma
+    previous_trauma: f32,
+    /// Offset applied to the camera during the last update
+    applied: Option<(Vec3, Quat)>,
+}
+
+impl Default for CameraShake {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CameraShake {
+    /// Creates a new CameraShake with default settings:
+    /// - Decay: 1.0 trauma per second
+    /// - Translation: up to 0.1 meters
+    /// - Rotation: up to 3 degrees yaw and pitch, 5 degrees roll
+    /// - Frequency: 15.0
+    pub fn new() -> Self {
+        Self {
+            trauma: 0.0,
+            decay: 1.0,
+            max_translation: Vec3::splat(0.1),
+            max_rotation: Vec3::new(3f32.to_radians(), 3f32.to_radians(), 5f32.to_radians()),
+            frequency: 15.0,
+            time: 0.0,
+            previous_trauma: 0.0,
+            applied: None,
+        }
+    }
+
+    /// Sets the maximum amplitude of the shake
+    ///
+    /// # Arguments
+    /// * `translation` - Maximum translation offset in meters along each local axis
+    /// * `rotation` - Maximum rotation in radians for yaw, pitch and roll
+    #[inline]
+    pub fn with_amplitude(mut self, translation: Vec3, rotation: Vec3) -> Self {
+        self.max_translation = translation;
+        self.max_rotation = rotation;
+        self
+    }
+
+    /// Sets the frequency of the shake noise
+    ///
+    /// # Arguments
+    /// * `frequency` - Noise frequency
+    #[inline]
+    pub fn with_frequency(mut self, frequency: f32) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    /// Sets the rate at which trauma decays
+    ///
+    /// # Arguments
+    /// * `decay` - Trauma removed per second
+    #[inline]
+    pub fn with_decay(mut self, decay: f32) -> Self {
+        self.decay = decay;
+        self
+    }
+
+    /// Adds trauma, clamped to the range 0 to 1
+    ///
+    /// # Arguments
+    /// * `trauma` - Trauma to add
+    #[inline]
+    pub fn add_trauma(&mut self, trauma: f32) {
+        self.trauma = (self.trauma + trauma).clamp(0.0, 1.0);
+    }
+
+    /// Returns the current trauma
+    #[inline]
+    pub fn trauma(&self) -> f32 {
+        self.trauma
+    }
+
+    /// Returns the current shake strength, the square of trauma
+    #[inline]
+    pub fn intensity(&self) -> f32 {
+        self.trauma * self.trauma
+    }
+}
+
+/// Removes the shake applied during the previous frame from controller cameras
+///
+/// # Arguments
+/// * `camera_controllers` - Query for controllers with shake
+/// * `camera_transforms` - Query for camera transforms to restore
+pub(crate) fn remove_shake(
+    mut camera_controllers: Query<(&CameraController, &mut CameraShake)>,
+    mut camera_transforms: Query<&mut Transform, CameraFilter>,
+) {
+    for (controller, mut shake) in camera_controllers.iter_mut() {
+        let Some((translation, rotation)) = shake.applied.take() else {
+            continue;
+        };
+        if let Ok(mut camera_transform) = camera_transforms.get_mut(controller.camera) {
+            camera_transform.translation -= translation;
+            camera_transform.rotation *= rotation.inverse();
+        }
+    }
+}
+
+/// Decays trauma and applies shake on top of the solved controller cameras
+///
+/// # Arguments
+/// * `camera_controllers` - Query for controllers with shake
+/// * `camera_transforms` - Query for camera transforms to modify
+/// * `feedback` - Event writer for notable camera events
+/// * `units` - Resource describing the scale of the world
+/// * `time` - Clocks providing frame timing for each controller's time source
+/// * `skipped` - Controllers skipped for missing entities, warned about once
+pub(crate) fn apply_shake(
+    mut camera_controllers: Query<(Entity, &CameraController, &mut CameraShake)>,
+    mut camera_transforms: Query<&mut Transform, CameraFilter>,
+    mut feedback: EventWriter<CameraFeedback>,
+    units: Res<WorldUnits>,
+    time: CameraTime,
+    mut skipped: Local<SkippedEntities>,
+) {
+    for (entity, controller, mut shake) in camera_controllers.iter_mut() {
+        let dt = time.delta_secs(controller.time_source);
+        // report newly added trauma
+        if shake.trauma > shake.previous_trauma {
+            feedback.write(CameraFeedback::new(
+                entity,
+                CameraFeedbackKind::Shake,
+                shake.trauma,
+            ));
+        }
+        shake.trauma = (shake.trauma - shake.decay * dt).max(0.0);
+        shake.previous_trauma = shake.trauma;
+
+        let intensity = shake.intensity();
+        if intensity <= 0.0 {
+            continue;
+        }
+        shake.time += dt * shake.frequency;
+        let t = shake.time;
+
+        let translation = units.to_world(
+            Vec3::new(
+                noise::value_noise(0, t),
+                noise::value_noise(1, t),
+                noise::value_noise(2, t),
+            ) * shake.max_translation
+                * intensity,
+        );
+        let angles = Vec3::new(
+            noise::value_noise(3, t),
+            noise::value_noise(4, t),
+            noise::value_noise(5, t),
+        ) * shake.max_rotation
+            * intensity;
+        let rotation = Quat::from_euler(EulerRot::YXZ, angles.x, angles.y, angles.z);
+
+        let Some(mut camera_transform) =
+            skipped.check(entity, camera_transforms.get_mut(controller.camera))
+        else {
+            continue;
+        };
+        // translation is along the camera's local axes
+        let translation = camera_transform.rotation * translation;
+        camera_transform.translation += translation;
+        camera_transform.rotation *= rotation;
+        shake.applied = Some((translation, rotation));
+    }
+}
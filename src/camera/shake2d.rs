@@ -0,0 +1,245 @@
+use bevy::prelude::*;
+
+use super::{
+    CameraController2d, CameraFeedback, CameraFeedbackKind, WorldUnits, noise,
+    target::{CameraFilter, SkippedEntities},
+    time::CameraTime,
+};
+
+/// A component that layers trauma based procedural shake on top of a 2D controller's camera,
+/// offsetting it along the screen axes and rolling it slightly around the Z axis.
+///
+/// Trauma is added directly with [`CameraShake2d::add_trauma`], or from a [`ShakeImpulse2d`]
+/// event weakened by its distance to the camera. The shake is applied after the camera
+/// follows its target and removed again before the next update, so it is never smoothed away.
+#[derive(Component, Clone)]
+pub struct CameraShake2d {
+    /// Current trauma in the range 0 to 1
+    trauma: f32,
+    /// Trauma removed per second
+    pub decay: f32,
+    /// Maximum translation offset in meters along each screen axis
+    pub max_translation: Vec2,
+    /// Maximum rotation in radians around the Z axis
+    pub max_rotation: f32,
+    /// Frequency of the shake noise
+    pub frequency: f32,
+    /// Time elapsed along the noise
+    time: f32,
+    /// Trauma during the previous update, used to detect new trauma
+    previous_trauma: f32,
+    /// Offset applied to the camera during the last update
+    applied: Option<(Vec2, f32)>,
+}
+
+impl Default for CameraShake2d {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CameraShake2d {
+    /// Creates a new CameraShake2d with default settings:
+    /// - Decay: 1.0 trauma per second
+    /// - Translation: up to 0.2 meters
+    /// - Rotation: up to 2 degrees
+    /// - Frequency: 15.0
+    pub fn new() -> Self {
+        Self {
+            trauma: 0.0,
+            decay: 1.0,
+            max_translation: Vec2::splat(0.2),
+            max_rotation: 2f32.to_radians(),
+            frequency: 15.0,
+            time: 0.0,
+            previous_trauma: 0.0,
+            applied: None,
+        }
+    }
+
+    /// Sets the maximum amplitude of the shake
+    ///
+    /// # Arguments
+    /// * `translation` - Maximum translation offset in meters along each screen axis
+    /// * `rotation` - Maximum rotation in radians around the Z axis
+    #[inline]
+    pub fn with_amplitude(mut self, translation: Vec2, rotation: f32) -> Self {
+        self.max_translation = translation;
+        self.max_rotation = rotation;
+        self
+    }
+
+    /// Sets the frequency of the shake noise
+    ///
+    /// # Arguments
+    /// * `frequency` - Noise frequency
+    #[inline]
+    pub fn with_frequency(mut self, frequency: f32) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    /// Sets the rate at which trauma decays
+    ///
+    /// # Arguments
+    /// * `decay` - Trauma removed per second
+    #[inline]
+    pub fn with_decay(mut self, decay: f32) -> Self {
+        self.decay = decay;
+        self
+    }
+
+    /// Adds trauma, clamped to the range 0 to 1
+    ///
+    /// # Arguments
+    /// * `trauma` - Trauma to add
+    #[inline]
+    pub fn add_trauma(&mut self, trauma: f32) {
+        self.trauma = (self.trauma + trauma).clamp(0.0, 1.0);
+    }
+
+    /// Returns the current trauma
+    #[inline]
+    pub fn trauma(&self) -> f32 {
+        self.trauma
+    }
+
+    /// Returns the current shake strength, the square of trauma
+    #[inline]
+    pub fn intensity(&self) -> f32 {
+        self.trauma * self.trauma
+    }
+}
+
+/// An event adding trauma to every [`CameraShake2d`] near a point in the world, such as an
+/// explosion. Cameras centered on the point receive the full trauma, falling off linearly
+/// to none at the radius.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ShakeImpulse2d {
+    /// World position of the shake source
+    pub position: Vec2,
+    /// Trauma added to cameras centered on the source
+    pub trauma: f32,
+    /// Distance in meters at which the trauma falls off to none
+    pub radius: f32,
+}
+
+impl ShakeImpulse2d {
+    /// Creates a new ShakeImpulse2d
+    ///
+    /// # Arguments
+    /// * `position` - World position of the shake source
+    /// * `trauma` - Trauma added to cameras centered on the source
+    /// * `radius` - Distance in meters at which the trauma falls off to none
+    pub fn new(position: Vec2, trauma: f32, radius: f32) -> Self {
+        Self {
+            position,
+            trauma,
+            radius,
+        }
+    }
+
+    /// Returns the trauma received by a camera centered at a point
+    ///
+    /// # Arguments
+    /// * `center` - World position of the camera center
+    /// * `units` - Scale of the world
+    pub fn trauma_at(&self, center: Vec2, units: &WorldUnits) -> f32 {
+        let radius = units.to_world(self.radius);
+        if radius <= 0.0 {
+            return 0.0;
+        }
+        self.trauma * (1.0 - center.distance(self.position) / radius).clamp(0.0, 1.0)
+    }
+}
+
+/// Removes the shake applied during the previous frame from 2D controller cameras
+///
+/// # Arguments
+/// * `camera_controllers` - Query for 2D controllers with shake
+/// * `camera_transforms` - Query for camera transforms to restore
+pub(crate) fn remove_shake_2d(
+    mut camera_controllers: Query<(&CameraController2d, &mut CameraShake2d)>,
+    mut camera_transforms: Query<&mut Transform, CameraFilter>,
+) {
+    for (controller, mut shake) in camera_controllers.iter_mut() {
+        let Some((translation, angle)) = shake.applied.take() else {
+            continue;
+        };
+        if let Ok(mut camera_transform) = camera_transforms.get_mut(controller.camera) {
+            camera_transform.translation -= translation.extend(0.0);
+            camera_transform.rotate_local_z(-angle);
+        }
+    }
+}
+
+/// Adds trauma from shake impulses, decays it and applies shake on top of the solved
+/// 2D controller cameras
+///
+/// # Arguments
+/// * `camera_controllers` - Query for 2D controllers with shake
+/// * `camera_transforms` - Query for camera transforms to modify
+/// * `impulses` - Event reader for shake impulses
+/// * `feedback` - Event writer for notable camera events
+/// * `units` - Resource describing the scale of the world
+/// * `time` - Clocks providing frame timing for each controller's time source
+/// * `skipped` - Controllers skipped for missing entities, warned about once
+pub(crate) fn apply_shake_2d(
+    mut camera_controllers: Query<(Entity, &CameraController2d, &mut CameraShake2d)>,
+    mut camera_transforms: Query<&mut Transform, CameraFilter>,
+    mut impulses: EventReader<ShakeImpulse2d>,
+    mut feedback: EventWriter<CameraFeedback>,
+    units: Res<WorldUnits>,
+    time: CameraTime,
+    mut skipped: Local<SkippedEntities>,
+) {
+    let impulses = impulses.read().copied().collect::<Vec<_>>();
+
+    for (entity, controller, mut shake) in camera_controllers.iter_mut() {
+        let dt = time.delta_secs(controller.time_source);
+        let Some(mut camera_transform) =
+            skipped.check(entity, camera_transforms.get_mut(controller.camera))
+        else {
+            continue;
+        };
+        let center = camera_transform.translation.truncate();
+        for impulse in impulses.iter() {
+            shake.add_trauma(impulse.trauma_at(center, &units));
+        }
+
+        // report newly added trauma
+        if shake.trauma > shake.previous_trauma {
+            feedback.write(CameraFeedback::new(
+                entity,
+                CameraFeedbackKind::Shake,
+                shake.trauma,
+            ));
+        }
+        shake.trauma = (shake.trauma - shake.decay * dt).max(0.0);
+        shake.previous_trauma = shake.trauma;
+
+        let intensity = shake.intensity();
+        if intensity <= 0.0 {
+            continue;
+        }
+        shake.time += dt * shake.frequency;
+        let t = shake.time;
+
+        // translation is along the rotated screen axes
+        let offset = units.to_world(
+            Vec2::new(noise::value_noise(0, t), noise::value_noise(1, t))
+                * shake.max_translation
+                * intensity,
+        );
+        let mut translation = (camera_transform.rotation * offset.extend(0.0)).truncate();
+        // keep pixel snapped cameras on whole pixels
+        if let Some(pixels_per_unit) = controller.pixel_snap.filter(|pixels| *pixels > 0.0) {
+            translation = (translation * pixels_per_unit).round() / pixels_per_unit;
+        }
+        let angle = noise::value_noise(2, t) * shake.max_rotation * intensity;
+
+        camera_transform.translation += translation.extend(0.0);
+        camera_transform.rotate_local_z(angle);
+        shake.applied = Some((translation, angle));
+    }
+}
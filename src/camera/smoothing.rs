@@ -0,0 +1,306 @@
+use bevy::prelude::*;
+use core::{f32::consts::LN_2, time::Duration};
+
+/// Response model used to smooth camera movement toward its goal
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Smoothing {
+    /// Exponential decay toward the goal, never overshoots.
+    /// An infinite decay rate moves instantly.
+    Exponential { decay: f32 },
+    /// Damped spring toward the goal, giving movement a sense of weight.
+    /// Damping below 1 overshoots, 1 is critically damped.
+    SpringDamper { frequency: f32, damping: f32 },
+    /// Critically damped smoothing reaching the goal in roughly the given time,
+    /// with a cap on speed
+    SmoothDamp { time: f32, max_speed: f32 },
+}
+
+impl Default for Smoothing {
+    fn default() -> Self {
+        Self::INSTANT
+    }
+}
+
+impl Smoothing {
+    /// Smoothing that moves instantly to the goal
+    pub const INSTANT: Self = Self::Exponential {
+        decay: f32::INFINITY,
+    };
+
+    /// Creates exponential smoothing from a smoothing factor,
+    /// larger values give smoother movement
+    ///
+    /// # Arguments
+    /// * `smoothing` - Smoothing factor, the inverse of the decay rate
+    #[inline]
+    pub fn exponential(smoothing: f32) -> Self {
+        Self::Exponential {
+            decay: 1.0 / smoothing,
+        }
+    }
+
+    /// Creates exponential smoothing that covers half the remaining distance to the goal
+    /// in the given time, where zero moves instantly
+    ///
+    /// # Arguments
+    /// * `half_life` - Time to cover half the remaining distance
+    #[inline]
+    pub fn from_half_life(half_life: Duration) -> Self {
+        Self::Exponential {
+            decay: half_life_to_decay(half_life),
+        }
+    }
+
+    /// Returns the time taken to cover half the remaining distance to the goal, which is
+    /// approximated from the equivalent decay rate for springs
+    #[inline]
+    pub fn half_life(&self) -> Duration {
+        decay_to_half_life(self.decay_rate())
+    }
+
+    /// Checks whether the smoothing moves instantly to the goal
+    #[inline]
+    pub fn is_instant(&self) -> bool {
+        matches!(self, Self::Exponential { decay } if !decay.is_finite())
+    }
+
+    /// Returns an equivalent exponential decay rate, used where only a rate is accepted
+    pub fn decay_rate(&self) -> f32 {
+        match *self {
+            Self::Exponential { decay } => decay,
+            Self::SpringDamper { frequency, damping } => {
+                std::f32::consts::TAU * frequency * damping.max(f32::EPSILON)
+            }
+            Self::SmoothDamp { time, .. } => 2.0 / time.max(f32::EPSILON),
+        }
+    }
+
+    /// Returns the smoothing slowed down by a factor, larger values give heavier movement
+    ///
+    /// # Arguments
+    /// * `factor` - Multiplier applied to the smoothing
+    pub fn scaled(self, factor: f32) -> Self {
+        match self {
+            Self::Exponential { decay } => Self::Exponential {
+                decay: decay / factor,
+            },
+            Self::SpringDamper { frequency, damping } => Self::SpringDamper {
+                frequency: frequency / factor,
+                damping,
+            },
+            Self::SmoothDamp { time, max_speed } => Self::SmoothDamp {
+                time: time * factor,
+                max_speed,
+            },
+        }
+    }
+
+    /// Moves a value toward a goal for one frame, returning the new value
+    ///
+    /// # Arguments
+    /// * `current` - Current value
+    /// * `target` - Goal value
+    /// * `velocity` - Velocity of the value, kept between frames
+    /// * `dt` - Time elapsed since last update in seconds
+    pub fn step(&self, current: f32, target: f32, velocity: &mut f32, dt: f32) -> f32 {
+        if self.is_instant() {
+            *velocity = 0.0;
+            return target;
+        }
+        if dt <= 0.0 {
+            return current;
+        }
+
+        match *self {
+            Self::Exponential { decay } => {
+                let mut value = current;
+                value.smooth_nudge(&target, decay, dt);
+                *velocity = (value - current) / dt;
+                value
+            }
+            Self::SpringDamper { frequency, damping } => {
+                // implicit integration stays stable for stiff springs and long frames
+                let omega = std::f32::consts::TAU * frequency;
+                let stiffness = omega * omega;
+                *velocity = (*velocity + stiffness * (target - current) * dt)
+                    / (1.0 + 2.0 * damping * omega * dt + stiffness * dt * dt);
+                current + *velocity * dt
+            }
+            Self::SmoothDamp { time, max_speed } => {
+                let time = time.max(1e-4);
+                let omega = 2.0 / time;
+                let x = omega * dt;
+                let decay = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+
+                let max_change = max_speed * time;
+                let change = (current - target).clamp(-max_change, max_change);
+                let goal = current - change;
+
+                let temp = (*velocity + omega * change) * dt;
+                *velocity = (*velocity - omega * temp) * decay;
+                let mut value = goal + (change + temp) * decay;
+
+                // prevent overshooting the original goal
+                if (target - current > 0.0) == (value > target) {
+                    value = target;
+                    *velocity = 0.0;
+                }
+                value
+            }
+        }
+    }
+}
+
+/// Converts a half-life into the exponential decay rate covering half the remaining
+/// distance in that time
+///
+/// # Arguments
+/// * `half_life` - Time to cover half the remaining distance
+pub(crate) fn half_life_to_decay(half_life: Duration) -> f32 {
+    LN_2 / half_life.as_secs_f32()
+}
+
+/// Converts an exponential decay rate into the time taken to cover half the remaining
+/// distance, saturating for rates that never get there
+///
+/// # Arguments
+/// * `decay` - Decay rate
+pub(crate) fn decay_to_half_life(decay: f32) -> Duration {
+    Duration::try_from_secs_f32(LN_2 / decay).unwrap_or(Duration::MAX)
+}
+
+/// Velocities of the smoothed channels of a controller, kept between frames
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct SmoothingVelocity {
+    /// Velocity of consumed 2D input
+    pub input: Vec2,
+    /// Velocity of consumed pan input
+    pub pan: Vec2,
+    /// Velocity of consumed zoom input
+    pub zoom: f32,
+    /// Velocity of consumed roll input
+    pub roll: f32,
+    /// Velocity of the followed distance or rail position
+    pub follow: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Steps a smoothing toward a goal for a number of fixed frames, returning every value
+    fn run(smoothing: Smoothing, target: f32, frames: usize, dt: f32) -> Vec<f32> {
+        let (mut value, mut velocity) = (0.0, 0.0);
+        (0..frames)
+            .map(|_| {
+                value = smoothing.step(value, target, &mut velocity, dt);
+                value
+            })
+            .collect()
+    }
+
+    #[test]
+    fn instant_reaches_the_goal_and_stops() {
+        let mut velocity = 5.0;
+        assert_eq!(Smoothing::INSTANT.step(0.0, 3.0, &mut velocity, 0.016), 3.0);
+        assert_eq!(velocity, 0.0);
+    }
+
+    #[test]
+    fn zero_time_step_holds_the_value() {
+        let smoothing = Smoothing::Exponential { decay: 10.0 };
+        let mut velocity = 0.0;
+        assert_eq!(smoothing.step(1.0, 3.0, &mut velocity, 0.0), 1.0);
+    }
+
+    #[test]
+    fn exponential_approaches_without_overshooting() {
+        let values = run(Smoothing::Exponential { decay: 10.0 }, 1.0, 120, 1.0 / 60.0);
+        assert!(values.windows(2).all(|pair| pair[1] >= pair[0]));
+        assert!(values.iter().all(|value| *value <= 1.0));
+        assert!((values.last().unwrap() - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn half_life_covers_half_the_distance() {
+        let smoothing = Smoothing::from_half_life(Duration::from_millis(500));
+        let mut velocity = 0.0;
+        let value = smoothing.step(0.0, 1.0, &mut velocity, 0.5);
+        assert!((value - 0.5).abs() < 1e-4);
+        assert!((smoothing.half_life().as_secs_f32() - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn critically_damped_spring_settles_without_overshooting() {
+        let smoothing = Smoothing::SpringDamper {
+            frequency: 2.0,
+            damping: 1.0,
+        };
+        let values = run(smoothing, 1.0, 240, 1.0 / 60.0);
+        assert!(values.iter().all(|value| *value <= 1.0 + 1e-4));
+        assert!((values.last().unwrap() - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn underdamped_spring_overshoots() {
+        let smoothing = Smoothing::SpringDamper {
+            frequency: 2.0,
+            damping: 0.2,
+        };
+        let values = run(smoothing, 1.0, 120, 1.0 / 60.0);
+        assert!(values.iter().any(|value| *value > 1.0));
+    }
+
+    #[test]
+    fn stiff_spring_stays_stable_over_long_frames() {
+        let smoothing = Smoothing::SpringDamper {
+            frequency: 50.0,
+            damping: 1.0,
+        };
+        let values = run(smoothing, 1.0, 10, 0.5);
+        assert!(
+            values
+                .iter()
+                .all(|value| value.is_finite() && value.abs() <= 1.0 + 1e-3)
+        );
+    }
+
+    #[test]
+    fn smooth_damp_respects_max_speed_and_never_overshoots() {
+        let smoothing = Smoothing::SmoothDamp {
+            time: 0.2,
+            max_speed: 2.0,
+        };
+        let dt = 1.0 / 60.0;
+        let values = run(smoothing, 10.0, 600, dt);
+        let mut previous = 0.0;
+        for value in values.iter() {
+            assert!(*value <= 10.0);
+            // the speed cap applies to the goal chased each frame, allowing some slack
+            assert!((value - previous) / dt <= 2.0 * 1.5);
+            previous = *value;
+        }
+        assert!((values.last().unwrap() - 10.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn scaling_slows_every_model_down() {
+        let models = [
+            Smoothing::Exponential { decay: 10.0 },
+            Smoothing::SpringDamper {
+                frequency: 2.0,
+                damping: 1.0,
+            },
+            Smoothing::SmoothDamp {
+                time: 0.2,
+                max_speed: f32::INFINITY,
+            },
+        ];
+        for smoothing in models {
+            let base = run(smoothing, 1.0, 10, 1.0 / 60.0);
+            let slowed = run(smoothing.scaled(2.0), 1.0, 10, 1.0 / 60.0);
+            assert!(slowed.last() < base.last(), "{smoothing:?}");
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+
+use super::{CameraAnchor, CameraBuffer, CameraController, CameraView};
+
+/// A saved camera view that can be restored later, such as editor bookmarks or numpad views
+#[derive(Clone)]
+pub struct CameraSnapshot {
+    /// Rotation of the camera
+    pub rotation: Quat,
+    /// Anchor of the controller, including the orbit distance
+    pub anchor: CameraAnchor,
+    /// View of the controller
+    pub view: CameraView,
+    /// Offset of the controller, in meters
+    pub offset: Vec3,
+}
+
+/// A pending request to save or restore a snapshot slot
+#[derive(Clone, Copy)]
+pub(crate) enum SnapshotRequest {
+    /// Save the current view into the slot
+    Save(u32),
+    /// Restore the view from the slot, blending over the duration in seconds
+    Restore(u32, f32),
+}
+
+/// Handles snapshot requests made with [`CameraController::save_view`] and
+/// [`CameraController::restore_view`], blending restored views with a transition
+///
+/// # Arguments
+/// * `camera_controllers` - Query for controllers and buffers
+pub(crate) fn update_snapshots(
+    mut camera_controllers: Query<(&mut CameraController, &mut CameraBuffer)>,
+) {
+    for (mut controller, mut buffer) in camera_controllers.iter_mut() {
+        if controller.snapshot_requests.is_empty() {
+            continue;
+        }
+        let requests = core::mem::take(&mut controller.snapshot_requests);
+        for request in requests {
+            match request {
+                SnapshotRequest::Save(slot) => {
+                    let snapshot = CameraSnapshot {
+                        rotation: buffer.rotation,
+                        anchor: controller.anchor.clone(),
+                        view: controller.view.clone(),
+                        offset: controller.offset,
+                    };
+                    controller.snapshots.insert(slot, snapshot);
+                }
+                SnapshotRequest::Restore(slot, duration) => {
+                    let Some(snapshot) = controller.snapshots.get(&slot).cloned() else {
+                        continue;
+                    };
+                    buffer.rotation = snapshot.rotation;
+                    // discard pending input so the restored view is not immediately moved
+                    buffer.clear();
+                    controller.offset = snapshot.offset;
+                    controller.transition_to(
+                        snapshot.anchor,
+                        snapshot.view,
+                        duration,
+                        EaseFunction::SmoothStep,
+                    );
+                }
+            }
+        }
+    }
+}
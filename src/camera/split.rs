@@ -0,0 +1,174 @@
+use bevy::{prelude::*, render::camera::Viewport};
+
+/// A component tagging controllers and cameras with the local player they belong to,
+/// so input and screen space can be routed per player in split-screen games.
+///
+/// Every controller updates only its own camera and buffer, so N controllers with N cameras
+/// never affect each other. Input systems should feed a controller's [`CameraBuffer`](super::CameraBuffer)
+/// only from the devices of its player, checked with [`CameraPlayer::accepts_mouse`]
+/// and [`CameraPlayer::accepts_gamepad`].
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CameraPlayer {
+    /// Index of the local player, starting at 0
+    pub index: usize,
+    /// Gamepad entity driving this player's camera
+    pub gamepad: Option<Entity>,
+    /// Whether the keyboard and mouse drive this player's camera
+    pub keyboard_and_mouse: bool,
+}
+
+impl CameraPlayer {
+    /// Creates a new CameraPlayer without a gamepad, where only the first player
+    /// uses the keyboard and mouse
+    ///
+    /// # Arguments
+    /// * `index` - Index of the local player
+    pub fn new(index: usize) -> Self {
+        Self {
+            index,
+            gamepad: None,
+            keyboard_and_mouse: index == 0,
+        }
+    }
+
+    /// Sets the gamepad driving this player's camera
+    ///
+    /// # Arguments
+    /// * `gamepad` - Gamepad entity
+    #[inline]
+    pub fn with_gamepad(mut self, gamepad: Entity) -> Self {
+        self.gamepad = Some(gamepad);
+        self
+    }
+
+    /// Sets whether the keyboard and mouse drive this player's camera
+    ///
+    /// # Arguments
+    /// * `keyboard_and_mouse` - Whether keyboard and mouse input is accepted
+    #[inline]
+    pub fn with_keyboard_and_mouse(mut self, keyboard_and_mouse: bool) -> Self {
+        self.keyboard_and_mouse = keyboard_and_mouse;
+        self
+    }
+
+    /// Checks whether keyboard and mouse input should reach this player's camera
+    #[inline]
+    pub fn accepts_mouse(&self) -> bool {
+        self.keyboard_and_mouse
+    }
+
+    /// Checks whether input from a gamepad should reach this player's camera
+    ///
+    /// # Arguments
+    /// * `gamepad` - Gamepad entity the input came from
+    #[inline]
+    pub fn accepts_gamepad(&self, gamepad: Entity) -> bool {
+        self.gamepad == Some(gamepad)
+    }
+}
+
+/// How the screen is divided between players
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SplitScreenLayout {
+    /// Side by side for two players, and a grid for more
+    #[default]
+    Auto,
+    /// Stacked from top to bottom
+    Rows,
+    /// Side by side from left to right
+    Columns,
+}
+
+/// Resource dividing the render target between cameras tagged with a [`CameraPlayer`].
+///
+/// While present, each tagged camera gets a viewport, ordered by player index, and a
+/// render order matching its place so cameras sharing a window never conflict.
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub struct SplitScreen {
+    /// How the screen is divided between players
+    pub layout: SplitScreenLayout,
+}
+
+impl SplitScreen {
+    /// Creates a new SplitScreen
+    ///
+    /// # Arguments
+    /// * `layout` - How the screen is divided between players
+    pub fn new(layout: SplitScreenLayout) -> Self {
+        Self { layout }
+    }
+
+    /// Returns the number of columns and rows used for a number of players
+    ///
+    /// # Arguments
+    /// * `count` - Number of players sharing the screen
+    pub fn grid(&self, count: usize) -> UVec2 {
+        let count = count.max(1) as u32;
+        match self.layout {
+            SplitScreenLayout::Rows => UVec2::new(1, count),
+            SplitScreenLayout::Columns => UVec2::new(count, 1),
+            SplitScreenLayout::Auto => {
+                let columns = (count as f32).sqrt().ceil() as u32;
+                UVec2::new(columns, count.div_ceil(columns))
+            }
+        }
+    }
+
+    /// Returns the viewport of a player's place on the screen
+    ///
+    /// # Arguments
+    /// * `slot` - Place of the player, counted left to right and top to bottom
+    /// * `count` - Number of players sharing the screen
+    /// * `target_size` - Physical size of the render target
+    pub fn viewport(&self, slot: usize, count: usize, target_size: UVec2) -> Viewport {
+        let grid = self.grid(count);
+        let cell = (target_size / grid).max(UVec2::ONE);
+        let slot = slot as u32;
+        Viewport {
+            physical_position: UVec2::new(slot % grid.x, slot / grid.x) * cell,
+            physical_size: cell,
+            ..default()
+        }
+    }
+}
+
+/// Assigns split-screen viewports to player cameras, ordered by player index
+///
+/// # Arguments
+/// * `split_screen` - Resource describing the layout, if split-screen is enabled
+/// * `cameras` - Query for player cameras
+pub(crate) fn update_split_screen(
+    split_screen: Option<Res<SplitScreen>>,
+    mut cameras: Query<(Entity, &mut Camera, &CameraPlayer)>,
+) {
+    let Some(split_screen) = split_screen else {
+        return;
+    };
+
+    let mut players: Vec<_> = cameras
+        .iter()
+        .map(|(entity, _, player)| (player.index, entity))
+        .collect();
+    players.sort_unstable();
+    let count = players.len();
+
+    for (slot, (_, entity)) in players.into_iter().enumerate() {
+        let Ok((_, mut camera, _)) = cameras.get_mut(entity) else {
+            continue;
+        };
+        let Some(target_size) = camera.physical_target_size() else {
+            continue;
+        };
+        let viewport = split_screen.viewport(slot, count, target_size);
+        // avoid triggering change detection when the layout is unchanged
+        if camera.viewport.as_ref().is_none_or(|current| {
+            current.physical_position != viewport.physical_position
+                || current.physical_size != viewport.physical_size
+        }) {
+            camera.viewport = Some(viewport);
+        }
+        if camera.order != slot as isize {
+            camera.order = slot as isize;
+        }
+    }
+}
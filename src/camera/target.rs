@@ -0,0 +1,123 @@
+use bevy::{
+    ecs::query::{QueryData, QueryFilter},
+    platform::collections::HashSet,
+    prelude::*,
+};
+
+use super::VirtualCamera;
+
+/// Filter for entities a controller can drive, either a camera or a virtual camera
+pub(crate) type CameraFilter = Or<(With<Camera>, With<VirtualCamera>)>;
+
+/// Filter for controller entities and targets, excluding anything a controller drives
+pub(crate) type TargetFilter = (Without<Camera>, Without<VirtualCamera>);
+
+/// Query data for reading the transform of controller entities and targets,
+/// accepting entities with either a `Transform` or only a `GlobalTransform`
+#[derive(QueryData)]
+pub struct TargetTransform {
+    global: Option<&'static GlobalTransform>,
+    local: Option<&'static Transform>,
+    parented: Has<ChildOf>,
+}
+
+impl TargetTransformItem<'_> {
+    /// Returns the world space transform of the entity, preferring `GlobalTransform`.
+    /// Root entities use their `Transform`, which matches the `GlobalTransform`
+    /// but is already up to date for the current frame.
+    pub fn transform(&self) -> Option<Transform> {
+        match (self.global, self.local) {
+            (_, Some(local)) if !self.parented => Some(*local),
+            (Some(global), _) => Some(global.compute_transform()),
+            (None, local) => local.copied(),
+        }
+    }
+}
+
+/// Gets the world space transform of an entity from a [`TargetTransform`] query
+///
+/// # Arguments
+/// * `query` - Query for target transforms
+/// * `entity` - Entity to get the transform of
+pub(crate) fn get_transform<F: QueryFilter>(
+    query: &Query<TargetTransform, F>,
+    entity: Entity,
+) -> Result<Transform, BevyError> {
+    query
+        .get(entity)?
+        .transform()
+        .ok_or_else(|| format!("entity {entity} has no Transform or GlobalTransform").into())
+}
+
+/// Gets the world space transform of a camera's parent, or `None` for cameras at the root
+///
+/// # Arguments
+/// * `parents` - Query for the parents of cameras
+/// * `query` - Query for target transforms
+/// * `camera` - Camera to get the parent transform of
+pub(crate) fn get_parent_transform<F: QueryFilter>(
+    parents: &Query<&ChildOf, CameraFilter>,
+    query: &Query<TargetTransform, F>,
+    camera: Entity,
+) -> Result<Option<Transform>, BevyError> {
+    match parents.get(camera) {
+        Ok(child_of) => get_transform(query, child_of.parent()).map(Some),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Converts a camera's local transform into world space
+///
+/// # Arguments
+/// * `local` - Transform of the camera relative to its parent
+/// * `parent` - World space transform of the parent, if any
+pub(crate) fn to_world(local: Transform, parent: Option<Transform>) -> Transform {
+    match parent {
+        Some(parent) => parent.mul_transform(local),
+        None => local,
+    }
+}
+
+/// Converts a world space transform into the local space of a camera's parent
+///
+/// # Arguments
+/// * `world` - World space transform of the camera
+/// * `parent` - World space transform of the parent, if any
+pub(crate) fn to_local(world: Transform, parent: Option<Transform>) -> Transform {
+    match parent {
+        Some(parent) => GlobalTransform::from(world).reparented_to(&GlobalTransform::from(parent)),
+        None => world,
+    }
+}
+
+/// Entities a system skipped because an entity they need is missing, so one despawned
+/// camera or target only stops the controller using it and is warned about once until it
+/// recovers
+#[derive(Default)]
+pub(crate) struct SkippedEntities(HashSet<Entity>);
+
+impl SkippedEntities {
+    /// Returns the value of an entity's lookup, or warns the first time it fails
+    ///
+    /// # Arguments
+    /// * `entity` - Entity the lookup was made for, usually a controller
+    /// * `result` - Result of looking up an entity the controller needs
+    pub(crate) fn check<T, E: core::fmt::Display>(
+        &mut self,
+        entity: Entity,
+        result: Result<T, E>,
+    ) -> Option<T> {
+        match result {
+            Ok(value) => {
+                self.0.remove(&entity);
+                Some(value)
+            }
+            Err(error) => {
+                if self.0.insert(entity) {
+                    warn!("camera update skipped {entity}: {error}");
+                }
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use super::{CameraController, CameraController2d};
+
+/// Clock a controller advances its smoothing and animation with
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CameraTimeSource {
+    /// Game time of the schedule the controller is solved in, which is scaled with slow
+    /// motion and stops while the game is paused
+    #[default]
+    Virtual,
+    /// Wall clock time, unaffected by time scaling or pausing, so the camera keeps its
+    /// pace in slow motion and can still be moved in pause menus
+    Real,
+    /// The fixed timestep, which controllers solved with
+    /// [`FixedCameraUpdate`](super::FixedCameraUpdate) always advance with
+    Fixed,
+}
+
+impl CameraTimeSource {
+    /// Returns the time source of whichever controller an entity has, or the default
+    ///
+    /// # Arguments
+    /// * `controller` - 3D controller on the entity, if any
+    /// * `controller2d` - 2D controller on the entity, if any
+    pub(crate) fn of(
+        controller: Option<&CameraController>,
+        controller2d: Option<&CameraController2d>,
+    ) -> Self {
+        match (controller, controller2d) {
+            (Some(controller), _) => controller.time_source,
+            (None, Some(controller)) => controller.time_source,
+            (None, None) => Self::default(),
+        }
+    }
+}
+
+/// Clocks available to controllers, read according to their [`CameraTimeSource`]
+#[derive(SystemParam)]
+pub(crate) struct CameraTime<'w> {
+    /// Default clock of the running schedule
+    time: Res<'w, Time>,
+    /// Wall clock
+    real: Res<'w, Time<Real>>,
+    /// Fixed timestep clock
+    fixed: Res<'w, Time<Fixed>>,
+}
+
+impl CameraTime<'_> {
+    /// Returns the seconds elapsed since the last update on the given clock
+    ///
+    /// # Arguments
+    /// * `source` - Clock to read
+    pub(crate) fn delta_secs(&self, source: CameraTimeSource) -> f32 {
+        match source {
+            CameraTimeSource::Virtual => self.time.delta_secs(),
+            CameraTimeSource::Real => self.real.delta_secs(),
+            CameraTimeSource::Fixed => self.fixed.delta_secs(),
+        }
+    }
+
+    /// Returns the seconds elapsed since a controller was last solved, which is always the
+    /// fixed timestep for controllers solved with
+    /// [`FixedCameraUpdate`](super::FixedCameraUpdate) whatever their time source
+    ///
+    /// # Arguments
+    /// * `source` - Clock the controller advances with
+    /// * `fixed_step` - Whether the controller is solved in the fixed timestep
+    pub(crate) fn solve_delta_secs(&self, source: CameraTimeSource, fixed_step: bool) -> f32 {
+        if fixed_step {
+            self.fixed.delta_secs()
+        } else {
+            self.delta_secs(source)
+        }
+    }
+
+    /// Returns the seconds elapsed since startup on the given clock
+    ///
+    /// # Arguments
+    /// * `source` - Clock to read
+    pub(crate) fn elapsed_secs(&self, source: CameraTimeSource) -> f32 {
+        match source {
+            CameraTimeSource::Virtual => self.time.elapsed_secs(),
+            CameraTimeSource::Real => self.real.elapsed_secs(),
+            CameraTimeSource::Fixed => self.fixed.elapsed_secs(),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+
+/// A blend from the camera pose before an anchor or view switch to the newly solved pose,
+/// started with [`CameraController::transition_to`](super::CameraController::transition_to)
+#[derive(Clone, Debug)]
+pub struct CameraTransition {
+    /// Duration of the blend in seconds
+    pub duration: f32,
+    /// Easing applied to the blend weight
+    pub ease: EaseFunction,
+    /// Time elapsed since the blend started
+    elapsed: f32,
+    /// Camera pose when the blend started, captured on the first update
+    from: Option<Transform>,
+}
+
+impl CameraTransition {
+    /// Creates a new CameraTransition
+    ///
+    /// # Arguments
+    /// * `duration` - Duration of the blend in seconds
+    /// * `ease` - Easing applied to the blend weight
+    pub fn new(duration: f32, ease: EaseFunction) -> Self {
+        Self {
+            duration,
+            ease,
+            elapsed: 0.0,
+            from: None,
+        }
+    }
+
+    /// Returns the eased blend weight from 0 at the start to 1 once finished
+    pub fn weight(&self) -> f32 {
+        if self.duration <= 0.0 {
+            return 1.0;
+        }
+        self.ease
+            .sample_clamped((self.elapsed / self.duration).clamp(0.0, 1.0))
+    }
+
+    /// Checks whether the blend has finished
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Advances the blend and returns the pose between the starting and solved pose
+    ///
+    /// # Arguments
+    /// * `previous` - Camera pose before this update, used as the starting pose
+    /// * `solved` - Camera pose solved for the new anchor and view
+    /// * `dt` - Time elapsed since last update in seconds
+    pub(crate) fn blend(&mut self, previous: Transform, solved: Transform, dt: f32) -> Transform {
+        let from = *self.from.get_or_insert(previous);
+        self.elapsed += dt;
+
+        let weight = self.weight();
+        Transform {
+            translation: from.translation.lerp(solved.translation, weight),
+            rotation: from.rotation.slerp(solved.rotation, weight),
+            scale: solved.scale,
+        }
+    }
+}
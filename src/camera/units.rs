@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+/// Resource describing the scale of the world, so controller settings can be authored
+/// in meters and shared between projects with different world units.
+///
+/// Distances, offsets, translation sensitivity and speeds on controllers are interpreted
+/// in meters and multiplied by [`WorldUnits::scale`] when applied. Angles are unaffected.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct WorldUnits {
+    /// Number of world units in one meter
+    pub scale: f32,
+}
+
+impl Default for WorldUnits {
+    fn default() -> Self {
+        Self::METERS
+    }
+}
+
+impl WorldUnits {
+    /// World measured in meters
+    pub const METERS: Self = Self { scale: 1.0 };
+    /// World measured in feet
+    pub const FEET: Self = Self { scale: 3.280_84 };
+    /// World measured in centimeters
+    pub const CENTIMETERS: Self = Self { scale: 100.0 };
+
+    /// World measured in pixels, such as 2D games
+    ///
+    /// # Arguments
+    /// * `pixels_per_meter` - Number of pixels in one meter
+    pub fn pixels(pixels_per_meter: f32) -> Self {
+        Self {
+            scale: pixels_per_meter,
+        }
+    }
+
+    /// Converts a length in meters into world units
+    ///
+    /// # Arguments
+    /// * `meters` - Length in meters
+    #[inline]
+    pub fn to_world<T: core::ops::Mul<f32, Output = T>>(&self, meters: T) -> T {
+        meters * self.scale
+    }
+
+    /// Converts a length in world units into meters
+    ///
+    /// # Arguments
+    /// * `world` - Length in world units
+    #[inline]
+    pub fn to_meters<T: core::ops::Mul<f32, Output = T>>(&self, world: T) -> T {
+        world * self.scale.recip()
+    }
+}
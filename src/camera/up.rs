@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+
+use super::{CameraBuffer, CameraController, target::CameraFilter, time::CameraTime};
+
+/// A component giving a [`CameraController`] a changing up direction, such as the normalized
+/// opposite of gravity on a spherical planet or the normal of the surface being walked on.
+///
+/// Game code writes [`CameraUp::up`] each frame. The controller's yaw axis turns toward it
+/// before input is consumed, carrying the camera's orientation along, so yaw, pitch and
+/// pitch limits stay relative to the current up direction.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct CameraUp {
+    /// Up direction the yaw axis turns toward
+    pub up: Dir3,
+    /// Rate at which the yaw axis turns toward the up direction with smooth interpolation
+    decay_rate: f32,
+}
+
+impl CameraUp {
+    /// Creates a new CameraUp without smoothing
+    ///
+    /// # Arguments
+    /// * `up` - Initial up direction
+    pub fn new(up: Dir3) -> Self {
+        Self {
+            up,
+            decay_rate: f32::INFINITY,
+        }
+    }
+
+    /// Sets smoothing factor for changes of the up direction.
+    /// Larger values give smoother movement.
+    ///
+    /// # Arguments
+    /// * `smoothing` - Smoothing factor for the yaw axis
+    #[inline]
+    pub fn with_smoothing(mut self, smoothing: f32) -> Self {
+        self.decay_rate = 1.0 / smoothing;
+        self
+    }
+
+    /// Sets the up direction the yaw axis turns toward
+    ///
+    /// # Arguments
+    /// * `up` - Up direction
+    #[inline]
+    pub fn set(&mut self, up: Dir3) {
+        self.up = up;
+    }
+}
+
+/// Turns the yaw axis of controllers toward their up direction, rotating the buffered
+/// and current camera orientation by the same amount
+///
+/// # Arguments
+/// * `camera_controllers` - Query for controllers, their buffers and up directions
+/// * `camera_transforms` - Query for camera transforms
+/// * `time` - Clocks providing frame timing for each controller's time source
+pub(crate) fn align_up_axes(
+    mut camera_controllers: Query<(&mut CameraController, &mut CameraBuffer, &CameraUp)>,
+    mut camera_transforms: Query<&mut Transform, CameraFilter>,
+    time: CameraTime,
+) {
+    for (mut controller, mut buffer, camera_up) in camera_controllers.iter_mut() {
+        let dt = time.delta_secs(controller.time_source);
+        let current = controller.yaw_axis;
+        if current == camera_up.up {
+            continue;
+        }
+        let axis = if camera_up.decay_rate.is_finite() {
+            current.slerp(camera_up.up, 1.0 - (-camera_up.decay_rate * dt).exp())
+        } else {
+            camera_up.up
+        };
+
+        // carry the camera along so its yaw and pitch relative to the up direction are kept
+        let arc = Quat::from_rotation_arc(current.as_vec3(), axis.as_vec3());
+        buffer.rotation = (arc * buffer.rotation).normalize();
+        if let Ok(mut camera_transform) = camera_transforms.get_mut(controller.camera) {
+            camera_transform.rotation = (arc * camera_transform.rotation).normalize();
+        }
+        controller.yaw_axis = axis;
+    }
+}
@@ -0,0 +1,273 @@
+use bevy::prelude::*;
+
+/// Duration and easing of a blend between two virtual cameras
+#[derive(Clone, Copy, Debug)]
+pub struct VirtualBlend {
+    /// Duration of the blend in seconds, where 0 cuts instantly
+    pub duration: f32,
+    /// Easing applied to the blend weight
+    pub ease: EaseFunction,
+}
+
+impl Default for VirtualBlend {
+    fn default() -> Self {
+        Self::new(1.0, EaseFunction::SmoothStep)
+    }
+}
+
+impl VirtualBlend {
+    /// Blend that cuts to the new virtual camera instantly
+    pub const CUT: Self = Self {
+        duration: 0.0,
+        ease: EaseFunction::Linear,
+    };
+
+    /// Creates a new VirtualBlend
+    ///
+    /// # Arguments
+    /// * `duration` - Duration of the blend in seconds
+    /// * `ease` - Easing applied to the blend weight
+    pub fn new(duration: f32, ease: EaseFunction) -> Self {
+        Self { duration, ease }
+    }
+}
+
+/// A lightweight camera pose that a [`CameraBrain`] can blend its camera toward.
+///
+/// The pose is the entity's own `Transform`, and the field of view is taken from its
+/// `Projection`. Virtual cameras can be placed by hand, animated, or driven like a real
+/// camera by pointing a [`CameraController`](super::CameraController) at them, so each one
+/// keeps its own anchor, view and settings. A virtual camera must not have a `Camera`.
+#[derive(Component, Clone, Debug)]
+#[require(Transform, Projection)]
+pub struct VirtualCamera {
+    /// Entity of the camera with the brain this virtual camera feeds
+    pub brain: Entity,
+    /// Priority when several virtual cameras are active, higher values win
+    pub priority: i32,
+    /// Optional blend used when this virtual camera goes live, replacing the brain's default
+    pub blend: Option<VirtualBlend>,
+    /// Whether this virtual camera competes to go live
+    active: bool,
+}
+
+impl VirtualCamera {
+    /// Creates a new active VirtualCamera with priority 0, using the brain's default blend
+    ///
+    /// # Arguments
+    /// * `brain` - Entity of the camera with the brain
+    pub fn new(brain: Entity) -> Self {
+        Self {
+            brain,
+            priority: 0,
+            blend: None,
+            active: true,
+        }
+    }
+
+    /// Sets the priority of this virtual camera
+    ///
+    /// # Arguments
+    /// * `priority` - Priority, higher values win
+    #[inline]
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the blend used when this virtual camera goes live
+    ///
+    /// # Arguments
+    /// * `duration` - Duration of the blend in seconds
+    /// * `ease` - Easing applied to the blend weight
+    #[inline]
+    pub fn with_blend(mut self, duration: f32, ease: EaseFunction) -> Self {
+        self.blend = Some(VirtualBlend::new(duration, ease));
+        self
+    }
+
+    /// Lets this virtual camera compete to go live again
+    #[inline]
+    pub fn activate(&mut self) {
+        self.active = true;
+    }
+
+    /// Stops this virtual camera from going live, handing the brain to the next one
+    #[inline]
+    pub fn deactivate(&mut self) {
+        self.active = false;
+    }
+
+    /// Checks whether this virtual camera competes to go live
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+}
+
+/// A component for cameras that follow the highest priority active [`VirtualCamera`]
+/// feeding them, blending between virtual cameras whenever the live one changes.
+///
+/// A blend follows the outgoing virtual camera as it keeps moving. A blend interrupted by
+/// another switch continues from the camera's current pose. The field of view is blended
+/// as well when both the camera and the virtual cameras use perspective projections.
+#[derive(Component, Clone, Debug, Default)]
+#[require(Camera, Projection)]
+pub struct CameraBrain {
+    /// Blend used by virtual cameras without their own
+    pub default_blend: VirtualBlend,
+    /// Virtual camera the camera follows
+    live: Option<Entity>,
+    /// Blend in progress toward the live virtual camera
+    blend: Option<BrainBlend>,
+}
+
+impl CameraBrain {
+    /// Creates a new CameraBrain with a 1 second smooth step default blend
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the blend used by virtual cameras without their own
+    ///
+    /// # Arguments
+    /// * `duration` - Duration of the blend in seconds
+    /// * `ease` - Easing applied to the blend weight
+    #[inline]
+    pub fn with_default_blend(mut self, duration: f32, ease: EaseFunction) -> Self {
+        self.default_blend = VirtualBlend::new(duration, ease);
+        self
+    }
+
+    /// Returns the virtual camera the camera follows, if any is active
+    #[inline]
+    pub fn live(&self) -> Option<Entity> {
+        self.live
+    }
+
+    /// Checks whether the camera is blending toward the live virtual camera
+    #[inline]
+    pub fn is_blending(&self) -> bool {
+        self.blend.is_some()
+    }
+}
+
+/// Pose and field of view of a camera or virtual camera
+#[derive(Clone, Copy, Debug)]
+struct VirtualPose {
+    /// Translation and rotation of the pose
+    transform: Transform,
+    /// Vertical field of view in radians, for perspective projections
+    fov: Option<f32>,
+}
+
+impl VirtualPose {
+    fn new(transform: &Transform, projection: Option<&Projection>) -> Self {
+        Self {
+            transform: *transform,
+            fov: match projection {
+                Some(Projection::Perspective(perspective)) => Some(perspective.fov),
+                _ => None,
+            },
+        }
+    }
+
+    fn lerp(&self, to: &Self, weight: f32) -> Self {
+        Self {
+            transform: Transform {
+                translation: self
+                    .transform
+                    .translation
+                    .lerp(to.transform.translation, weight),
+                rotation: self.transform.rotation.slerp(to.transform.rotation, weight),
+                scale: to.transform.scale,
+            },
+            fov: match (self.fov, to.fov) {
+                (Some(from), Some(to)) => Some(from + (to - from) * weight),
+                (_, to) => to,
+            },
+        }
+    }
+}
+
+/// A blend in progress between two virtual cameras
+#[derive(Clone, Debug)]
+struct BrainBlend {
+    /// Outgoing virtual camera, or `None` when continuing from a frozen pose
+    from: Option<Entity>,
+    /// Last known pose of the outgoing virtual camera
+    from_pose: VirtualPose,
+    /// Duration and easing of the blend
+    settings: VirtualBlend,
+    /// Time elapsed since the blend started
+    elapsed: f32,
+}
+
+/// Moves brain cameras to their live virtual camera, blending when it changes
+///
+/// # Arguments
+/// * `brains` - Query for brain cameras and their transforms and projections
+/// * `virtual_cameras` - Query for virtual cameras and their poses
+/// * `time` - Resource providing frame timing information
+pub(crate) fn update_brains(
+    mut brains: Query<(Entity, &mut CameraBrain, &mut Transform, &mut Projection), With<Camera>>,
+    virtual_cameras: Query<(Entity, &VirtualCamera, &Transform, &Projection), Without<Camera>>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, mut brain, mut camera_transform, mut projection) in brains.iter_mut() {
+        // the highest priority active virtual camera goes live, with ties going to the oldest
+        let Some((live, virtual_camera, transform, virtual_projection)) = virtual_cameras
+            .iter()
+            .filter(|(_, virtual_camera, ..)| {
+                virtual_camera.brain == entity && virtual_camera.is_active()
+            })
+            .max_by(|a, b| a.1.priority.cmp(&b.1.priority).then(b.0.cmp(&a.0)))
+        else {
+            brain.live = None;
+            brain.blend = None;
+            continue;
+        };
+
+        if brain.live != Some(live) {
+            let settings = virtual_camera.blend.unwrap_or(brain.default_blend);
+            brain.blend = match brain.live {
+                Some(previous) if settings.duration > 0.0 => Some(BrainBlend {
+                    // an interrupted blend continues from where the camera is now
+                    from: (!brain.is_blending()).then_some(previous),
+                    from_pose: VirtualPose::new(&camera_transform, Some(projection.as_ref())),
+                    settings,
+                    elapsed: 0.0,
+                }),
+                _ => None,
+            };
+            brain.live = Some(live);
+        }
+
+        let mut pose = VirtualPose::new(transform, Some(virtual_projection));
+        if let Some(blend) = brain.blend.as_mut() {
+            // keep following the outgoing virtual camera while it exists
+            if let Some((_, _, from_transform, from_projection)) =
+                blend.from.and_then(|from| virtual_cameras.get(from).ok())
+            {
+                blend.from_pose = VirtualPose::new(from_transform, Some(from_projection));
+            }
+            blend.elapsed += dt;
+
+            let t = (blend.elapsed / blend.settings.duration).clamp(0.0, 1.0);
+            pose = blend
+                .from_pose
+                .lerp(&pose, blend.settings.ease.sample_clamped(t));
+            if t >= 1.0 {
+                brain.blend = None;
+            }
+        }
+
+        camera_transform.translation = pose.transform.translation;
+        camera_transform.rotation = pose.transform.rotation;
+        if let (Some(fov), Projection::Perspective(perspective)) = (pose.fov, projection.as_mut()) {
+            perspective.fov = fov;
+        }
+    }
+}
@@ -0,0 +1,216 @@
+#[cfg(feature = "avian3d")]
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use super::{
+    CameraAnchor, CameraController, CameraView,
+    target::{self, SkippedEntities, TargetFilter, TargetTransform},
+};
+
+/// Volume of a [`CameraZone`]
+#[derive(Clone, Debug)]
+pub enum ZoneVolume {
+    /// Box with the given half size in the zone entity's local space
+    Box { half_extents: Vec3 },
+    /// Uses the avian3d `Collider` on the zone entity
+    #[cfg(feature = "avian3d")]
+    Collider,
+}
+
+/// A volume that overrides the anchor, view and offset of controllers whose entity is
+/// inside it, such as fixed cameras or per-room camera behavior. The controller blends
+/// to the zone's settings on entry and back to its own settings on exit.
+#[derive(Component, Clone)]
+#[require(Transform)]
+pub struct CameraZone {
+    /// Volume controllers need to be inside of
+    pub volume: ZoneVolume,
+    /// Anchor used while inside the zone
+    pub anchor: CameraAnchor,
+    /// View used while inside the zone
+    pub view: CameraView,
+    /// Optional offset used while inside the zone, in meters
+    pub offset: Option<Vec3>,
+    /// Zones with higher priority win where zones overlap
+    pub priority: i32,
+    /// Duration of the blend when entering or leaving the zone, in seconds
+    pub blend: f32,
+    /// Easing applied to the blend
+    pub ease: EaseFunction,
+}
+
+impl CameraZone {
+    /// Creates a new CameraZone with a priority of 0 and a smooth 0.5 second blend
+    ///
+    /// # Arguments
+    /// * `volume` - Volume controllers need to be inside of
+    /// * `anchor` - Anchor used while inside the zone
+    /// * `view` - View used while inside the zone
+    pub fn new(volume: ZoneVolume, anchor: CameraAnchor, view: CameraView) -> Self {
+        Self {
+            volume,
+            anchor,
+            view,
+            offset: None,
+            priority: 0,
+            blend: 0.5,
+            ease: EaseFunction::SmoothStep,
+        }
+    }
+
+    /// Sets the offset used while inside the zone
+    ///
+    /// # Arguments
+    /// * `offset` - 3D vector offset in meters
+    #[inline]
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the priority of the zone where zones overlap
+    ///
+    /// # Arguments
+    /// * `priority` - Zones with higher priority win
+    #[inline]
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the blend used when entering or leaving the zone
+    ///
+    /// # Arguments
+    /// * `duration` - Duration of the blend in seconds
+    /// * `ease` - Easing applied to the blend
+    #[inline]
+    pub fn with_blend(mut self, duration: f32, ease: EaseFunction) -> Self {
+        self.blend = duration;
+        self.ease = ease;
+        self
+    }
+
+    /// Checks whether a world space point is inside the zone
+    ///
+    /// # Arguments
+    /// * `transform` - Transform of the zone entity
+    /// * `collider` - Collider on the zone entity, if any
+    /// * `point` - World space point to test
+    pub fn contains(
+        &self,
+        transform: &Transform,
+        #[cfg(feature = "avian3d")] collider: Option<&Collider>,
+        point: Vec3,
+    ) -> bool {
+        match &self.volume {
+            ZoneVolume::Box { half_extents } => {
+                let local = transform.compute_affine().inverse().transform_point3(point);
+                local.abs().cmple(*half_extents).all()
+            }
+            #[cfg(feature = "avian3d")]
+            ZoneVolume::Collider => collider.is_some_and(|collider| {
+                collider.contains_point(
+                    Position::new(transform.translation),
+                    Rotation::from(transform.rotation),
+                    point,
+                )
+            }),
+        }
+    }
+}
+
+/// Zone currently overriding a controller, with the settings to restore on exit
+#[derive(Component)]
+pub(crate) struct ActiveCameraZone {
+    /// Zone entity overriding the controller
+    zone: Entity,
+    /// Anchor, view and offset of the controller before entering any zone
+    previous: (CameraAnchor, CameraView, Vec3),
+}
+
+#[cfg(feature = "avian3d")]
+type ZoneItem<'a> = (Entity, &'a CameraZone, &'a Transform, Option<&'a Collider>);
+#[cfg(not(feature = "avian3d"))]
+type ZoneItem<'a> = (Entity, &'a CameraZone, &'a Transform);
+
+/// Switches controllers to the highest priority zone containing their entity,
+/// restoring their own settings when they leave every zone
+///
+/// # Arguments
+/// * `commands` - Commands for tracking active zones
+/// * `camera_controllers` - Query for controllers and their active zone
+/// * `controller_transforms` - Query for controller entity transforms
+/// * `zones` - Query for zone volumes
+/// * `skipped` - Controllers skipped for missing entities, warned about once
+pub(crate) fn update_zones(
+    mut commands: Commands,
+    mut camera_controllers: Query<(Entity, &mut CameraController, Option<&mut ActiveCameraZone>)>,
+    controller_transforms: Query<TargetTransform, TargetFilter>,
+    zones: Query<ZoneItem>,
+    mut skipped: Local<SkippedEntities>,
+) {
+    for (entity, mut controller, active) in camera_controllers.iter_mut() {
+        let Some(controller_transform) = skipped.check(
+            entity,
+            target::get_transform(&controller_transforms, entity),
+        ) else {
+            continue;
+        };
+        let translation = controller_transform.translation;
+
+        #[cfg(feature = "avian3d")]
+        let inside = zones
+            .iter()
+            .filter(|(_, zone, transform, collider)| {
+                zone.contains(transform, *collider, translation)
+            })
+            .map(|(zone_entity, zone, _, _)| (zone_entity, zone));
+        #[cfg(not(feature = "avian3d"))]
+        let inside = zones
+            .iter()
+            .filter(|(_, zone, transform)| zone.contains(transform, translation))
+            .map(|(zone_entity, zone, _)| (zone_entity, zone));
+        let best = inside.max_by_key(|(_, zone)| zone.priority);
+
+        match (best, active) {
+            (Some((zone_entity, _)), Some(active)) if active.zone == zone_entity => {}
+            (Some((zone_entity, zone)), active) => {
+                let offset = zone.offset.unwrap_or(controller.offset);
+                match active {
+                    // moving between zones keeps the settings from before the first zone
+                    Some(mut active) => active.zone = zone_entity,
+                    None => {
+                        commands.entity(entity).insert(ActiveCameraZone {
+                            zone: zone_entity,
+                            previous: (
+                                controller.anchor.clone(),
+                                controller.view.clone(),
+                                controller.offset,
+                            ),
+                        });
+                    }
+                }
+                controller.offset = offset;
+                controller.transition_to(
+                    zone.anchor.clone(),
+                    zone.view.clone(),
+                    zone.blend,
+                    zone.ease,
+                );
+            }
+            (None, Some(active)) => {
+                // blend back using the settings of the zone being left
+                let (blend, ease) = zones
+                    .get(active.zone)
+                    .map_or((0.5, EaseFunction::SmoothStep), |item| {
+                        (item.1.blend, item.1.ease)
+                    });
+                let (anchor, view, offset) = active.previous.clone();
+                controller.offset = offset;
+                controller.transition_to(anchor, view, blend, ease);
+                commands.entity(entity).remove::<ActiveCameraZone>();
+            }
+            (None, None) => {}
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+/// Steps the velocity of a character body toward a wished horizontal velocity,
+/// applying gravity while airborne and an optional jump from the ground
+///
+/// # Arguments
+/// * `velocity` - Current velocity in world units per second
+/// * `wish` - Wished horizontal velocity in world units per second
+/// * `up` - Direction pointing away from the ground
+/// * `grounded` - Whether the body is standing on walkable ground
+/// * `acceleration` - Ground acceleration in world units per second squared
+/// * `air_control` - Fraction of the acceleration available while airborne
+/// * `gravity` - Downward acceleration in world units per second squared
+/// * `jump` - Upward speed given by a jump this step, if any
+/// * `dt` - Time step in seconds
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn accelerate(
+    velocity: Vec3,
+    wish: Vec3,
+    up: Vec3,
+    grounded: bool,
+    acceleration: f32,
+    air_control: f32,
+    gravity: f32,
+    jump: Option<f32>,
+    dt: f32,
+) -> Vec3 {
+    // accelerate along the ground, with reduced control in the air
+    let vertical = velocity.dot(up);
+    let mut horizontal = velocity - up * vertical;
+    let acceleration = if grounded {
+        acceleration
+    } else {
+        acceleration * air_control
+    };
+    if acceleration.is_finite() {
+        horizontal = horizontal.move_towards(wish, acceleration * dt);
+    } else {
+        horizontal = wish;
+    }
+
+    let mut vertical = if grounded {
+        vertical.max(0.0)
+    } else {
+        vertical - gravity * dt
+    };
+    if let Some(jump) = jump
+        && grounded
+    {
+        vertical = jump;
+    }
+
+    horizontal + up * vertical
+}
+
+/// Sweeps a collider along a motion, sliding along the surfaces it hits.
+/// Returns the final position and the velocity left after sliding.
+///
+/// # Arguments
+/// * `spatial_query` - Spatial query used to sweep the collider
+/// * `filter` - Filter excluding the character's own colliders
+/// * `collider` - Collider of the character
+/// * `rotation` - Rotation of the collider
+/// * `translation` - Starting position of the collider
+/// * `velocity` - Velocity in world units per second
+/// * `dt` - Time step in seconds
+/// * `skin` - Gap kept between the collider and the world
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn move_and_slide(
+    spatial_query: &SpatialQuery,
+    filter: &SpatialQueryFilter,
+    collider: &Collider,
+    rotation: Quat,
+    mut translation: Vec3,
+    mut velocity: Vec3,
+    dt: f32,
+    skin: f32,
+) -> (Vec3, Vec3) {
+    let mut motion = velocity * dt;
+
+    // a few iterations handle corners where several surfaces meet
+    for _ in 0..4 {
+        let Ok((direction, length)) = Dir3::new_and_length(motion) else {
+            break;
+        };
+        let config = ShapeCastConfig {
+            ignore_origin_penetration: true,
+            ..ShapeCastConfig::from_max_distance(length + skin)
+        };
+        let Some(hit) =
+            spatial_query.cast_shape(collider, translation, rotation, direction, &config, filter)
+        else {
+            translation += motion;
+            break;
+        };
+
+        let travel = (hit.distance - skin).max(0.0);
+        translation += direction * travel;
+
+        // remove the part of the remaining motion and velocity pushing into the surface
+        let normal = surface_normal(hit.normal1, direction.as_vec3());
+        motion = (motion - direction * travel).reject_from_normalized(normal);
+        if velocity.dot(normal) < 0.0 {
+            velocity = velocity.reject_from_normalized(normal);
+        }
+    }
+
+    (translation, velocity)
+}
+
+/// Checks whether walkable ground is just below a collider
+///
+/// # Arguments
+/// * `spatial_query` - Spatial query used to find the ground
+/// * `filter` - Filter excluding the character's own colliders
+/// * `collider` - Collider of the character
+/// * `translation` - Position of the collider
+/// * `rotation` - Rotation of the collider
+/// * `up` - Direction pointing away from the ground
+/// * `skin` - Gap kept between the collider and the world
+/// * `max_slope` - Steepest ground angle in radians that counts as walkable
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn find_ground(
+    spatial_query: &SpatialQuery,
+    filter: &SpatialQueryFilter,
+    collider: &Collider,
+    translation: Vec3,
+    rotation: Quat,
+    up: Vec3,
+    skin: f32,
+    max_slope: f32,
+) -> bool {
+    let down = Dir3::new(-up).unwrap_or(Dir3::NEG_Y);
+    spatial_query
+        .cast_shape(
+            collider,
+            translation,
+            rotation,
+            down,
+            &ShapeCastConfig::from_max_distance(skin * 2.0),
+            filter,
+        )
+        .is_some_and(|hit| surface_normal(hit.normal1, -up).dot(up) >= max_slope.cos())
+}
+
+/// Orients a hit normal so it faces against the cast direction
+///
+/// # Arguments
+/// * `normal` - Normal reported by the shape cast
+/// * `direction` - Direction of the shape cast
+#[inline]
+fn surface_normal(normal: Vec3, direction: Vec3) -> Vec3 {
+    if normal.dot(direction) > 0.0 {
+        -normal
+    } else {
+        normal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DT: f32 = 0.1;
+
+    #[test]
+    fn grounded_body_accelerates_toward_the_wish() {
+        let velocity = accelerate(
+            Vec3::ZERO,
+            Vec3::X * 10.0,
+            Vec3::Y,
+            true,
+            20.0,
+            0.5,
+            9.81,
+            None,
+            DT,
+        );
+        assert!((velocity - Vec3::X * 2.0).length() < 1e-5);
+
+        // the wish is reached without overshooting
+        let velocity = accelerate(
+            Vec3::X * 9.5,
+            Vec3::X * 10.0,
+            Vec3::Y,
+            true,
+            20.0,
+            0.5,
+            9.81,
+            None,
+            DT,
+        );
+        assert!((velocity - Vec3::X * 10.0).length() < 1e-5);
+    }
+
+    #[test]
+    fn airborne_body_has_reduced_control_and_falls() {
+        let velocity = accelerate(
+            Vec3::ZERO,
+            Vec3::X * 10.0,
+            Vec3::Y,
+            false,
+            20.0,
+            0.5,
+            10.0,
+            None,
+            DT,
+        );
+        assert!((velocity - Vec3::new(1.0, -1.0, 0.0)).length() < 1e-5);
+    }
+
+    #[test]
+    fn grounded_body_does_not_sink() {
+        let velocity = accelerate(
+            Vec3::NEG_Y * 5.0,
+            Vec3::ZERO,
+            Vec3::Y,
+            true,
+            20.0,
+            0.5,
+            10.0,
+            None,
+            DT,
+        );
+        assert_eq!(velocity, Vec3::ZERO);
+    }
+
+    #[test]
+    fn jumps_only_from_the_ground() {
+        let velocity = accelerate(
+            Vec3::ZERO,
+            Vec3::ZERO,
+            Vec3::Y,
+            true,
+            20.0,
+            0.5,
+            10.0,
+            Some(5.0),
+            DT,
+        );
+        assert_eq!(velocity, Vec3::Y * 5.0);
+
+        let velocity = accelerate(
+            Vec3::ZERO,
+            Vec3::ZERO,
+            Vec3::Y,
+            false,
+            20.0,
+            0.5,
+            10.0,
+            Some(5.0),
+            DT,
+        );
+        assert!((velocity - Vec3::NEG_Y).length() < 1e-5);
+    }
+
+    #[test]
+    fn infinite_acceleration_snaps_to_the_wish() {
+        let wish = Vec3::new(3.0, 0.0, -4.0);
+        let velocity = accelerate(
+            Vec3::ZERO,
+            wish,
+            Vec3::Y,
+            true,
+            f32::INFINITY,
+            1.0,
+            10.0,
+            None,
+            DT,
+        );
+        assert_eq!(velocity, wish);
+    }
+
+    #[test]
+    fn follows_a_custom_up_direction() {
+        // a wall walker with up along X keeps falling along X
+        let velocity = accelerate(
+            Vec3::ZERO,
+            Vec3::Z * 10.0,
+            Vec3::X,
+            false,
+            20.0,
+            1.0,
+            10.0,
+            None,
+            DT,
+        );
+        assert!((velocity - Vec3::new(-1.0, 0.0, 2.0)).length() < 1e-5);
+    }
+}
@@ -0,0 +1,68 @@
+mod fps;
+mod kinematic;
+mod third_person;
+
+pub use fps::FirstPersonCharacter;
+pub use third_person::ThirdPersonCharacter;
+
+use bevy::prelude::*;
+
+/// Character Plugin for moving kinematic character bodies, which are driven by
+/// [`CameraController`](crate::camera::CameraController) cameras on the same entity
+#[derive(Default)]
+pub struct CharacterPlugin;
+
+impl Plugin for CharacterPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                fps::update_first_person_characters,
+                third_person::update_third_person_characters,
+            ),
+        );
+    }
+}
+
+/// Keys used by character bodies
+#[derive(Clone, Debug)]
+pub struct CharacterBindings {
+    pub forward: KeyCode,
+    pub back: KeyCode,
+    pub left: KeyCode,
+    pub right: KeyCode,
+    pub jump: KeyCode,
+    /// Held to move faster
+    pub sprint: KeyCode,
+}
+
+impl Default for CharacterBindings {
+    fn default() -> Self {
+        Self {
+            forward: KeyCode::KeyW,
+            back: KeyCode::KeyS,
+            left: KeyCode::KeyA,
+            right: KeyCode::KeyD,
+            jump: KeyCode::Space,
+            sprint: KeyCode::ShiftLeft,
+        }
+    }
+}
+
+impl CharacterBindings {
+    /// Reads the normalized movement input, whether sprint is held and whether jump was pressed
+    ///
+    /// # Arguments
+    /// * `keys` - Keyboard input
+    pub(crate) fn read(&self, keys: &ButtonInput<KeyCode>) -> (Vec2, bool, bool) {
+        let axis = |positive: KeyCode, negative: KeyCode| {
+            keys.pressed(positive) as i32 as f32 - keys.pressed(negative) as i32 as f32
+        };
+        let input = Vec2::new(axis(self.right, self.left), axis(self.forward, self.back));
+        (
+            input.normalize_or_zero(),
+            keys.pressed(self.sprint),
+            keys.just_pressed(self.jump),
+        )
+    }
+}
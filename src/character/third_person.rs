@@ -0,0 +1,300 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use super::{CharacterBindings, kinematic};
+use crate::camera::{
+    CameraController, CameraPlayer, InputSource, InputSources, WorldUnits, exclude_hierarchy,
+};
+
+/// A kinematic third person character body, moved with WASD relative to the yaw of the
+/// camera driven by the [`CameraController`] on the same entity, turning toward the direction
+/// it moves in at a limited rate, falling with gravity and jumping from the ground.
+///
+/// Pairs with the [`Orbit`](crate::camera::CameraAnchor::Orbit) anchor. In strafe mode the
+/// character always faces the camera's forward direction instead, such as while aiming.
+/// The controller's offset is in the character's local space and turns with it, so offsets
+/// beside the character are best combined with strafe mode.
+///
+/// Gravity pulls against the controller's yaw axis, so a [`CameraUp`](crate::camera::CameraUp)
+/// on the entity also lets the body walk around spherical worlds.
+#[derive(Component, Clone, Debug)]
+#[require(RigidBody = RigidBody::Kinematic)]
+pub struct ThirdPersonCharacter {
+    /// Whether the character responds to input
+    pub enabled: bool,
+    /// Walking speed in meters per second
+    pub speed: f32,
+    /// Multiplier applied to speed while the sprint key is held
+    pub sprint_multiplier: f32,
+    /// Rate in meters per second squared at which the character reaches its walking speed
+    /// on the ground, or infinite for instant changes
+    pub acceleration: f32,
+    /// Fraction of the acceleration available while airborne
+    pub air_control: f32,
+    /// Downward acceleration in meters per second squared
+    pub gravity: f32,
+    /// Upward speed in meters per second given by a jump
+    pub jump_speed: f32,
+    /// Steepest ground angle in radians the character can stand on
+    pub max_slope: f32,
+    /// Gap in meters kept between the collider and the world, avoiding getting stuck in surfaces
+    pub skin: f32,
+    /// Maximum turning speed in radians per second, or infinite to turn instantly
+    pub turn_rate: f32,
+    /// Whether the character faces the camera's forward direction rather than its movement
+    pub strafe: bool,
+    /// Keys used for control
+    pub bindings: CharacterBindings,
+    /// Velocity of the character in world units per second
+    pub(crate) velocity: Vec3,
+    /// Whether the character is standing on walkable ground
+    pub(crate) grounded: bool,
+}
+
+impl Default for ThirdPersonCharacter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ThirdPersonCharacter {
+    /// Creates a new ThirdPersonCharacter with default settings:
+    /// - Speed: 5.0 meters per second, sprint multiplier: 1.8
+    /// - Acceleration: 50.0 meters per second squared, air control: 0.3
+    /// - Gravity: 9.81 meters per second squared, jump speed: 5.0 meters per second
+    /// - Max slope: 45 degrees
+    /// - Turn rate: 720 degrees per second, without strafing
+    /// - WASD to move, space to jump, left shift to sprint
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            speed: 5.0,
+            sprint_multiplier: 1.8,
+            acceleration: 50.0,
+            air_control: 0.3,
+            gravity: 9.81,
+            jump_speed: 5.0,
+            max_slope: std::f32::consts::FRAC_PI_4,
+            skin: 0.02,
+            turn_rate: 4.0 * std::f32::consts::PI,
+            strafe: false,
+            bindings: CharacterBindings::default(),
+            velocity: Vec3::ZERO,
+            grounded: false,
+        }
+    }
+
+    /// Sets the walking speed
+    ///
+    /// # Arguments
+    /// * `speed` - Speed in meters per second
+    #[inline]
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Sets the ground acceleration and the fraction of it available while airborne
+    ///
+    /// # Arguments
+    /// * `acceleration` - Acceleration in meters per second squared
+    /// * `air_control` - Fraction of the acceleration available while airborne
+    #[inline]
+    pub fn with_acceleration(mut self, acceleration: f32, air_control: f32) -> Self {
+        self.acceleration = acceleration;
+        self.air_control = air_control;
+        self
+    }
+
+    /// Sets the jump speed and gravity
+    ///
+    /// # Arguments
+    /// * `jump_speed` - Upward speed in meters per second given by a jump
+    /// * `gravity` - Downward acceleration in meters per second squared
+    #[inline]
+    pub fn with_jump(mut self, jump_speed: f32, gravity: f32) -> Self {
+        self.jump_speed = jump_speed;
+        self.gravity = gravity;
+        self
+    }
+
+    /// Sets the steepest ground angle the character can stand on
+    ///
+    /// # Arguments
+    /// * `max_slope` - Angle in radians from horizontal
+    #[inline]
+    pub fn with_max_slope(mut self, max_slope: f32) -> Self {
+        self.max_slope = max_slope;
+        self
+    }
+
+    /// Sets the maximum turning speed
+    ///
+    /// # Arguments
+    /// * `turn_rate` - Turning speed in radians per second, or infinite to turn instantly
+    #[inline]
+    pub fn with_turn_rate(mut self, turn_rate: f32) -> Self {
+        self.turn_rate = turn_rate;
+        self
+    }
+
+    /// Sets whether the character faces the camera's forward direction rather than its movement
+    ///
+    /// # Arguments
+    /// * `strafe` - Whether strafe mode is enabled
+    #[inline]
+    pub fn with_strafe(mut self, strafe: bool) -> Self {
+        self.strafe = strafe;
+        self
+    }
+
+    /// Sets the keys used for control
+    ///
+    /// # Arguments
+    /// * `bindings` - Key bindings
+    #[inline]
+    pub fn with_bindings(mut self, bindings: CharacterBindings) -> Self {
+        self.bindings = bindings;
+        self
+    }
+
+    /// Returns the velocity of the character in world units per second
+    #[inline]
+    pub fn velocity(&self) -> Vec3 {
+        self.velocity
+    }
+
+    /// Checks whether the character is standing on walkable ground
+    #[inline]
+    pub fn is_grounded(&self) -> bool {
+        self.grounded
+    }
+}
+
+/// Moves third person characters from keyboard input relative to their camera's yaw and
+/// turns them toward their movement or the camera's forward direction, skipping characters
+/// whose camera is not routed the keyboard and mouse
+///
+/// # Arguments
+/// * `characters` - Query for characters, their transforms, colliders and controllers
+/// * `cameras` - Query for camera transforms
+/// * `spatial_query` - Spatial query used to sweep the character through the world
+/// * `children` - Query for child colliders excluded from the character's own sweeps
+/// * `keys` - Keyboard input, if the input plugin is present
+/// * `units` - Resource describing the scale of the world
+/// * `time` - Resource providing frame timing information
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_third_person_characters(
+    mut characters: Query<(
+        Entity,
+        &mut ThirdPersonCharacter,
+        &mut Transform,
+        &Collider,
+        &CameraController,
+        Option<&CameraPlayer>,
+        Option<&InputSources>,
+    )>,
+    cameras: Query<&GlobalTransform, (With<Camera>, Without<ThirdPersonCharacter>)>,
+    spatial_query: SpatialQuery,
+    children: Query<&Children>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    units: Res<WorldUnits>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+    if dt <= 0.0 {
+        return;
+    }
+
+    for (entity, mut character, mut transform, collider, controller, player, sources) in
+        characters.iter_mut()
+    {
+        let filter = exclude_hierarchy([entity], &children, LayerMask::ALL);
+        // the body stands along the controller's yaw axis, which follows any CameraUp
+        let up = controller.yaw_axis.as_vec3();
+        let body_up = transform.rotation * Vec3::Y;
+        if body_up.dot(up) < 1.0 - f32::EPSILON {
+            transform.rotation =
+                (Quat::from_rotation_arc(body_up, up) * transform.rotation).normalize();
+        }
+
+        // read movement input relative to the camera's heading
+        let (input, sprinting, jumping) = match keys.as_deref() {
+            Some(keys)
+                if character.enabled
+                    && InputSources::routes(sources, player, InputSource::Mouse) =>
+            {
+                character.bindings.read(keys)
+            }
+            _ => (Vec2::ZERO, false, false),
+        };
+        let forward = cameras
+            .get(controller.camera)
+            .map(|camera| camera.forward().as_vec3())
+            .unwrap_or(Vec3::NEG_Z)
+            .reject_from(up)
+            .normalize_or(Vec3::NEG_Z);
+        let right = forward.cross(up);
+
+        let mut speed = units.to_world(character.speed);
+        if sprinting {
+            speed *= character.sprint_multiplier;
+        }
+        let wish = (right * input.x + forward * input.y) * speed;
+
+        let velocity = kinematic::accelerate(
+            character.velocity,
+            wish,
+            up,
+            character.grounded,
+            units.to_world(character.acceleration),
+            character.air_control,
+            units.to_world(character.gravity),
+            jumping.then(|| units.to_world(character.jump_speed)),
+            dt,
+        );
+
+        // turn toward the camera's heading when strafing, or the movement direction otherwise
+        let facing = if character.strafe { forward } else { wish };
+        if let Ok(facing) = Dir3::new(facing) {
+            let target = Transform::default().looking_to(facing, up).rotation;
+            let rotation = if character.turn_rate.is_finite() {
+                transform
+                    .rotation
+                    .rotate_towards(target, character.turn_rate * dt)
+            } else {
+                target
+            };
+            if transform.rotation != rotation {
+                transform.rotation = rotation;
+            }
+        }
+
+        let skin = units.to_world(character.skin);
+        let (translation, velocity) = kinematic::move_and_slide(
+            &spatial_query,
+            &filter,
+            collider,
+            transform.rotation,
+            transform.translation,
+            velocity,
+            dt,
+            skin,
+        );
+        character.grounded = kinematic::find_ground(
+            &spatial_query,
+            &filter,
+            collider,
+            translation,
+            transform.rotation,
+            up,
+            skin,
+            character.max_slope,
+        ) && velocity.dot(up) <= 0.0;
+        character.velocity = velocity;
+
+        if transform.translation != translation {
+            transform.translation = translation;
+        }
+    }
+}
@@ -1,5 +1,12 @@
+#[cfg(all(feature = "avian3d", feature = "rapier3d"))]
+compile_error!("the `avian3d` and `rapier3d` features are mutually exclusive, enable only one");
+
 pub mod camera;
+#[cfg(feature = "avian3d")]
+pub mod character;
 
 pub mod prelude {
     pub use crate::camera::*;
+    #[cfg(feature = "avian3d")]
+    pub use crate::character::*;
 }